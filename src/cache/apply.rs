@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::paths::{expand, resolve};
+use super::share::{clone_dir, freshest, link_dir};
+use super::CacheConfig;
+use crate::issue::IssueRef;
+
+/// What setting up a worktree's shared caches did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Prepared {
+    /// Number of `cache.env` directories made ready.
+    pub env: usize,
+    /// Number of worktree paths symlinked to shared directories.
+    pub linked: usize,
+    /// Number of directories cloned from sibling worktrees.
+    pub cloned: usize,
+    /// Paths left alone because the worktree already has them.
+    pub existing: usize,
+}
+
+impl std::fmt::Display for Prepared {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            env,
+            linked,
+            cloned,
+            ..
+        } = self;
+        write!(
+            f,
+            "Shared caches: {env} variable(s), {linked} link(s), {cloned} cloned from sibling worktrees"
+        )?;
+        if self.existing > 0 {
+            write!(f, " ({} already present)", self.existing)?;
+        }
+        Ok(())
+    }
+}
+
+/// Set up the shared caches of the new worktree `wt` of `issue`, started from
+/// `base_ref`, printing what was done. Failures are reported as warnings:
+/// the worktree works without its caches, only slower.
+pub fn prepare_new_worktree(issue: &IssueRef, wt: &Path, base_ref: Option<&str>) {
+    let prepared = resolve(issue, wt, base_ref).and_then(|found| {
+        found
+            .map(|(cache, root, base)| prepare(&cache, &root, &base, wt))
+            .transpose()
+    });
+    match prepared {
+        Ok(Some(p)) => eprintln!("{p}"),
+        Ok(None) => {}
+        Err(e) => eprintln!("warning: could not set up shared caches: {e:#}"),
+    }
+}
+
+/// Create the shared directories `cache` names under `root` for the base
+/// branch `base`, symlink the worktree `wt` to them, and clone its sibling
+/// directories.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be created, linked, or cloned.
+pub fn prepare(cache: &CacheConfig, root: &Path, base: &str, wt: &Path) -> Result<Prepared> {
+    let mut prepared = Prepared::default();
+    for dir in cache.env.values() {
+        let dir = expand(dir, root, base);
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        prepared.env += 1;
+    }
+    for (path, dir) in &cache.link {
+        let (dest, target) = (wt.join(path), expand(dir, root, base));
+        if dest.symlink_metadata().is_ok() {
+            prepared.existing += 1;
+            continue;
+        }
+        std::fs::create_dir_all(&target)?;
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        link_dir(&target, &dest).with_context(|| format!("Failed to link {}", dest.display()))?;
+        prepared.linked += 1;
+    }
+    for entry in &cache.sibling {
+        let dest = wt.join(entry);
+        if dest.symlink_metadata().is_ok() {
+            prepared.existing += 1;
+        } else if let Some(from) = freshest(wt, entry) {
+            clone_dir(&from.join(entry), &dest)?;
+            prepared.cloned += 1;
+        }
+    }
+    Ok(prepared)
+}
+
+#[cfg(test)]
+#[path = "apply_tests.rs"]
+mod tests;
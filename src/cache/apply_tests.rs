@@ -0,0 +1,70 @@
+use super::*;
+use crate::test_support::{git_in, init_repo};
+
+fn config(env: &[(&str, &str)], link: &[(&str, &str)], sibling: &[&str]) -> CacheConfig {
+    let map = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    };
+    CacheConfig {
+        env: map(env),
+        link: map(link),
+        sibling: sibling.iter().map(|s| (*s).to_owned()).collect(),
+    }
+}
+
+#[test]
+fn test_prepare_creates_env_dirs_and_links() {
+    let tmp = tempfile::tempdir().unwrap();
+    let (root, wt) = (tmp.path().join("cache"), tmp.path().join("wt"));
+    std::fs::create_dir_all(wt.join("kept")).unwrap();
+    let cache = config(
+        &[("CARGO_TARGET_DIR", "target/{{base}}")],
+        &[(".turbo", "turbo/{{base}}"), ("kept", "kept")],
+        &[],
+    );
+    let prepared = prepare(&cache, &root, "main", &wt).unwrap();
+    assert_eq!(
+        (prepared.env, prepared.linked, prepared.existing),
+        (1, 1, 1)
+    );
+    assert!(root.join("target/main").is_dir());
+    assert!(root.join("turbo/main").is_dir());
+    #[cfg(unix)]
+    assert_eq!(
+        std::fs::read_link(wt.join(".turbo")).unwrap(),
+        root.join("turbo/main")
+    );
+    assert!(!wt.join("kept").is_symlink());
+}
+
+#[test]
+fn test_prepare_clones_from_freshest_sibling() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = init_repo(tmp.path());
+    for name in ["a", "b", "new"] {
+        let wt = tmp.path().join(name);
+        git_in(
+            &repo,
+            &["worktree", "add", "-q", wt.to_str().unwrap(), "-b", name],
+        );
+    }
+    std::fs::create_dir_all(tmp.path().join("a/node_modules/pkg")).unwrap();
+    std::fs::write(tmp.path().join("a/node_modules/pkg/index.js"), "old").unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    std::fs::create_dir_all(tmp.path().join("b/node_modules/pkg")).unwrap();
+    std::fs::write(tmp.path().join("b/node_modules/pkg/index.js"), "new").unwrap();
+
+    let new = tmp.path().join("new");
+    let cache = config(&[], &[], &["node_modules", ".venv"]);
+    let prepared = prepare(&cache, &tmp.path().join("cache"), "main", &new).unwrap();
+    assert_eq!(prepared.cloned, 1);
+    let copied = std::fs::read_to_string(new.join("node_modules/pkg/index.js")).unwrap();
+    assert_eq!(copied, "new");
+    assert!(!new.join(".venv").exists());
+    assert!(prepared
+        .to_string()
+        .contains("1 cloned from sibling worktrees"));
+}
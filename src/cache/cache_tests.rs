@@ -0,0 +1,56 @@
+use super::*;
+
+fn cache(env: &[(&str, &str)], link: &[(&str, &str)], sibling: &[&str]) -> CacheConfig {
+    let map = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    };
+    CacheConfig {
+        env: map(env),
+        link: map(link),
+        sibling: sibling.iter().map(|s| (*s).to_owned()).collect(),
+    }
+}
+
+#[test]
+fn test_merged_prefers_repo_entries() {
+    let global = cache(
+        &[("CARGO_TARGET_DIR", "target")],
+        &[(".turbo", "turbo")],
+        &["node_modules"],
+    );
+    let repo = cache(
+        &[("CARGO_TARGET_DIR", "target/{{base}}")],
+        &[],
+        &["node_modules", ".venv"],
+    );
+    let merged = global.merged(&repo);
+    assert_eq!(merged.env["CARGO_TARGET_DIR"], "target/{{base}}");
+    assert_eq!(merged.link[".turbo"], "turbo");
+    assert_eq!(merged.sibling, ["node_modules", ".venv"]);
+    assert!(!merged.is_empty());
+    assert!(CacheConfig::default().is_empty());
+}
+
+#[test]
+fn test_validate() {
+    assert!(cache(
+        &[("CARGO_TARGET_DIR", "t")],
+        &[("a/b", "x")],
+        &["node_modules"]
+    )
+    .validate()
+    .is_ok());
+    for bad in ["", "1X", "A-B", "A B"] {
+        let err = cache(&[(bad, "t")], &[], &[]).validate().unwrap_err();
+        assert!(err.contains("not a valid variable name"), "{bad}: {err}");
+    }
+    for bad in ["", "/abs", "../up", "a/../../b"] {
+        let err = cache(&[], &[(bad, "x")], &[]).validate().unwrap_err();
+        assert!(err.contains("`cache.link` path"), "{bad}: {err}");
+        let err = cache(&[], &[], &[bad]).validate().unwrap_err();
+        assert!(err.contains("`cache.sibling` path"), "{bad}: {err}");
+    }
+}
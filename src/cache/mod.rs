@@ -0,0 +1,76 @@
+//! Dependency and build caches shared by every worktree of a repository.
+//!
+//! The `[cache]` table of the global config and of the repo's
+//! `.worktree.toml` names the directories to share. Cache paths are relative
+//! to `~/.cache/worktree/<owner>/<repo>/` unless absolute or starting with
+//! `~`, and `{{base}}` stands for the base branch the worktree was created
+//! from.
+//!
+//! ```toml
+//! [cache]
+//! sibling = ["node_modules"]   # cloned from the freshest sibling worktree
+//!
+//! [cache.env]                  # exported into hooks and terminals
+//! CARGO_TARGET_DIR = "target/{{base}}"
+//! npm_config_store_dir = "~/.cache/pnpm-store"
+//!
+//! [cache.link]                 # worktree path = cache path
+//! ".turbo" = "turbo/{{base}}"
+//! ```
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+mod apply;
+mod paths;
+mod share;
+mod validate;
+
+pub use apply::{prepare, prepare_new_worktree, Prepared};
+pub use paths::{cache_env, cache_root, expand};
+
+/// Shared cache directories for a repository's worktrees.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Environment variables set to shared directories, e.g.
+    /// `CARGO_TARGET_DIR`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Worktree paths symlinked to shared directories.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub link: BTreeMap<String, String>,
+    /// Worktree directories cloned, reflinked where the file system allows,
+    /// from the sibling worktree that updated them last.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sibling: Vec<String>,
+}
+
+impl CacheConfig {
+    /// These settings with the repo's `repo` settings layered on top: the
+    /// repo wins for each variable and link, and the sibling lists are
+    /// combined.
+    #[must_use]
+    pub fn merged(&self, repo: &Self) -> Self {
+        let mut merged = self.clone();
+        merged.env.extend(repo.env.clone());
+        merged.link.extend(repo.link.clone());
+        let extra: Vec<String> = repo
+            .sibling
+            .iter()
+            .filter(|s| !self.sibling.contains(s))
+            .cloned()
+            .collect();
+        merged.sibling.extend(extra);
+        merged
+    }
+
+    /// `true` when nothing is shared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.env.is_empty() && self.link.is_empty() && self.sibling.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[path = "cache_tests.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::CacheConfig;
+use crate::config::Config;
+use crate::git::base_branch_name;
+use crate::issue::IssueRef;
+use crate::repo_hooks::RepoConfig;
+use crate::ttl::WorkspaceRegistry;
+
+/// The shared cache directory of the repository `slug` (see
+/// [`IssueRef::repo_slug`]): `~/.cache/worktree/<owner>/<repo>/`.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn cache_root(slug: &str) -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    Ok(home.join(".cache").join("worktree").join(slug))
+}
+
+/// The directory the cache path `template` names, with `{{base}}` replaced
+/// by `base`: under `root` when relative, under the home directory when it
+/// starts with `~`.
+#[must_use]
+pub fn expand(template: &str, root: &Path, base: &str) -> PathBuf {
+    let rendered = template.replace("{{base}}", base);
+    let path = Path::new(&rendered);
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => root.join(path),
+    }
+}
+
+/// The cache settings for the worktree `wt` of `issue`, with its cache
+/// root and base branch name; `None` when nothing is shared.
+pub(super) fn resolve(
+    issue: &IssueRef,
+    wt: &Path,
+    base_ref: Option<&str>,
+) -> Result<Option<(CacheConfig, PathBuf, String)>> {
+    let repo = RepoConfig::load_from(wt).unwrap_or_default();
+    let cache = Config::load()?.cache.merged(&repo.cache);
+    if cache.is_empty() {
+        return Ok(None);
+    }
+    cache.validate().map_err(|e| anyhow::anyhow!(e))?;
+    let base = base_branch_name(wt, base_ref).unwrap_or_else(|| "default".to_owned());
+    Ok(Some((cache, cache_root(&issue.repo_slug())?, base)))
+}
+
+/// The `cache.env` variables for the worktree `wt` of `issue`, for hooks and
+/// terminals. Failures are reported as a warning and yield no variables.
+#[must_use]
+pub fn cache_env(issue: &IssueRef, wt: &Path) -> Vec<(String, String)> {
+    let registry = WorkspaceRegistry::load().ok();
+    let base_ref = registry.as_ref().and_then(|r| r.get(wt)?.base_ref.clone());
+    match resolve(issue, wt, base_ref.as_deref()) {
+        Ok(Some((cache, root, base))) => cache
+            .env
+            .iter()
+            .map(|(k, v)| (k.clone(), expand(v, &root, &base).display().to_string()))
+            .collect(),
+        Ok(None) => Vec::new(),
+        Err(e) => {
+            eprintln!("warning: ignoring shared caches: {e:#}");
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "paths_tests.rs"]
+mod tests;
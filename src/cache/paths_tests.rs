@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn test_cache_root_is_keyed_by_slug() {
+    let root = cache_root("acme/api").unwrap();
+    assert!(root.ends_with(".cache/worktree/acme/api"), "{root:?}");
+}
+
+#[test]
+fn test_expand() {
+    let root = Path::new("/cache/acme/api");
+    assert_eq!(
+        expand("target/{{base}}", root, "main"),
+        Path::new("/cache/acme/api/target/main")
+    );
+    assert_eq!(expand("/abs/{{base}}", root, "dev"), Path::new("/abs/dev"));
+    let home = dirs::home_dir().unwrap();
+    assert_eq!(
+        expand("~/.pnpm-store", root, "main"),
+        home.join(".pnpm-store")
+    );
+}
@@ -0,0 +1,56 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::git::{common_dir, list_worktrees};
+use crate::lifecycle::copy_tree;
+
+/// The other worktree of `wt`'s repository whose `entry` was modified most
+/// recently, i.e. the freshest install to start from.
+pub(super) fn freshest(wt: &Path, entry: &str) -> Option<PathBuf> {
+    let me = wt.canonicalize().ok()?;
+    let worktrees = list_worktrees(&common_dir(wt).ok()?).ok()?;
+    worktrees
+        .into_iter()
+        .filter(|w| !w.bare && !w.prunable)
+        .filter(|w| w.path.canonicalize().ok().as_ref() != Some(&me))
+        .filter_map(|w| {
+            let modified = w.path.join(entry).metadata().ok()?.modified().ok()?;
+            Some((modified, w.path))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Copy the directory `from` to `to`, sharing blocks through copy-on-write
+/// clones where the file system supports them.
+pub(super) fn clone_dir(from: &Path, to: &Path) -> Result<()> {
+    let flags: &[&str] = if cfg!(target_os = "macos") {
+        &["-cR"]
+    } else {
+        &["-R", "--reflink=auto"]
+    };
+    let cloned = cfg!(unix)
+        && Command::new("cp")
+            .args(flags)
+            .arg(from)
+            .arg(to)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success());
+    if !cloned {
+        let _ = std::fs::remove_dir_all(to);
+        copy_tree(from, to)
+            .with_context(|| format!("Failed to copy {} to {}", from.display(), to.display()))?;
+    }
+    Ok(())
+}
+
+/// Symlink `link` to the directory `target`.
+pub(super) fn link_dir(target: &Path, link: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    return std::os::unix::fs::symlink(target, link);
+    #[cfg(windows)]
+    return std::os::windows::fs::symlink_dir(target, link);
+}
@@ -0,0 +1,37 @@
+use std::path::{Component, Path};
+
+use super::CacheConfig;
+use crate::workspace_env::is_var_name;
+
+impl CacheConfig {
+    /// Check that variable names are valid and that worktree paths stay
+    /// inside the worktree.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the first offending entry.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(k) = self.env.keys().find(|k| !is_var_name(k)) {
+            return Err(format!(
+                "`cache.env` key `{k}` is not a valid variable name"
+            ));
+        }
+        let inside = |p: &str| {
+            !p.is_empty()
+                && Path::new(p)
+                    .components()
+                    .all(|c| matches!(c, Component::Normal(_)))
+        };
+        let paths = self
+            .link
+            .keys()
+            .map(|k| ("cache.link", k))
+            .chain(self.sibling.iter().map(|s| ("cache.sibling", s)));
+        for (key, p) in paths {
+            if !inside(p) {
+                return Err(format!("`{key}` path `{p}` must stay inside the worktree"));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
 use clap::{Parser, Subcommand};
 
-use crate::commands::config::ConfigAction;
-use crate::commands::scheme::SchemeAction;
+use crate::commands::{
+    clean::CleanArgs, config::ConfigAction, doctor::DoctorArgs, gc::GcArgs,
+    hibernate::HibernateArgs, import::ImportArgs, list::ListArgs, mv::MvArgs, open::OpenArgs,
+    pin::PinArgs, prune::PruneArgs, remove::RemoveArgs, restore::RestoreArgs, run::RunArgs,
+    scheme::SchemeAction, status::StatusArgs, sync::SyncArgs, trash::TrashAction,
+    unarchive::UnarchiveArgs,
+};
 
 #[derive(Parser)]
 #[command(
@@ -17,29 +22,7 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Parse an issue reference, create a worktree, and open it
-    Open {
-        /// Issue reference (omit to detect from current repo's origin remote)
-        #[arg(value_name = "REF")]
-        issue_ref: Option<String>,
-        /// Force open in editor
-        #[arg(long)]
-        editor: bool,
-        /// Skip pre/post-open hooks
-        #[arg(long)]
-        no_hooks: bool,
-        /// Skip opening editor/terminal (hooks still run); useful for programmatic invocation
-        #[arg(long)]
-        headless: bool,
-        /// Run a script from .worktree/ as post:open, replacing all other hooks
-        #[arg(long, value_name = "NAME")]
-        script: Option<String>,
-        /// Environment variables to inject (KEY=VALUE), may be repeated
-        #[arg(long = "env", value_name = "KEY=VALUE", action = clap::ArgAction::Append)]
-        env: Vec<String>,
-        /// Output JSON with worktree path and created flag instead of human-readable text
-        #[arg(long)]
-        json: bool,
-    },
+    Open(OpenArgs),
     /// Open multiple repos as a unified workspace under ~/workspaces/<name>/
     #[command(name = "open-multi")]
     OpenMulti {
@@ -61,19 +44,45 @@ pub enum Commands {
         action: SchemeAction,
     },
     /// List all registered workspaces with their TTL status
-    List {
-        /// Emit a JSON report to stdout instead of human-readable output
-        #[arg(long)]
-        json: bool,
-    },
-    /// Remove expired worktrees based on workspace.ttl config
-    Prune {
-        /// Emit a JSON report to stdout instead of human-readable output
-        #[arg(long)]
-        json: bool,
-    },
+    List(ListArgs),
+    /// Show branch, changes, ahead/behind, last commit and size per workspace
+    Status(StatusArgs),
+    /// Fetch, then rebase or merge each clean workspace onto its base branch
+    Sync(SyncArgs),
+    /// Remove expired or merged worktrees (see `workspace.prune_policy`)
+    Prune(PruneArgs),
+    /// Remove a single workspace, its git worktree, and its registry entry
+    Remove(RemoveArgs),
+    /// Run a command in a workspace with its saved environment, e.g. `run acme/api#42 -- make`
+    Run(RunArgs),
+    /// Move or rename a workspace, e.g. from an ad-hoc name to an issue's
+    Mv(MvArgs),
+    /// Exempt a workspace from pruning and lock its git worktree
+    Pin(PinArgs),
+    /// Make a pinned workspace prunable again and unlock its git worktree
+    Unpin(PinArgs),
+    /// Delete build artifacts (`target/`, `node_modules/`, …) from idle workspaces
+    Clean(CleanArgs),
+    /// Stash a workspace's local changes and delete its tree until the next `open`
+    Hibernate(HibernateArgs),
     /// Restore worktrees whose directories were manually deleted
-    Restore,
+    Restore(RestoreArgs),
+    /// List, restore, or empty workspaces moved to the trash by `prune` or
+    /// `remove`
+    Trash {
+        #[command(subcommand)]
+        action: TrashAction,
+    },
+    /// Restore the workspace most recently moved to the trash
+    Undo,
+    /// Recreate a workspace archived by a forced `prune` or `remove`, or list archives
+    Unarchive(UnarchiveArgs),
+    /// Register worktrees created outside this tool (e.g. `git worktree add`)
+    Import(ImportArgs),
+    /// Delete bare clones no workspace uses any more and `git gc` the rest
+    Gc(GcArgs),
+    /// Check git, the config, the editor, the URL scheme, and the registry for problems
+    Doctor(DoctorArgs),
     /// Run first-time setup: detect editor, write config, register URL scheme
     Setup,
     /// Print the current version
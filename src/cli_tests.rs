@@ -9,8 +9,8 @@ fn parse(args: &[&str]) -> Commands {
 fn test_open_env_single() {
     let cmd = parse(&["open", "acme/repo#1", "--env", "FOO=bar"]);
     match cmd {
-        Commands::Open { env, .. } => {
-            assert_eq!(env, vec!["FOO=bar"]);
+        Commands::Open(args) => {
+            assert_eq!(args.env, vec!["FOO=bar"]);
         }
         _other => unreachable!("unexpected command variant"),
     }
@@ -27,8 +27,8 @@ fn test_open_env_multiple() {
         "BAZ=qux",
     ]);
     match cmd {
-        Commands::Open { env, .. } => {
-            assert_eq!(env, vec!["FOO=bar", "BAZ=qux"]);
+        Commands::Open(args) => {
+            assert_eq!(args.env, vec!["FOO=bar", "BAZ=qux"]);
         }
         _other => unreachable!("unexpected command variant"),
     }
@@ -38,8 +38,8 @@ fn test_open_env_multiple() {
 fn test_open_env_empty_by_default() {
     let cmd = parse(&["open", "acme/repo#1"]);
     match cmd {
-        Commands::Open { env, .. } => {
-            assert!(env.is_empty());
+        Commands::Open(args) => {
+            assert!(args.env.is_empty());
         }
         _other => unreachable!("unexpected command variant"),
     }
@@ -49,8 +49,8 @@ fn test_open_env_empty_by_default() {
 fn test_open_json_flag() {
     let cmd = parse(&["open", "acme/repo#1", "--json"]);
     match cmd {
-        Commands::Open { json, .. } => {
-            assert!(json);
+        Commands::Open(args) => {
+            assert!(args.json);
         }
         _other => unreachable!("unexpected command variant"),
     }
@@ -60,8 +60,8 @@ fn test_open_json_flag() {
 fn test_open_json_false_by_default() {
     let cmd = parse(&["open", "acme/repo#1"]);
     match cmd {
-        Commands::Open { json, .. } => {
-            assert!(!json);
+        Commands::Open(args) => {
+            assert!(!args.json);
         }
         _other => unreachable!("unexpected command variant"),
     }
@@ -78,15 +78,10 @@ fn test_open_env_and_json_together() {
         "--headless",
     ]);
     match cmd {
-        Commands::Open {
-            env,
-            json,
-            headless,
-            ..
-        } => {
-            assert_eq!(env, vec!["KEY=val"]);
-            assert!(json);
-            assert!(headless);
+        Commands::Open(args) => {
+            assert_eq!(args.env, vec!["KEY=val"]);
+            assert!(args.json);
+            assert!(args.headless);
         }
         _other => unreachable!("unexpected command variant"),
     }
@@ -0,0 +1,86 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use anyhow::{bail, Result};
+use clap::Args;
+use worktree_io::config::Config;
+use worktree_io::lifecycle::find_artifacts;
+use worktree_io::ttl::{is_expired, Ttl, TtlMode, WorkspaceRegistry};
+
+use super::prune::dir_size;
+
+mod report;
+
+use report::CleanReport;
+
+#[derive(Args)]
+pub struct CleanArgs {
+    /// Clean workspaces idle at least this long, instead of
+    /// `workspace.clean_after`
+    #[arg(long, value_name = "DURATION")]
+    idle: Option<Ttl>,
+    /// Report what would be deleted, with sizes, without deleting anything
+    #[arg(long)]
+    dry_run: bool,
+    /// Emit a JSON report to stdout instead of human-readable output
+    #[arg(long)]
+    json: bool,
+}
+
+/// Delete build artifacts such as `target/` and `node_modules/` from
+/// workspaces idle longer than `--idle` or `workspace.clean_after`, keeping
+/// the worktrees themselves. Pinned workspaces are left alone, and only paths
+/// git ignores are deleted.
+pub fn cmd_clean(args: &CleanArgs) -> Result<()> {
+    let cfg = Config::load()?.workspace;
+    let Some(idle) = args.idle.or(cfg.clean_after) else {
+        bail!(
+            "No idle time configured. Pass --idle or set workspace.clean_after (e.g. \"3days\")."
+        );
+    };
+    let mut registry = WorkspaceRegistry::load()?;
+    let now = SystemTime::now();
+    let mut report = CleanReport::new(args.json, args.dry_run, idle);
+    for record in &mut registry.workspace {
+        let idle_long = is_expired(record, &idle, TtlMode::Idle, now);
+        if record.pinned || !record.path.exists() || !idle_long {
+            continue;
+        }
+        let artifacts = find_artifacts(&record.path);
+        if artifacts.is_empty() {
+            continue;
+        }
+        let mut removed = Vec::new();
+        for artifact in artifacts {
+            let size = artifact_size(&artifact);
+            match delete(&artifact, args.dry_run) {
+                Ok(()) => removed.push((artifact, size)),
+                Err(e) => eprintln!("Warning: failed to delete {}: {e}", artifact.display()),
+            }
+        }
+        if !args.dry_run {
+            record.size = None;
+        }
+        report.cleaned(&record.path, &removed);
+    }
+    registry.save()?;
+    report.finish(registry.workspace.len());
+    Ok(())
+}
+
+/// Size in bytes of the file or directory at `path`; symlinks count as zero.
+fn artifact_size(path: &Path) -> u64 {
+    match std::fs::symlink_metadata(path) {
+        Ok(m) if m.is_dir() => dir_size(path),
+        Ok(m) if m.is_file() => m.len(),
+        _ => 0,
+    }
+}
+
+fn delete(path: &Path, dry_run: bool) -> std::io::Result<()> {
+    match std::fs::symlink_metadata(path)? {
+        _ if dry_run => Ok(()),
+        m if m.is_dir() => std::fs::remove_dir_all(path),
+        _ => std::fs::remove_file(path),
+    }
+}
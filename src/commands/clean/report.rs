@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+
+use worktree_io::ttl::Ttl;
+
+use super::super::json::escape;
+use super::super::prune::format_bytes;
+
+/// Collects the outcome of a clean run and prints it as text or JSON.
+pub struct CleanReport {
+    json: bool,
+    dry_run: bool,
+    idle: Ttl,
+    cleaned: Vec<String>,
+    total_freed: u64,
+}
+
+impl CleanReport {
+    pub const fn new(json: bool, dry_run: bool, idle: Ttl) -> Self {
+        Self {
+            json,
+            dry_run,
+            idle,
+            cleaned: Vec::new(),
+            total_freed: 0,
+        }
+    }
+
+    /// Record the artifacts deleted from the workspace at `path`, or with
+    /// `--dry-run` the ones that would be, with their sizes.
+    pub fn cleaned(&mut self, path: &Path, removed: &[(PathBuf, u64)]) {
+        let freed: u64 = removed.iter().map(|(_, size)| size).sum();
+        self.total_freed += freed;
+        let names: Vec<String> = removed
+            .iter()
+            .map(|(p, _)| p.strip_prefix(path).unwrap_or(p).display().to_string())
+            .collect();
+        let list: Vec<String> = names.iter().map(|n| format!("\"{}\"", escape(n))).collect();
+        self.cleaned.push(format!(
+            "{{\"path\":\"{}\",\"removed\":[{}],\"freed_bytes\":{freed}}}",
+            escape(&path.display().to_string()),
+            list.join(",")
+        ));
+        if self.json {
+            return;
+        }
+        let (path, names, size) = (path.display(), names.join(", "), format_bytes(freed));
+        if self.dry_run {
+            eprintln!("Would clean {path}: {names} (size: {size})");
+        } else {
+            eprintln!("Cleaned {path}: {names} (freed: {size})");
+        }
+    }
+
+    pub fn finish(&self, checked: usize) {
+        let (n, idle, freed) = (self.cleaned.len(), self.idle, self.total_freed);
+        if self.json {
+            let cleaned = self.cleaned.join(",");
+            println!("{{\"checked\":{checked},\"dry_run\":{},\"idle\":\"{idle}\",\"cleaned\":[{cleaned}],\"total_freed_bytes\":{freed}}}", self.dry_run);
+            return;
+        }
+        let freed = format_bytes(freed);
+        if n == 0 {
+            eprintln!("Checked {checked} workspace(s), none idle for {idle} with build artifacts.");
+        } else if self.dry_run {
+            eprintln!("Would clean {n} idle workspace(s), freeing {freed}. Nothing was removed.");
+        } else {
+            eprintln!("Cleaned {n} idle workspace(s). Total freed: {freed}.");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+use worktree_io::ttl::WorkspaceRegistry;
+
+use super::registry::repos;
+use super::{Check, Level};
+
+/// Bare clones that back no registered workspace.
+pub fn unregistered(registry: &WorkspaceRegistry, clones: &[PathBuf]) -> Check {
+    let used = |bare: &Path| {
+        let mut records = registry.workspace.iter();
+        records.any(|r| r.path.starts_with(bare) || repos(r).any(|p| p == bare))
+    };
+    let unused: Vec<String> = (clones.iter().filter(|b| !used(b)))
+        .map(|b| b.display().to_string())
+        .collect();
+    if clones.is_empty() {
+        return Check::new(Level::Pass, "bare clones", "none found");
+    }
+    if unused.is_empty() {
+        let n = clones.len();
+        return Check::new(Level::Pass, "bare clones", format!("{n} found, all in use"));
+    }
+    let detail = format!(
+        "{} back no registered workspace; `worktree import` registers their worktrees \
+         and `worktree gc` deletes unused ones",
+        unused.join(", ")
+    );
+    Check::new(Level::Warn, "bare clones", detail)
+}
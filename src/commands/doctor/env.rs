@@ -0,0 +1,88 @@
+use std::process::Command;
+
+use worktree_io::config::Config;
+use worktree_io::opener::{augmented_path, resolve_editor_command};
+use worktree_io::scheme::{self, SchemeStatus};
+
+use super::{Check, Level};
+
+/// The oldest git with every `git worktree` feature used here; `list` only
+/// reports stale entries from 2.31 on.
+const FULL_SUPPORT: (u32, u32) = (2, 31);
+/// The oldest git that can remove worktrees at all.
+const MIN_SUPPORT: (u32, u32) = (2, 17);
+
+/// Checks of git, the config file, the editor, and the URL scheme handler.
+pub fn checks() -> Vec<Check> {
+    let config = Config::load();
+    let editor = config.as_ref().map_or_else(
+        |_| Check::new(Level::Warn, "editor", "skipped; the config did not load"),
+        editor,
+    );
+    let config = match config {
+        Ok(_) => Check::new(Level::Pass, "config", "parsed"),
+        Err(e) => Check::new(Level::Fail, "config", format!("{e:#}")),
+    };
+    vec![git(), config, editor, scheme()]
+}
+
+fn git() -> Check {
+    let Ok(out) = Command::new("git").arg("--version").output() else {
+        return Check::new(Level::Fail, "git", "not found on PATH");
+    };
+    let text = String::from_utf8_lossy(&out.stdout).trim().to_owned();
+    let mut numbers = text
+        .trim_start_matches("git version ")
+        .split(|c: char| !c.is_ascii_digit())
+        .map_while(|n| n.parse::<u32>().ok());
+    let version = (numbers.next().unwrap_or(0), numbers.next().unwrap_or(0));
+    if version >= FULL_SUPPORT {
+        Check::new(Level::Pass, "git", text)
+    } else if version >= MIN_SUPPORT {
+        let (major, minor) = FULL_SUPPORT;
+        let detail = format!("{text}; trash restore and stale-entry checks need {major}.{minor}+");
+        Check::new(Level::Warn, "git", detail)
+    } else {
+        let (major, minor) = MIN_SUPPORT;
+        Check::new(
+            Level::Fail,
+            "git",
+            format!("{text}; `git worktree` needs {major}.{minor}+"),
+        )
+    }
+}
+
+fn editor(config: &Config) -> Check {
+    let Some(command) = config.editor.command.as_deref() else {
+        let detail = "none configured; run `worktree setup` to pick one";
+        return Check::new(Level::Warn, "editor", detail);
+    };
+    let resolved = resolve_editor_command(command);
+    let program = resolved.split_whitespace().next().unwrap_or_default();
+    let cwd = std::env::current_dir().unwrap_or_default();
+    which::which_in(program, Some(augmented_path()), cwd).map_or_else(
+        |_| {
+            let detail = format!("`{program}` (from editor.command = {command:?}) is not on PATH");
+            Check::new(Level::Fail, "editor", detail)
+        },
+        |path| {
+            Check::new(
+                Level::Pass,
+                "editor",
+                format!("{program} -> {}", path.display()),
+            )
+        },
+    )
+}
+
+fn scheme() -> Check {
+    match scheme::status() {
+        Ok(SchemeStatus::Installed { path }) => Check::new(Level::Pass, "scheme", path),
+        Ok(SchemeStatus::NotInstalled) => Check::new(
+            Level::Warn,
+            "scheme",
+            "worktree:// is not registered; run `worktree setup`",
+        ),
+        Err(e) => Check::new(Level::Fail, "scheme", format!("{e:#}")),
+    }
+}
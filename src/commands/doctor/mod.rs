@@ -0,0 +1,67 @@
+use anyhow::{bail, Result};
+use clap::Args;
+
+mod clones;
+mod env;
+mod registry;
+mod stale;
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// Apply the safe repairs: prune stale git worktree entries and drop
+    /// registry entries that have nothing left to restore from
+    #[arg(long)]
+    fix: bool,
+}
+
+/// How a check came out.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// The outcome of one diagnostic, e.g. `WARN  scheme: not registered`.
+pub struct Check {
+    level: Level,
+    name: &'static str,
+    detail: String,
+}
+
+impl Check {
+    pub fn new(level: Level, name: &'static str, detail: impl Into<String>) -> Self {
+        let detail = detail.into();
+        Self {
+            level,
+            name,
+            detail,
+        }
+    }
+}
+
+/// Check the environment, configuration, and registry that `worktree` relies
+/// on, and report each as pass, warn, or fail. With `--fix` the safe repairs
+/// are applied along the way. Fails when any check fails.
+pub fn cmd_doctor(args: &DoctorArgs) -> Result<()> {
+    let mut checks = env::checks();
+    checks.extend(registry::checks(args.fix));
+    let count = |level| checks.iter().filter(|c| c.level == level).count();
+    let (passed, warned, failed) = (count(Level::Pass), count(Level::Warn), count(Level::Fail));
+    for c in &checks {
+        let level = match c.level {
+            Level::Pass => "PASS",
+            Level::Warn => "WARN",
+            Level::Fail => "FAIL",
+        };
+        eprintln!("{level}  {}: {}", c.name, c.detail);
+    }
+    eprintln!("{passed} passed, {warned} warning(s), {failed} failed.");
+    if warned > 0 && !args.fix && checks.iter().any(|c| c.detail.contains("--fix")) {
+        eprintln!("Run `worktree doctor --fix` to apply the safe repairs.");
+    }
+    if failed > 0 {
+        bail!("{failed} check(s) failed");
+    }
+    Ok(())
+}
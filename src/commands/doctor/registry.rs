@@ -0,0 +1,89 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use worktree_io::git::find_bare_clones;
+use worktree_io::issue::worktrees_root;
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+use super::clones::unregistered;
+use super::stale::stale;
+use super::{Check, Level};
+
+/// Checks of the registry against the disk: entries whose directory is gone,
+/// bare clones no entry uses, and stale `git worktree` entries.
+pub fn checks(fix: bool) -> Vec<Check> {
+    let mut registry = match WorkspaceRegistry::load() {
+        Ok(r) => r,
+        Err(e) => return vec![Check::new(Level::Fail, "registry", format!("{e:#}"))],
+    };
+    let clones = find_bare_clones(&worktrees_root());
+    let mut checks = vec![
+        missing(&mut registry, fix),
+        unregistered(&registry, &clones),
+    ];
+    let recorded = registry.workspace.iter().flat_map(repos);
+    let all: BTreeSet<PathBuf> = clones.into_iter().chain(recorded).collect();
+    checks.push(stale(&all, fix));
+    if let Err(e) = fix.then(|| registry.save()).transpose() {
+        checks.push(Check::new(Level::Fail, "registry", format!("{e:#}")));
+    }
+    checks
+}
+
+/// The existing repositories `r` was created from.
+pub(super) fn repos(r: &WorkspaceRecord) -> impl Iterator<Item = PathBuf> + '_ {
+    r.repos().filter(|p| p.exists()).map(Path::to_path_buf)
+}
+
+fn names(entries: &[&(PathBuf, bool)]) -> String {
+    let shown: Vec<String> = entries
+        .iter()
+        .map(|(p, _)| p.display().to_string())
+        .collect();
+    shown.join(", ")
+}
+
+/// Entries whose directory is gone, other than trashed and hibernated ones.
+/// Those whose repository is gone too can only be dropped.
+fn missing(registry: &mut WorkspaceRegistry, fix: bool) -> Check {
+    let gone: Vec<(PathBuf, bool)> = registry
+        .workspace
+        .iter()
+        .filter(|r| !r.path.exists() && r.trashed_at.is_none() && r.hibernated.is_none())
+        .map(|r| (r.path.clone(), repos(r).next().is_some()))
+        .collect();
+    if gone.is_empty() {
+        let total = registry.workspace.len();
+        return Check::new(
+            Level::Pass,
+            "registry",
+            format!("{total} workspace(s), all present"),
+        );
+    }
+    let (restorable, lost): (Vec<_>, Vec<_>) = gone.iter().partition(|(_, repo)| *repo);
+    let mut notes = Vec::new();
+    if !restorable.is_empty() {
+        let paths = names(&restorable);
+        notes.push(format!(
+            "missing on disk: {paths}; `worktree restore` rebuilds them"
+        ));
+    }
+    if !lost.is_empty() && fix {
+        registry
+            .workspace
+            .retain(|r| !lost.iter().any(|(p, _)| *p == r.path));
+        notes.push(format!(
+            "dropped {}, which had nothing to restore from",
+            names(&lost)
+        ));
+    } else if !lost.is_empty() {
+        let paths = names(&lost);
+        notes.push(format!("{paths} cannot be restored; `--fix` drops them"));
+    }
+    let level = if restorable.is_empty() && fix {
+        Level::Pass
+    } else {
+        Level::Warn
+    };
+    Check::new(level, "registry", notes.join("; "))
+}
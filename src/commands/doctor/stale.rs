@@ -0,0 +1,44 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use worktree_io::git::{git_worktree_prune, list_worktrees};
+
+use super::{Check, Level};
+
+/// `git worktree` entries whose directory is gone, which block re-adding the
+/// same branch. Locked ones are left to their owner.
+pub fn stale(repos: &BTreeSet<PathBuf>, fix: bool) -> Check {
+    let mut found = Vec::new();
+    for repo in repos {
+        match list_worktrees(repo) {
+            Ok(entries) => {
+                let n = entries.iter().filter(|e| e.prunable && !e.locked).count();
+                if n > 0 {
+                    found.push((repo, n));
+                }
+            }
+            Err(e) => return Check::new(Level::Warn, "git worktrees", format!("{e:#}")),
+        }
+    }
+    let total: usize = found.iter().map(|(_, n)| n).sum();
+    if total == 0 {
+        let detail = format!("no stale entries in {} repositories", repos.len());
+        return Check::new(Level::Pass, "git worktrees", detail);
+    }
+    if fix {
+        if let Some(e) = found.iter().find_map(|(r, _)| git_worktree_prune(r).err()) {
+            return Check::new(Level::Fail, "git worktrees", format!("{e:#}"));
+        }
+        return Check::new(
+            Level::Pass,
+            "git worktrees",
+            format!("pruned {total} stale entries"),
+        );
+    }
+    let repos: Vec<String> = found.iter().map(|(r, _)| r.display().to_string()).collect();
+    let detail = format!(
+        "{total} stale entries in {}; `--fix` prunes them",
+        repos.join(", ")
+    );
+    Check::new(Level::Warn, "git worktrees", detail)
+}
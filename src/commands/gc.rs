@@ -0,0 +1,99 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+use clap::Args;
+use worktree_io::config::Config;
+use worktree_io::git::{find_bare_clones, git_gc};
+use worktree_io::issue::worktrees_root;
+use worktree_io::lifecycle::{bare_clone_in_use, last_activity};
+use worktree_io::ttl::{Ttl, WorkspaceRegistry};
+
+use super::prune::{dir_size, format_bytes};
+
+/// Grace period used when neither `--grace` nor `workspace.gc_grace` is set.
+const DEFAULT_GRACE: Duration = Duration::from_hours(7 * 24);
+
+#[derive(Args)]
+pub struct GcArgs {
+    /// Keep unused bare clones active within this long [default: `workspace.gc_grace` or 7days]
+    #[arg(long, value_name = "DURATION")]
+    grace: Option<Ttl>,
+}
+
+/// Delete bare clones that no workspace uses any more once they have been
+/// idle for the grace period, and `git gc` the ones that are kept.
+pub fn cmd_gc(args: &GcArgs) -> Result<()> {
+    let config = Config::load()?;
+    let grace = args
+        .grace
+        .or(config.workspace.gc_grace)
+        .unwrap_or(Ttl::new(DEFAULT_GRACE));
+    let registry = WorkspaceRegistry::load()?;
+    let root = worktrees_root();
+    let clones = find_bare_clones(&root);
+    let now = SystemTime::now();
+    let (mut removed, mut compacted, mut total_freed) = (0usize, 0usize, 0u64);
+    for bare in &clones {
+        let before = dir_size(bare);
+        match bare_clone_in_use(bare, &registry) {
+            Ok(true) => {}
+            Ok(false) => {
+                let idle = now.duration_since(last_activity(bare)).unwrap_or_default();
+                let idle_str = humantime::format_duration(Duration::from_secs(idle.as_secs()));
+                if idle >= grace.duration() {
+                    if let Err(e) = remove_clone(&root, bare) {
+                        eprintln!("Warning: failed to remove {}: {e}", bare.display());
+                        continue;
+                    }
+                    let (path, freed) = (bare.display(), format_bytes(before));
+                    eprintln!(
+                        "Removed unused bare clone {path} (idle: {idle_str}, freed: {freed})"
+                    );
+                    removed += 1;
+                    total_freed += before;
+                    continue;
+                }
+                eprintln!(
+                    "Keeping unused bare clone {} (idle: {idle_str}, grace: {grace})",
+                    bare.display()
+                );
+            }
+            Err(e) => {
+                eprintln!("Warning: skipping {}: {e:#}", bare.display());
+                continue;
+            }
+        }
+        if let Err(e) = git_gc(bare) {
+            eprintln!("Warning: git gc failed in {}: {e:#}", bare.display());
+            continue;
+        }
+        let freed = before.saturating_sub(dir_size(bare));
+        eprintln!(
+            "Compacted {} (freed: {})",
+            bare.display(),
+            format_bytes(freed)
+        );
+        compacted += 1;
+        total_freed += freed;
+    }
+    eprintln!(
+        "Checked {} bare clone(s): removed {removed}, compacted {compacted}. Total freed: {}.",
+        clones.len(),
+        format_bytes(total_freed)
+    );
+    Ok(())
+}
+
+/// Delete `bare` along with any parent directories it leaves empty, up to
+/// (but not including) `root`.
+fn remove_clone(root: &Path, bare: &Path) -> std::io::Result<()> {
+    std::fs::remove_dir_all(bare)?;
+    let parents = bare.ancestors().skip(1);
+    for dir in parents.take_while(|d| *d != root && d.starts_with(root)) {
+        if std::fs::remove_dir(dir).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::time::SystemTime;
+
+use anyhow::{bail, Result};
+use clap::Args;
+use worktree_io::lifecycle::hibernate_workspace;
+use worktree_io::ttl::WorkspaceRegistry;
+
+use super::target::resolve_target;
+
+#[derive(Args)]
+pub struct HibernateArgs {
+    /// Issue reference or path of the workspace to hibernate
+    #[arg(value_name = "REF|PATH")]
+    target: String,
+}
+
+/// Hibernate a registered workspace: stash its local changes into its
+/// repository and delete its working tree. The next `worktree open` of the
+/// same reference wakes it.
+pub fn cmd_hibernate(args: &HibernateArgs) -> Result<()> {
+    let path = resolve_target(&args.target)?;
+    let mut registry = WorkspaceRegistry::load()?;
+    let Some(record) = registry.get_mut(&path) else {
+        bail!("No workspace registered at {}", path.display());
+    };
+    if record.hibernated.is_some() {
+        bail!("{} is already hibernated.", path.display());
+    }
+    if record.pinned {
+        bail!(
+            "Refusing to hibernate {} — it is pinned. Run `worktree unpin` first.",
+            path.display()
+        );
+    }
+    hibernate_workspace(record, SystemTime::now())?;
+    let saved = record
+        .hibernated
+        .as_ref()
+        .is_some_and(|h| h.stash.is_some());
+    registry.save()?;
+    let changes = if saved { " with its local changes" } else { "" };
+    eprintln!(
+        "Hibernated {}{changes}; `worktree open` wakes it.",
+        path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use worktree_io::git::{find_bare_clones, list_worktrees};
+use worktree_io::issue::{worktrees_root, IssueRef};
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+#[derive(Args)]
+pub struct ImportArgs {
+    /// Extra repositories to scan in addition to the managed bare clones
+    #[arg(value_name = "REPO")]
+    repos: Vec<PathBuf>,
+}
+
+/// Register worktrees that were created outside this tool.
+///
+/// Scans every managed bare clone under the worktrees root plus any extra
+/// `repos` with `git worktree list --porcelain`, and adds each worktree that
+/// is not yet in the registry. The issue is inferred from the branch name and
+/// the directory's modification time stands in for `created_at`.
+pub fn cmd_import(args: &ImportArgs) -> Result<()> {
+    let root = worktrees_root();
+    let mut sources = find_bare_clones(&root);
+    for repo in &args.repos {
+        let abs = std::fs::canonicalize(repo)
+            .with_context(|| format!("Repository not found: {}", repo.display()))?;
+        sources.push(abs);
+    }
+    let mut registry = WorkspaceRegistry::load()?;
+    let mut imported = 0usize;
+    for repo in &sources {
+        imported += import_from(&root, repo, &mut registry);
+    }
+    if imported > 0 {
+        registry.save()?;
+    }
+    eprintln!(
+        "Scanned {} repo(s), imported {imported} worktree(s).",
+        sources.len()
+    );
+    Ok(())
+}
+
+fn import_from(root: &Path, repo: &Path, registry: &mut WorkspaceRegistry) -> usize {
+    let entries = match list_worktrees(repo) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Warning: skipping {}: {e}", repo.display());
+            return 0;
+        }
+    };
+    let mut count = 0;
+    // The first entry is the bare repository itself or the main checkout.
+    for wt in entries.iter().skip(1) {
+        if wt.bare || wt.prunable || !wt.path.exists() || registry.contains(&wt.path) {
+            continue;
+        }
+        let branch = wt.branch.as_deref().unwrap_or("");
+        let created_at = modified_at(&wt.path);
+        let jira_host = registry.jira_host(repo).map(str::to_owned);
+        let issue = IssueRef::infer(root, repo, branch, jira_host.as_deref());
+        let mut record = issue.map_or_else(
+            || WorkspaceRecord::new(wt.path.clone(), created_at),
+            |issue| WorkspaceRecord::for_issue(&issue, wt.path.clone(), created_at),
+        );
+        record.branch.clone_from(&wt.branch);
+        record.bare_path = Some(repo.to_path_buf());
+        let guess = record
+            .issue
+            .as_ref()
+            .map_or_else(|| "issue unknown".to_owned(), IssueRef::branch_name);
+        eprintln!("Imported {} ({guess})", wt.path.display());
+        registry.insert(record);
+        count += 1;
+    }
+    count
+}
+
+fn modified_at(path: &Path) -> SystemTime {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .unwrap_or_else(|_| SystemTime::now())
+}
@@ -0,0 +1,15 @@
+/// Escape `s` for use inside a JSON string literal.
+pub fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_owned(),
+            '"' => "\\\"".to_owned(),
+            c if c.is_control() => format!("\\u{:04x}", u32::from(c)),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "json_tests.rs"]
+mod tests;
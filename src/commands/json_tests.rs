@@ -0,0 +1,8 @@
+use super::*;
+
+#[test]
+fn test_escape_quotes_backslashes_and_control_chars() {
+    assert_eq!(escape(r#"say "hi" \o/"#), r#"say \"hi\" \\o/"#);
+    assert_eq!(escape("a\tb"), "a\\u0009b");
+    assert_eq!(escape("fatal: x\nhint: y"), "fatal: x\\u000ahint: y");
+}
@@ -1,23 +1,32 @@
 use std::time::SystemTime;
 
 use anyhow::Result;
+use clap::Args;
 use worktree_io::config::Config;
-use worktree_io::ttl::{self, Ttl, WorkspaceRecord, WorkspaceRegistry};
+use worktree_io::ttl::{self, Ttl, TtlMode, WorkspaceRecord, WorkspaceRegistry};
 
-pub fn cmd_list(json: bool) -> Result<()> {
+#[derive(Args)]
+pub struct ListArgs {
+    /// Emit a JSON report to stdout instead of human-readable output
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn cmd_list(args: &ListArgs) -> Result<()> {
     let config = Config::load()?;
     let ttl = config.workspace.ttl;
+    let mode = config.workspace.ttl_mode;
     let now = SystemTime::now();
     let registry = WorkspaceRegistry::load()?;
-    if json {
-        print_json(&registry.workspace, ttl.as_ref(), now);
+    if args.json {
+        print_json(&registry.workspace, ttl.as_ref(), mode, now);
     } else {
-        print_human(&registry.workspace, ttl.as_ref(), now);
+        print_human(&registry.workspace, ttl.as_ref(), mode, now);
     }
     Ok(())
 }
 
-fn print_json(workspaces: &[WorkspaceRecord], ttl: Option<&Ttl>, now: SystemTime) {
+fn print_json(workspaces: &[WorkspaceRecord], ttl: Option<&Ttl>, mode: TtlMode, now: SystemTime) {
     let ttl_str = ttl.map_or_else(|| "null".to_owned(), |t| format!("\"{t}\""));
     let entries: Vec<String> = workspaces
         .iter()
@@ -25,46 +34,63 @@ fn print_json(workspaces: &[WorkspaceRecord], ttl: Option<&Ttl>, now: SystemTime
             let path_str = r.path.display().to_string();
             let path_esc = path_str.replace('\\', "\\\\").replace('"', "\\\"");
             let created = humantime::format_rfc3339(r.created_at);
-            let expired = ttl.is_some_and(|t| ttl::is_expired(r, t, now));
+            let opened = r.last_opened_at.map_or_else(
+                || "null".to_owned(),
+                |t| format!("\"{}\"", humantime::format_rfc3339(t)),
+            );
+            let own = r.ttl.map_or_else(|| "null".to_owned(), |t| format!("\"{t}\""));
+            let expired = r.ttl.as_ref().or(ttl).is_some_and(|t| ttl::is_expired(r, t, mode, now));
+            let (pinned, trashed) = (r.pinned, r.trashed_at.is_some());
+            let hibernated = r.hibernated.is_some();
             format!(
-                "{{\"path\":\"{path_esc}\",\"created_at\":\"{created}\",\"expired\":{expired}}}"
+                "{{\"path\":\"{path_esc}\",\"created_at\":\"{created}\",\"last_opened_at\":{opened},\"ttl\":{own},\"expired\":{expired},\"pinned\":{pinned},\"trashed\":{trashed},\"hibernated\":{hibernated}}}"
             )
         })
         .collect();
     println!(
-        "{{\"ttl\":{ttl_str},\"workspaces\":[{}]}}",
+        "{{\"ttl\":{ttl_str},\"ttl_mode\":\"{mode}\",\"workspaces\":[{}]}}",
         entries.join(",")
     );
 }
 
-fn print_human(workspaces: &[WorkspaceRecord], ttl: Option<&Ttl>, now: SystemTime) {
+fn print_human(workspaces: &[WorkspaceRecord], ttl: Option<&Ttl>, mode: TtlMode, now: SystemTime) {
     if workspaces.is_empty() {
         eprintln!("No workspaces registered.");
         return;
     }
+    let since = if mode == TtlMode::Idle {
+        " since last open"
+    } else {
+        ""
+    };
     match ttl {
-        Some(t) => eprintln!("{} workspace(s) registered (TTL: {t}):", workspaces.len()),
+        Some(t) => eprintln!(
+            "{} workspace(s) registered (TTL: {t}{since}):",
+            workspaces.len()
+        ),
         None => eprintln!("{} workspace(s) registered:", workspaces.len()),
     }
     for r in workspaces {
         let age = now.duration_since(r.created_at).unwrap_or_default();
-        let age_str = humantime::format_duration(age);
-        match ttl {
-            Some(t) if ttl::is_expired(r, t, now) => {
-                eprintln!("  {}  created {}  EXPIRED", r.path.display(), age_str);
-            }
+        let idle = r.last_opened_at.map_or_else(String::new, |opened| {
+            let idle = now.duration_since(opened).unwrap_or_default();
+            format!("  idle {}", humantime::format_duration(idle))
+        });
+        let age = humantime::format_duration(age);
+        let own = r.ttl.map_or_else(String::new, |t| format!("  TTL {t}"));
+        let line = format!("  {}  created {age}{idle}{own}", r.path.display());
+        match r.ttl.as_ref().or(ttl) {
+            _ if r.trashed_at.is_some() => eprintln!("{line}  TRASHED"),
+            _ if r.hibernated.is_some() => eprintln!("{line}  HIBERNATED"),
+            _ if r.pinned => eprintln!("{line}  PINNED"),
+            Some(t) if ttl::is_expired(r, t, mode, now) => eprintln!("{line}  EXPIRED"),
             Some(t) => {
-                let remaining = t.duration().saturating_sub(age);
-                eprintln!(
-                    "  {}  created {}  expires in {}",
-                    r.path.display(),
-                    age_str,
-                    humantime::format_duration(remaining),
-                );
-            }
-            None => {
-                eprintln!("  {}  created {}", r.path.display(), age_str);
+                let elapsed = now.duration_since(mode.since(r)).unwrap_or_default();
+                let remaining = t.duration().saturating_sub(elapsed);
+                let remaining = humantime::format_duration(remaining);
+                eprintln!("{line}  expires in {remaining}");
             }
+            None => eprintln!("{line}"),
         }
     }
 }
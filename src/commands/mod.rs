@@ -1,8 +1,23 @@
+pub mod clean;
 pub mod config;
+pub mod doctor;
+pub mod gc;
+pub mod hibernate;
+pub mod import;
+mod json;
 pub mod list;
+pub mod mv;
 pub mod open;
 pub mod open_multi;
+pub mod pin;
 pub mod prune;
+pub mod remove;
 pub mod restore;
+pub mod run;
 pub mod scheme;
 pub mod setup;
+pub mod status;
+pub mod sync;
+pub mod target;
+pub mod trash;
+pub mod unarchive;
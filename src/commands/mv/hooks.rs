@@ -0,0 +1,30 @@
+use anyhow::{Context, Result};
+use worktree_io::config::Config;
+use worktree_io::hooks::run_hook;
+use worktree_io::ttl::WorkspaceRecord;
+use worktree_io::workspace_env::workspace_env;
+
+use super::super::open::{build_hook_context, effective_hooks};
+
+/// Run the pre:open and post:open hooks for the workspace `record` describes,
+/// as a headless `open` would.
+pub(super) fn run_open_hooks(record: &WorkspaceRecord) -> Result<()> {
+    let issue = record
+        .issue
+        .as_ref()
+        .context("--hooks needs a workspace opened for an issue")?;
+    let mut ctx = build_hook_context(issue, &record.path);
+    if let Some(branch) = &record.branch {
+        ctx.branch.clone_from(branch);
+    }
+    ctx.port = record.port.map(|p| p.to_string()).unwrap_or_default();
+    ctx.extra_env = workspace_env(Some(issue), &ctx, &record.path);
+    let (pre, post) = effective_hooks(&Config::load()?, &record.path);
+    for (name, script) in [("pre:open", pre), ("post:open", post)] {
+        if let Some(script) = script.as_deref() {
+            eprintln!("Running {name} hook…");
+            run_hook(script, &ctx)?;
+        }
+    }
+    Ok(())
+}
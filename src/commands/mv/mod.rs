@@ -0,0 +1,97 @@
+mod hooks;
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use worktree_io::git::common_dir;
+use worktree_io::issue::IssueRef;
+use worktree_io::lifecycle::move_workspace;
+use worktree_io::ttl::WorkspaceRegistry;
+
+use super::target::resolve_target;
+
+#[derive(Args)]
+pub struct MvArgs {
+    /// Issue reference or path of the workspace to move
+    #[arg(value_name = "FROM")]
+    from: String,
+    /// Issue reference to move the workspace to the path of, or a new path
+    #[arg(value_name = "TO")]
+    to: String,
+    /// Also rename the workspace's branch to NAME
+    #[arg(long, value_name = "NAME", conflicts_with = "rename_branch")]
+    branch: Option<String>,
+    /// Also rename the workspace's branch to the one `open` uses for TO,
+    /// which must be an issue reference
+    #[arg(long)]
+    rename_branch: bool,
+    /// Run the pre:open and post:open hooks in the new location
+    #[arg(long)]
+    hooks: bool,
+}
+
+/// Move or rename a registered workspace with `git worktree move`, keeping
+/// its registry record. No hooks run unless `--hooks` is given.
+pub fn cmd_mv(args: &MvArgs) -> Result<()> {
+    let from = resolve_target(&args.from)?;
+    let mut registry = WorkspaceRegistry::load()?;
+    let Some(mut record) = registry.remove(&from) else {
+        bail!("No workspace registered at {}", from.display());
+    };
+    if record.hibernated.is_some() {
+        bail!(
+            "{} is hibernated; `worktree open` it first.",
+            from.display()
+        );
+    }
+    if !from.exists() {
+        bail!("{} does not exist.", from.display());
+    }
+    let (dest, issue) = destination(&args.to, &from)?;
+    let branch = match (&issue, args.rename_branch) {
+        (Some(issue), true) => Some(issue.branch_name()),
+        (None, true) => bail!("--rename-branch needs an issue reference as TO"),
+        (_, false) => args.branch.clone(),
+    };
+    move_workspace(&mut record, &dest, branch.as_deref())?;
+    if issue.is_some() {
+        record.issue = issue;
+    }
+    registry.insert(record.clone());
+    registry.save()?;
+    let renamed = branch.map_or_else(String::new, |b| format!(" on branch {b}"));
+    eprintln!("Moved {} to {}{renamed}", from.display(), dest.display());
+    if args.hooks {
+        hooks::run_open_hooks(&record)?;
+    }
+    Ok(())
+}
+
+/// Resolve TO to a path and, for an issue reference, the issue. The issue
+/// must belong to the repository of the workspace at `from`.
+fn destination(to: &str, from: &std::path::Path) -> Result<(PathBuf, Option<IssueRef>)> {
+    let issue = match IssueRef::parse(to) {
+        Ok(IssueRef::Adhoc { .. }) | Err(_) => {
+            let path =
+                std::path::absolute(to).with_context(|| format!("Failed to resolve {to}"))?;
+            return Ok((path, None));
+        }
+        Ok(issue) => issue,
+    };
+    let repo = match &issue {
+        IssueRef::Local { project_path, .. } => project_path.clone(),
+        _ => issue.bare_clone_path(),
+    };
+    let actual = std::fs::canonicalize(common_dir(from)?).ok();
+    if ![repo.join(".git"), repo]
+        .iter()
+        .any(|p| std::fs::canonicalize(p).ok() == actual)
+    {
+        bail!(
+            "{to} belongs to a different repository than {}",
+            from.display()
+        );
+    }
+    Ok((issue.temp_path(), Some(issue)))
+}
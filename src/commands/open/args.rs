@@ -0,0 +1,47 @@
+use clap::Args;
+use worktree_io::ttl::Ttl;
+
+#[derive(Args)]
+#[allow(
+    clippy::struct_excessive_bools,
+    reason = "each bool maps directly to a distinct CLI flag"
+)]
+pub struct OpenArgs {
+    /// Issue reference (omit to detect from current repo's origin remote)
+    #[arg(value_name = "REF")]
+    pub issue_ref: Option<String>,
+    /// Force open in editor
+    #[arg(long)]
+    pub editor: bool,
+    /// Skip pre/post-open hooks
+    #[arg(long)]
+    pub no_hooks: bool,
+    /// Skip opening editor/terminal (hooks still run); useful for programmatic invocation
+    #[arg(long)]
+    pub headless: bool,
+    /// Run a script from .worktree/ as post:open, replacing all other hooks
+    #[arg(long, value_name = "NAME")]
+    pub script: Option<String>,
+    /// Environment variables to inject (KEY=VALUE), may be repeated
+    #[arg(long = "env", value_name = "KEY=VALUE", action = clap::ArgAction::Append)]
+    pub env: Vec<String>,
+    /// Output JSON with worktree path and created flag instead of human-readable text
+    #[arg(long)]
+    pub json: bool,
+    /// Time-to-live for this workspace (e.g. `2d`), overriding repo and config TTLs
+    #[arg(long, value_name = "DURATION")]
+    pub ttl: Option<Ttl>,
+}
+
+impl OpenArgs {
+    /// The `--env KEY=VALUE` pairs; entries without `=` are ignored.
+    pub fn extra_env(&self) -> Vec<(String, String)> {
+        self.env
+            .iter()
+            .filter_map(|kv| {
+                let (k, v) = kv.split_once('=')?;
+                Some((k.to_string(), v.to_string()))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,80 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use worktree_io::{
+    config::Config,
+    lifecycle::{hibernate_workspace, preview_prune, select_prunable, Pruned},
+    ttl::{PrunePolicy, WorkspaceRecord, WorkspaceRegistry},
+};
+
+use super::super::trash::{empty_expired_trash, move_to_trash};
+
+/// Move expired workspaces without unsaved work to the trash, and empty the
+/// trash of those past `workspace.trash_retention`. Failures only print
+/// warnings so they never block `open`. The workspace being `opened` is never
+/// pruned.
+///
+/// Merged workspaces are left to `worktree prune`: checking them fetches
+/// every repository, too slow for every `open`.
+///
+/// Under `workspace.hibernate` the workspaces are hibernated instead, unsaved
+/// work and all.
+pub(super) fn run_auto_prune(config: &Config, opened: &Path) {
+    let policy = PrunePolicy {
+        merged: false,
+        ..config.workspace.prune_policy
+    };
+    let ttl = config.workspace.ttl;
+    if !config.workspace.auto_prune || !policy.ttl {
+        return;
+    }
+    let Ok(mut registry) = WorkspaceRegistry::load() else {
+        return;
+    };
+    if let Err(e) = empty_expired_trash(&mut registry, &config.workspace) {
+        eprintln!("Warning: failed to empty the trash: {e:#}");
+    }
+    let (mode, now) = (config.workspace.ttl_mode, SystemTime::now());
+    let opened = registry
+        .get(opened)
+        .map_or_else(|| opened.to_path_buf(), |r| r.path.clone());
+    let mut candidates = select_prunable(&registry.workspace, ttl.as_ref(), mode, policy, now);
+    candidates.retain(|(r, _)| r.path != opened);
+    let hibernate = config.workspace.hibernate;
+    for (record, reason) in candidates {
+        let (path, reason) = (&record.path, reason.as_str());
+        if hibernate {
+            let Some(stored) = registry.get_mut(path) else {
+                continue;
+            };
+            match hibernate_workspace(stored, now) {
+                Ok(()) => eprintln!("Hibernated {reason} workspace at {}", path.display()),
+                Err(e) => eprintln!("Warning: failed to hibernate {}: {e:#}", path.display()),
+            }
+            continue;
+        }
+        match trash_if_clean(&mut registry, &record) {
+            Ok(Pruned::Removed) => {
+                eprintln!(
+                    "Moved {reason} workspace at {} to the trash",
+                    path.display()
+                );
+            }
+            Ok(Pruned::Kept(_)) => eprintln!(
+                "Kept {reason} workspace at {} — it has unsaved work (see `worktree prune`).",
+                path.display()
+            ),
+            Err(e) => eprintln!("Warning: failed to prune {}: {e:#}", path.display()),
+        }
+    }
+    let _ = registry.save();
+}
+
+fn trash_if_clean(registry: &mut WorkspaceRegistry, record: &WorkspaceRecord) -> Result<Pruned> {
+    let outcome = preview_prune(&record.path, false)?;
+    if matches!(outcome, Pruned::Removed) {
+        move_to_trash(registry, record, false)?;
+    }
+    Ok(outcome)
+}
@@ -1,4 +1,5 @@
 use super::*;
+use std::path::Path;
 use worktree_io::ttl::Ttl;
 
 #[test]
@@ -56,14 +57,14 @@ fn test_build_hook_ctx_local() {
 #[test]
 fn test_run_auto_prune_disabled() {
     let config = Config::default();
-    run_auto_prune(&config);
+    run_auto_prune(&config, Path::new("/nonexistent"));
 }
 
 #[test]
 fn test_run_auto_prune_no_ttl() {
     let mut config = Config::default();
     config.workspace.auto_prune = true;
-    run_auto_prune(&config);
+    run_auto_prune(&config, Path::new("/nonexistent"));
 }
 
 #[test]
@@ -72,5 +73,5 @@ fn test_run_auto_prune_with_ttl_no_expired() {
     config.workspace.auto_prune = true;
     // 1000-year TTL: no real registry entry can be this old, so nothing is pruned
     config.workspace.ttl = Some(Ttl::new(std::time::Duration::from_hours(1_000 * 365 * 24)));
-    run_auto_prune(&config);
+    run_auto_prune(&config, Path::new("/nonexistent"));
 }
@@ -3,10 +3,15 @@ use std::path::{Component, Path};
 use worktree_io::{
     config::Config,
     hooks::{run_hook, HookContext},
+    issue::IssueRef,
     opener,
+    ports::assign_port,
     repo_hooks::{combined_script, RepoConfig},
+    workspace_env,
 };
 
+use super::build_hook_context;
+
 pub(super) fn load_worktree_io_script(worktree_path: &Path, name: &str) -> Result<String> {
     let components: Vec<_> = Path::new(name).components().collect();
     if !matches!(components.as_slice(), [Component::Normal(_)]) {
@@ -17,7 +22,7 @@ pub(super) fn load_worktree_io_script(worktree_path: &Path, name: &str) -> Resul
         .with_context(|| format!("script not found: {}", script_path.display()))
 }
 
-pub(super) fn effective_hooks(
+pub fn effective_hooks(
     config: &Config,
     workspace_path: &std::path::Path,
 ) -> (Option<String>, Option<String>) {
@@ -33,6 +38,28 @@ pub(super) fn effective_hooks(
     (pre, post)
 }
 
+/// The hook context for the workspace at `wt`: saves the `env` pairs given
+/// to this open, assigns its port block, and resolves its environment,
+/// writing it to `.envrc` when `open.envrc` is set.
+pub(super) fn workspace_hook_context(
+    issue: &IssueRef,
+    wt: &Path,
+    config: &Config,
+    env: &[(String, String)],
+) -> Result<HookContext> {
+    let mut ctx = build_hook_context(issue, wt);
+    match assign_port(wt, &config.ports) {
+        Ok(port) => ctx.port = port.map(|p| p.to_string()).unwrap_or_default(),
+        Err(e) => eprintln!("warning: could not allocate a port: {e:#}"),
+    }
+    workspace_env::save_env(wt, env)?;
+    ctx.extra_env = workspace_env::workspace_env(Some(issue), &ctx, wt);
+    if config.open.envrc {
+        workspace_env::write_envrc(wt, &ctx.extra_env)?;
+    }
+    Ok(ctx)
+}
+
 pub(super) fn launch_editor(
     workspace: &std::path::Path,
     cmd: Option<&str>,
@@ -40,16 +67,17 @@ pub(super) fn launch_editor(
     background: bool,
     ctx: &HookContext,
 ) -> Result<()> {
+    let exports = workspace_env::env_exports(&ctx.extra_env);
     match (cmd, post_hook) {
         (Some(c), Some(s)) => {
-            let rendered = ctx.render(s);
+            let rendered = format!("{exports}{}", ctx.render(s));
             if !opener::open_with_hook(workspace, c, &rendered, background)? {
                 eprintln!("Running post:open hook…");
                 run_hook(s, ctx)?;
             }
         }
         (Some(c), None) => {
-            opener::open_editor_or_terminal(workspace, c, background)?;
+            opener::open_editor_or_terminal(workspace, c, &exports, background)?;
         }
         (None, Some(s)) => {
             eprintln!("Running post:open hook…");
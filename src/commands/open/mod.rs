@@ -1,68 +1,65 @@
+mod args;
+mod auto_prune;
 mod editor;
 mod hook_build;
 mod hook_ctx;
 mod output;
+mod ttl;
+
+pub use args::OpenArgs;
+pub use hook_build::build_hook_context;
+pub use hook_ctx::effective_hooks;
 
 use anyhow::Result;
-use hook_build::{build_hook_context, run_auto_prune};
-use hook_ctx::{effective_hooks, launch_editor, load_worktree_io_script};
+use auto_prune::run_auto_prune;
+use hook_ctx::{launch_editor, load_worktree_io_script, workspace_hook_context};
 use worktree_io::{
     config::Config,
     hooks::run_hook,
     issue::{DeepLinkOptions, IssueRef},
     repo_hooks_scaffold::scaffold_if_missing,
     workspace::Workspace,
+    workspace_env,
 };
 
-#[allow(
-    clippy::fn_params_excessive_bools,
-    reason = "each bool maps directly to a distinct CLI flag (`--no-editor`, `--no-hooks`, `--headless`, `--force`); wrapping them in a flags struct would add indirection without clarity gains at the single call site"
-)]
-pub fn cmd_open(
-    issue_ref: Option<&str>,
-    force_editor: bool,
-    no_hooks: bool,
-    headless: bool,
-    script: Option<&str>,
-    extra_env: Vec<(String, String)>,
-    json: bool,
-) -> Result<()> {
-    let (issue, deep_link_opts) = match issue_ref {
+pub fn cmd_open(args: &OpenArgs) -> Result<()> {
+    let (issue, deep_link_opts) = match args.issue_ref.as_deref() {
         Some(r) => IssueRef::parse_with_options(r)?,
         None => (IssueRef::from_current_repo()?, DeepLinkOptions::default()),
     };
     let mut merged_env = deep_link_opts.extra_env.clone();
-    merged_env.extend(extra_env);
+    merged_env.extend(args.extra_env());
+    workspace_env::check_names(&merged_env)?;
 
     let workspace = Workspace::open_or_create(issue.clone())?;
-    output::report_workspace(&workspace, json);
+    output::report_workspace(&workspace, args.json);
 
-    if matches!(scaffold_if_missing(&workspace.path), Ok(true)) && !json {
+    if matches!(scaffold_if_missing(&workspace.path), Ok(true)) && !args.json {
         eprintln!("created .worktree.toml (no active config — edit to enable hooks)");
     }
 
     let config = Config::load()?;
-    run_auto_prune(&config);
-    let mut hook_ctx = build_hook_context(&issue, &workspace.path);
-    hook_ctx.extra_env = merged_env;
-    let (effective_pre, effective_post) = if let Some(name) = script {
+    ttl::record_ttl(&workspace, args.ttl.or(deep_link_opts.ttl), &config)?;
+    run_auto_prune(&config, &workspace.path);
+    let hook_ctx = workspace_hook_context(&issue, &workspace.path, &config, &merged_env)?;
+    let (effective_pre, effective_post) = if let Some(name) = args.script.as_deref() {
         (None, Some(load_worktree_io_script(&workspace.path, name)?))
-    } else if no_hooks || deep_link_opts.no_hooks {
+    } else if args.no_hooks || deep_link_opts.no_hooks {
         (None, None)
     } else {
         effective_hooks(&config, &workspace.path)
     };
 
     if let Some(script) = &effective_pre {
-        if !json {
+        if !args.json {
             eprintln!("Running pre:open hook…");
         }
         run_hook(script, &hook_ctx)?;
     }
 
-    if headless {
+    if args.headless {
         if let Some(script) = &effective_post {
-            if !json {
+            if !args.json {
                 eprintln!("Running post:open hook…");
             }
             run_hook(script, &hook_ctx)?;
@@ -71,7 +68,7 @@ pub fn cmd_open(
     }
     let editor_cmd: Option<String> = if let Some(editor_name) = deep_link_opts.editor {
         Some(editor::resolve_editor_command(&editor_name))
-    } else if force_editor || config.open.editor {
+    } else if args.editor || config.open.editor {
         if config.editor.command.is_none() {
             eprintln!("No editor configured. Run: worktree setup");
         }
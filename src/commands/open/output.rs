@@ -1,15 +1,32 @@
-use worktree_io::workspace::Workspace;
+use worktree_io::workspace::{Integrity, Workspace};
 
 pub fn report_workspace(workspace: &Workspace, json: bool) {
+    let integrity = workspace.integrity.as_ref();
     if json {
+        let branch = match integrity {
+            Some(Integrity::OtherBranch(b)) => format!(",\"branch\":{b:?}"),
+            _ => String::new(),
+        };
+        let integrity =
+            integrity.map_or_else(|| "null".to_owned(), |i| format!("{:?}", i.as_str()));
         println!(
-            "{{\"path\":{:?},\"created\":{}}}",
+            "{{\"path\":{:?},\"created\":{},\"woke\":{},\"integrity\":{integrity}{branch}}}",
             workspace.path.display().to_string(),
-            workspace.created
+            workspace.created,
+            workspace.woke
         );
-    } else if workspace.created {
-        eprintln!("Created workspace at {}", workspace.path.display());
-    } else {
-        eprintln!("Workspace already exists at {}", workspace.path.display());
+        return;
+    }
+    let path = workspace.path.display();
+    match integrity {
+        Some(Integrity::Repaired) => eprintln!("Repaired the git links of workspace at {path}"),
+        Some(Integrity::Relinked) => eprintln!("Relinked workspace at {path} to its repository"),
+        Some(Integrity::Recreated) => eprintln!("Recreated workspace at {path}, keeping its files"),
+        Some(Integrity::OtherBranch(b)) => {
+            eprintln!("Workspace already exists at {path}, but it is on {b}");
+        }
+        None if workspace.woke => eprintln!("Woke workspace at {path}"),
+        None if workspace.created => eprintln!("Created workspace at {path}"),
+        None => eprintln!("Workspace already exists at {path}"),
     }
 }
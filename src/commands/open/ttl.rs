@@ -0,0 +1,35 @@
+use anyhow::Result;
+use worktree_io::{
+    config::Config, repo_hooks::RepoConfig, ttl::Ttl, ttl::WorkspaceRegistry, workspace::Workspace,
+};
+
+/// Store the workspace's effective TTL on its registry record.
+///
+/// An explicit TTL (`--ttl` or the deep-link `ttl=` param) always wins and is
+/// stored on every open. Otherwise the repo's `.worktree.toml` TTL, then the
+/// longest matching `workspace.repo_ttl` rule, is stored when the workspace is
+/// first created — later opens keep whatever was recorded then.
+pub(super) fn record_ttl(
+    workspace: &Workspace,
+    explicit: Option<Ttl>,
+    config: &Config,
+) -> Result<()> {
+    let ttl = match explicit {
+        Some(ttl) => ttl,
+        None if workspace.created => {
+            let repo = RepoConfig::load_from(&workspace.path).unwrap_or_default();
+            let slug = workspace.issue.repo_slug();
+            match repo.ttl.or_else(|| config.workspace.repo_ttl_for(&slug)) {
+                Some(ttl) => ttl,
+                None => return Ok(()),
+            }
+        }
+        None => return Ok(()),
+    };
+    let mut registry = WorkspaceRegistry::load()?;
+    if let Some(record) = registry.get_mut(&workspace.path) {
+        record.ttl = Some(ttl);
+        registry.save()?;
+    }
+    Ok(())
+}
@@ -52,12 +52,8 @@ pub fn cmd_open_multi(refs: &[String], no_hooks: bool) -> Result<()> {
     let config = Config::load()?;
 
     let hook_ctx = HookContext {
-        owner: String::new(),
-        repo: String::new(),
-        issue: String::new(),
-        branch: String::new(),
         worktree_path: root.to_string_lossy().into_owned(),
-        extra_env: vec![],
+        ..HookContext::default()
     };
 
     if !no_hooks {
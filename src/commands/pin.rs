@@ -0,0 +1,36 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use worktree_io::lifecycle::set_locked;
+use worktree_io::ttl::WorkspaceRegistry;
+
+use super::target::resolve_target;
+
+#[derive(Args)]
+pub struct PinArgs {
+    /// Issue reference or path of the workspace
+    #[arg(value_name = "REF|PATH")]
+    target: String,
+}
+
+/// Pin (`pin = true`) or unpin a registered workspace.
+///
+/// Pinned workspaces are skipped by `prune` and auto-prune, and their git
+/// worktrees are locked so `git worktree prune` leaves them alone too.
+pub fn cmd_pin(args: &PinArgs, pin: bool) -> Result<()> {
+    let path = resolve_target(&args.target)?;
+    let mut registry = WorkspaceRegistry::load()?;
+    let Some(record) = registry.get_mut(&path) else {
+        bail!("No workspace registered at {}", path.display());
+    };
+    record.pinned = pin;
+    if path.exists() {
+        set_locked(&path, pin)?;
+    }
+    registry.save()?;
+    if pin {
+        eprintln!("Pinned {}; prune will leave it alone.", path.display());
+    } else {
+        eprintln!("Unpinned {}.", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use worktree_io::lifecycle::{preview_prune, remove_workspace, PruneReason, Pruned};
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+use super::super::trash::move_to_trash;
+use super::super::unarchive::archive_before_removal;
+use super::budget::cached_size;
+use super::confirm::confirm;
+use super::hibernate::hibernate_in;
+use super::report::Report;
+use super::PruneArgs;
+
+/// Move each candidate to the trash, or with `hibernate` hibernate it, or
+/// with `--dry-run` only preview it, and record the outcome in `report` and
+/// `registry`.
+///
+/// Outside hibernation, over-budget evictions are deleted outright, since
+/// the trash would not free their space. Returns how many workspaces can
+/// still be brought back, from the trash or by waking them.
+pub fn prune_candidates(
+    args: &PruneArgs,
+    hibernate: bool,
+    candidates: &mut [(WorkspaceRecord, PruneReason)],
+    registry: &mut WorkspaceRegistry,
+    report: &mut Report,
+    now: SystemTime,
+) -> Result<usize> {
+    let mut trashed = 0;
+    for (record, reason) in candidates {
+        let freed = cached_size(record, now);
+        if args.interactive && !confirm(record, *reason, freed, hibernate)? {
+            continue;
+        }
+        if hibernate {
+            match hibernate_in(registry, record, args.dry_run, now) {
+                Ok(()) => {
+                    trashed += usize::from(!args.dry_run);
+                    report.removed(record, *reason, freed);
+                }
+                Err(e) => report.failed(record, *reason, &e),
+            }
+            continue;
+        }
+        if args.dry_run {
+            match preview_prune(&record.path, args.force) {
+                Ok(Pruned::Kept(unsaved)) => report.kept(record, &unsaved),
+                Ok(Pruned::Removed) => report.removed(record, *reason, freed),
+                Err(e) => report.failed(record, *reason, &e),
+            }
+            continue;
+        }
+        match remove(registry, record, *reason, args.force) {
+            Ok(Pruned::Kept(unsaved)) => report.kept(record, &unsaved),
+            Ok(Pruned::Removed) => {
+                trashed += usize::from(*reason != PruneReason::OverBudget);
+                report.removed(record, *reason, freed);
+            }
+            Err(e) => report.failed(record, *reason, &e),
+        }
+    }
+    Ok(trashed)
+}
+
+/// Prune one workspace, archiving unsaved work first when it is forced.
+fn remove(
+    registry: &mut WorkspaceRegistry,
+    record: &WorkspaceRecord,
+    reason: PruneReason,
+    force: bool,
+) -> Result<Pruned> {
+    if force {
+        archive_before_removal(record)?;
+    }
+    let outcome = preview_prune(&record.path, force)?;
+    if matches!(outcome, Pruned::Kept(_)) {
+        return Ok(outcome);
+    }
+    if reason == PruneReason::OverBudget {
+        remove_workspace(&record.path, false)?;
+        registry.remove(&record.path);
+    } else {
+        move_to_trash(registry, record, false)?;
+    }
+    Ok(outcome)
+}
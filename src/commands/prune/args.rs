@@ -0,0 +1,32 @@
+use clap::Args;
+
+use super::filter::PruneFilter;
+
+#[derive(Args)]
+#[allow(
+    clippy::struct_excessive_bools,
+    reason = "each bool maps directly to a distinct CLI flag"
+)]
+pub struct PruneArgs {
+    /// Emit a JSON report to stdout instead of human-readable output
+    #[arg(long)]
+    pub(super) json: bool,
+    /// Also remove worktrees with uncommitted changes or unpushed commits
+    #[arg(long)]
+    pub(super) force: bool,
+    /// Also remove worktrees whose branch is merged or was deleted upstream
+    #[arg(long)]
+    pub(super) merged: bool,
+    /// Report what would be removed, and why, without removing anything
+    #[arg(long)]
+    pub(super) dry_run: bool,
+    /// Ask before removing each workspace
+    #[arg(long, short, conflicts_with = "dry_run")]
+    pub(super) interactive: bool,
+    /// Hibernate workspaces instead of moving them to the trash (see
+    /// `workspace.hibernate`)
+    #[arg(long)]
+    pub(super) hibernate: bool,
+    #[command(flatten)]
+    pub(super) filter: PruneFilter,
+}
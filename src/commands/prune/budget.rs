@@ -0,0 +1,56 @@
+use std::time::SystemTime;
+
+use worktree_io::lifecycle::{unsaved_work, PruneReason};
+use worktree_io::ttl::{ByteSize, CachedSize, WorkspaceRecord};
+
+use super::size::dir_size;
+
+/// The disk usage of `record`'s workspace. [`dir_size`] walks the tree only
+/// when the size cached on the record is stale; the fresh value is stored
+/// back on the record.
+pub fn cached_size(record: &mut WorkspaceRecord, now: SystemTime) -> u64 {
+    match record.size {
+        Some(c) if c.is_fresh(record.last_opened_at, now) => c.bytes,
+        _ => {
+            let bytes = dir_size(&record.path);
+            let measured_at = now;
+            record.size = Some(CachedSize { bytes, measured_at });
+            bytes
+        }
+    }
+}
+
+/// Pick workspaces to evict, least recently opened first, until the ones
+/// left fit in `budget`. Workspaces already `selected` for another reason
+/// count as gone; only those `in_scope` are evicted, and pinned ones and
+/// ones with unsaved work never are.
+pub fn select_over_budget(
+    records: &mut [WorkspaceRecord],
+    budget: ByteSize,
+    selected: &[(WorkspaceRecord, PruneReason)],
+    in_scope: impl Fn(&WorkspaceRecord) -> bool,
+    now: SystemTime,
+) -> Vec<(WorkspaceRecord, PruneReason)> {
+    let taken = |r: &WorkspaceRecord| selected.iter().any(|(s, _)| s.path == r.path);
+    let mut total: u64 = records
+        .iter_mut()
+        .filter(|r| r.path.exists() && !taken(r))
+        .map(|r| cached_size(r, now))
+        .sum();
+    let mut lru: Vec<&WorkspaceRecord> = records
+        .iter()
+        .filter(|r| !r.pinned && r.path.exists() && !taken(r) && in_scope(r))
+        .collect();
+    lru.sort_by_key(|r| r.last_opened_at.unwrap_or(r.created_at));
+    let mut evicted = Vec::new();
+    for r in lru {
+        if total <= budget.bytes() {
+            break;
+        }
+        if unsaved_work(&r.path).is_ok_and(|u| u.is_empty()) {
+            total -= r.size.map_or(0, |c| c.bytes);
+            evicted.push((r.clone(), PruneReason::OverBudget));
+        }
+    }
+    evicted
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use worktree_io::lifecycle::PruneReason;
+use worktree_io::ttl::WorkspaceRecord;
+
+use super::size::format_bytes;
+
+/// Ask on stderr whether to prune `r` — remove it, or with `hibernate`
+/// hibernate it; anything but `y` or `yes` declines.
+pub fn confirm(
+    r: &WorkspaceRecord,
+    reason: PruneReason,
+    size: u64,
+    hibernate: bool,
+) -> Result<bool> {
+    use std::io::{BufRead, Write};
+    let verb = if hibernate { "Hibernate" } else { "Remove" };
+    let (path, size) = (r.path.display(), format_bytes(size));
+    eprint!("{verb} {path} ({}, {size})? [y/N] ", reason.as_str());
+    std::io::stderr().flush().ok();
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    Ok(matches!(
+        line.trim().to_ascii_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
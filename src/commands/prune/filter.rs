@@ -0,0 +1,61 @@
+use clap::Args;
+use worktree_io::issue::IssueRef;
+use worktree_io::ttl::{Ttl, WorkspaceRecord};
+
+/// Narrows a prune to some workspaces, e.g. `--owner acme --older-than 3d`.
+#[derive(Args, Default)]
+pub struct PruneFilter {
+    /// Only prune workspaces of this repository (`repo` or `owner/repo`)
+    #[arg(long, value_name = "REPO")]
+    repo: Option<String>,
+    /// Only prune workspaces of repositories owned by this user or org
+    #[arg(long, value_name = "OWNER")]
+    owner: Option<String>,
+    /// Only prune workspaces from this provider (github, gitlab, ...)
+    #[arg(long, value_name = "PROVIDER")]
+    provider: Option<String>,
+    /// Prune matching workspaces older than this, ignoring configured TTLs
+    #[arg(long, value_name = "DURATION")]
+    pub(super) older_than: Option<Ttl>,
+}
+
+impl PruneFilter {
+    /// Whether `r` passes the `--repo`, `--owner`, and `--provider` filters.
+    /// Workspaces with no recorded issue match only when neither `--repo`
+    /// nor `--owner` is given.
+    pub fn matches(&self, r: &WorkspaceRecord) -> bool {
+        let slug = r.issue.as_ref().map(IssueRef::repo_slug);
+        let slug = slug.as_deref();
+        let same =
+            |want: &str, have: Option<&str>| have.is_some_and(|h| h.eq_ignore_ascii_case(want));
+        let repo = self.repo.as_deref().is_none_or(|want| {
+            same(want, slug) || same(want, slug.and_then(|s| s.rsplit('/').next()))
+        });
+        let owner = self
+            .owner
+            .as_deref()
+            .is_none_or(|want| same(want, slug.and_then(|s| s.split_once('/')).map(|(o, _)| o)));
+        let provider = self.provider.as_deref();
+        repo && owner && provider.is_none_or(|want| same(want, r.provider.as_deref()))
+    }
+
+    /// The workspaces this run may prune. With `--older-than`, their own
+    /// TTLs are dropped so that age alone decides.
+    pub fn scope(&self, records: &[WorkspaceRecord]) -> Vec<WorkspaceRecord> {
+        records
+            .iter()
+            .filter(|r| self.matches(r))
+            .cloned()
+            .map(|mut r| {
+                if self.older_than.is_some() {
+                    r.ttl = None;
+                }
+                r
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "filter_tests.rs"]
+mod tests;
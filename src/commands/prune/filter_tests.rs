@@ -0,0 +1,54 @@
+use super::*;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+fn record(owner: &str, repo: &str) -> WorkspaceRecord {
+    let issue = IssueRef::GitHub {
+        owner: owner.to_owned(),
+        repo: repo.to_owned(),
+        number: 1,
+    };
+    WorkspaceRecord::for_issue(&issue, PathBuf::from("/ws"), SystemTime::now())
+}
+
+#[test]
+fn test_filter_matches_repo_owner_and_provider() {
+    let r = record("Acme", "api");
+    let f = |repo: Option<&str>, owner: Option<&str>, provider: Option<&str>| PruneFilter {
+        repo: repo.map(str::to_owned),
+        owner: owner.map(str::to_owned),
+        provider: provider.map(str::to_owned),
+        older_than: None,
+    };
+    assert!(f(None, None, None).matches(&r));
+    assert!(f(Some("api"), None, None).matches(&r));
+    assert!(f(Some("acme/api"), Some("acme"), Some("github")).matches(&r));
+    assert!(!f(Some("web"), None, None).matches(&r));
+    assert!(!f(None, Some("other"), None).matches(&r));
+    assert!(!f(None, None, Some("gitlab")).matches(&r));
+}
+
+#[test]
+fn test_filter_without_issue_matches_only_unfiltered() {
+    let r = WorkspaceRecord::new(PathBuf::from("/ws"), SystemTime::now());
+    assert!(PruneFilter::default().matches(&r));
+    let by_owner = PruneFilter {
+        owner: Some("acme".to_owned()),
+        ..PruneFilter::default()
+    };
+    assert!(!by_owner.matches(&r));
+}
+
+#[test]
+fn test_scope_older_than_drops_record_ttls() {
+    let mut r = record("acme", "api");
+    r.ttl = Some(Ttl::new(Duration::from_hours(1)));
+    assert!(PruneFilter::default().scope(std::slice::from_ref(&r))[0]
+        .ttl
+        .is_some());
+    let older = PruneFilter {
+        older_than: Some(Ttl::new(Duration::from_hours(24))),
+        ..PruneFilter::default()
+    };
+    assert!(older.scope(&[r])[0].ttl.is_none());
+}
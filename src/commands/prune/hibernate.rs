@@ -0,0 +1,23 @@
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use worktree_io::lifecycle::hibernate_workspace;
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+/// Hibernate the registry's copy of `record`; unsaved work is stashed, so it
+/// never keeps a workspace.
+pub fn hibernate_in(
+    registry: &mut WorkspaceRegistry,
+    record: &WorkspaceRecord,
+    dry_run: bool,
+    now: SystemTime,
+) -> Result<()> {
+    let path = &record.path;
+    let stored = registry
+        .get_mut(path)
+        .with_context(|| format!("{} is not registered", path.display()))?;
+    if dry_run {
+        return Ok(());
+    }
+    hibernate_workspace(stored, now)
+}
@@ -0,0 +1,69 @@
+use std::time::SystemTime;
+
+use worktree_io::lifecycle::{PruneReason, UnsavedWork};
+use worktree_io::ttl::{Ttl, TtlMode, WorkspaceRecord};
+
+use super::super::json::escape;
+
+/// One `pruned` entry. Merged and evicted workspaces are reported as
+/// expiring `now`.
+pub fn entry_json(
+    r: &WorkspaceRecord,
+    reason: PruneReason,
+    ttl: Option<(Ttl, TtlMode)>,
+    now: SystemTime,
+    freed_bytes: u64,
+) -> String {
+    let path_esc = escape(&r.path.display().to_string());
+    let ea = match (reason, ttl) {
+        (PruneReason::Expired, Some((t, mode))) => mode.since(r).checked_add(t.duration()),
+        _ => Some(now),
+    };
+    let ea = ea.unwrap_or(r.created_at);
+    format!(
+        "{{\"path\":\"{path_esc}\",\"reason\":\"{}\",\"expired_at\":\"{}\",\"freed_bytes\":{freed_bytes}}}",
+        reason.as_str(),
+        humantime::format_rfc3339(ea)
+    )
+}
+
+pub fn skipped_json(r: &WorkspaceRecord, unsaved: &[UnsavedWork]) -> String {
+    let path_esc = escape(&r.path.display().to_string());
+    let dirty: usize = unsaved.iter().map(|u| u.dirty).sum();
+    let unpushed: usize = unsaved.iter().map(|u| u.unpushed).sum();
+    format!("{{\"path\":\"{path_esc}\",\"uncommitted\":{dirty},\"unpushed\":{unpushed}}}")
+}
+
+/// One `failed` entry: a workspace whose removal failed and that stays
+/// registered.
+pub fn failed_json(r: &WorkspaceRecord, reason: PruneReason, e: &anyhow::Error) -> String {
+    let path_esc = escape(&r.path.display().to_string());
+    let error = escape(&format!("{e:#}"));
+    format!(
+        "{{\"path\":\"{path_esc}\",\"reason\":\"{}\",\"error\":\"{error}\"}}",
+        reason.as_str()
+    )
+}
+
+/// The JSON entries of a prune run, one list per outcome.
+#[derive(Default)]
+pub struct Entries {
+    pub pruned: Vec<String>,
+    pub skipped: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+/// The closing JSON object. `ttl` is the configured TTL and is left out when
+/// none is configured, e.g. in a merged-only run.
+pub fn summary_json(
+    checked: usize,
+    entries: &Entries,
+    total_freed: u64,
+    ttl: Option<&Ttl>,
+    dry_run: bool,
+) -> String {
+    let [pruned, skipped, failed] =
+        [&entries.pruned, &entries.skipped, &entries.failed].map(|l| l.join(","));
+    let ttl = ttl.map_or_else(String::new, |t| format!(",\"ttl\":\"{t}\""));
+    format!("{{\"checked\":{checked},\"dry_run\":{dry_run},\"pruned\":[{pruned}],\"skipped\":[{skipped}],\"failed\":[{failed}],\"total_freed_bytes\":{total_freed}{ttl}}}")
+}
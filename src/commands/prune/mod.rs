@@ -0,0 +1,89 @@
+use std::time::SystemTime;
+
+use anyhow::{bail, Result};
+use worktree_io::config::Config;
+use worktree_io::lifecycle::{select_prunable, DEFAULT_TRASH_RETENTION};
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+mod apply;
+mod args;
+mod budget;
+mod confirm;
+mod filter;
+mod hibernate;
+mod json;
+mod report;
+mod size;
+mod summary;
+mod text;
+
+use super::trash::empty_expired_trash;
+use apply::prune_candidates;
+pub use args::PruneArgs;
+pub use budget::cached_size;
+use budget::select_over_budget;
+use report::Report;
+pub use size::{dir_size, format_bytes};
+use text::Action;
+
+/// Move workspaces that are expired, or, under the `merged` policy, whose
+/// branch is merged, to the trash, then delete the least recently opened ones
+/// while usage exceeds `workspace.max_total_size`. Workspaces with uncommitted
+/// changes or unpushed commits are reported and kept unless `--force` is
+/// given. Filters limit all of this to matching workspaces. The trash is
+/// emptied of workspaces older than `workspace.trash_retention` first.
+///
+/// With `--hibernate` or `workspace.hibernate`, workspaces are hibernated
+/// instead, keeping their unsaved work in their repository.
+pub fn cmd_prune(args: &PruneArgs) -> Result<()> {
+    let mut cfg = Config::load()?.workspace;
+    cfg.ttl = args.filter.older_than.or(cfg.ttl);
+    let mut policy = cfg.prune_policy;
+    policy.merged |= args.merged;
+    policy.ttl |= args.filter.older_than.is_some();
+    let mut registry = WorkspaceRegistry::load()?;
+    let scope = args.filter.scope(&registry.workspace);
+    policy.ttl &= cfg.ttl.is_some() || scope.iter().any(|r| r.ttl.is_some());
+    if !policy.ttl && !policy.merged && cfg.max_total_size.is_none() {
+        bail!("No workspace TTL configured. Set workspace.ttl in your config (e.g. \"7days\").");
+    }
+    if !args.dry_run {
+        empty_expired_trash(&mut registry, &cfg)?;
+    }
+    let (checked, now) = (scope.len(), SystemTime::now());
+    let mut candidates = select_prunable(&scope, cfg.ttl.as_ref(), cfg.ttl_mode, policy, now);
+    if let Some(budget) = cfg.max_total_size {
+        let in_scope = |r: &WorkspaceRecord| args.filter.matches(r);
+        let records = &mut registry.workspace;
+        let evicted = select_over_budget(records, budget, &candidates, in_scope, now);
+        candidates.extend(evicted);
+    }
+    let hibernate = args.hibernate || cfg.hibernate;
+    let action = Action {
+        dry_run: args.dry_run,
+        hibernate,
+    };
+    let mut report = Report::new(args, &cfg, policy, action, now);
+    if !args.json && candidates.is_empty() {
+        registry.save()?;
+        eprintln!("Checked {checked} workspace(s), none {}.", report.criteria);
+        return Ok(());
+    }
+    let recoverable = prune_candidates(
+        args,
+        hibernate,
+        &mut candidates,
+        &mut registry,
+        &mut report,
+        now,
+    )?;
+    registry.save()?;
+    report.finish(checked);
+    if recoverable > 0 && !args.json && hibernate {
+        eprintln!("Hibernated workspaces wake on their next `worktree open`.");
+    } else if recoverable > 0 && !args.json {
+        let keep = cfg.trash_retention.unwrap_or(DEFAULT_TRASH_RETENTION);
+        eprintln!("Pruned workspaces stay in the trash for {keep}; `worktree undo` restores the last one.");
+    }
+    Ok(())
+}
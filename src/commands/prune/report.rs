@@ -0,0 +1,93 @@
+use std::time::SystemTime;
+
+use anyhow::Error;
+use worktree_io::config::WorkspaceConfig;
+use worktree_io::lifecycle::{PruneReason, UnsavedWork};
+use worktree_io::ttl::{PrunePolicy, Ttl, TtlMode, WorkspaceRecord};
+
+use super::json::{entry_json, failed_json, skipped_json, summary_json, Entries};
+use super::size::format_bytes;
+use super::summary::print_summary;
+use super::text::{criteria, removed_line, Action};
+use super::PruneArgs;
+
+/// Collects the outcome of a prune run and prints it as text or JSON.
+pub struct Report {
+    json: bool,
+    action: Action,
+    ttl: Option<Ttl>,
+    mode: TtlMode,
+    now: SystemTime,
+    /// What was looked for, e.g. `expired (TTL: 7days) or merged`.
+    pub criteria: String,
+    /// Short form of `criteria`, e.g. `expired or merged`.
+    label: String,
+    entries: Entries,
+    total_freed: u64,
+}
+
+impl Report {
+    pub fn new(
+        args: &PruneArgs,
+        cfg: &WorkspaceConfig,
+        policy: PrunePolicy,
+        action: Action,
+        now: SystemTime,
+    ) -> Self {
+        let (criteria, label) = criteria(cfg, policy);
+        Self {
+            json: args.json,
+            action,
+            ttl: cfg.ttl,
+            mode: cfg.ttl_mode,
+            now,
+            criteria,
+            label,
+            entries: Entries::default(),
+            total_freed: 0,
+        }
+    }
+
+    /// Record a workspace whose removal failed. It stays registered and is
+    /// neither counted as pruned nor as freed.
+    pub fn failed(&mut self, r: &WorkspaceRecord, reason: PruneReason, e: &Error) {
+        let verb = self.action.verb();
+        eprintln!("Warning: failed to {verb} {}: {e:#}", r.path.display());
+        self.entries.failed.push(failed_json(r, reason, e));
+    }
+
+    /// Count a workspace that was removed, or with `--dry-run` would be.
+    pub fn removed(&mut self, r: &WorkspaceRecord, reason: PruneReason, freed: u64) {
+        self.total_freed += freed;
+        let ttl = r.ttl.or(self.ttl).map(|t| (t, self.mode));
+        let entry = entry_json(r, reason, ttl, self.now, freed);
+        self.entries.pruned.push(entry);
+        let line = removed_line(r, reason, ttl, self.now, freed, self.action);
+        if !self.json {
+            eprintln!("{line}");
+        }
+    }
+
+    pub fn kept(&mut self, r: &WorkspaceRecord, unsaved: &[UnsavedWork]) {
+        self.entries.skipped.push(skipped_json(r, unsaved));
+        if self.json {
+            return;
+        }
+        eprintln!("Skipping {} — it has unsaved work:", r.path.display());
+        for u in unsaved {
+            eprintln!("  {u}");
+        }
+    }
+
+    pub fn finish(&self, checked: usize) {
+        let (e, dry_run) = (&self.entries, self.action.dry_run);
+        if self.json {
+            let (freed, ttl) = (self.total_freed, self.ttl.as_ref());
+            println!("{}", summary_json(checked, e, freed, ttl, dry_run));
+            return;
+        }
+        let counts = [e.pruned.len(), e.skipped.len(), e.failed.len()];
+        let freed = format_bytes(self.total_freed);
+        print_summary(counts, &self.label, &freed, dry_run);
+    }
+}
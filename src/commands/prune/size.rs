@@ -0,0 +1,34 @@
+use std::path::Path;
+
+/// Human-readable byte count, e.g. `1.5 KB`.
+pub fn format_bytes(n: u64) -> String {
+    const KB: u64 = 1_024;
+    const MB: u64 = 1_024 * KB;
+    const GB: u64 = 1_024 * MB;
+    let (whole, frac, unit) = if n >= GB {
+        (n / GB, (n % GB) * 10 / GB, "GB")
+    } else if n >= MB {
+        (n / MB, (n % MB) * 10 / MB, "MB")
+    } else if n >= KB {
+        (n / KB, (n % KB) * 10 / KB, "KB")
+    } else {
+        return format!("{n} B");
+    };
+    format!("{whole}.{frac} {unit}")
+}
+
+fn entry_size(e: &std::fs::DirEntry) -> u64 {
+    let p = e.path();
+    e.metadata()
+        .map_or(0, |m| if m.is_dir() { dir_size(&p) } else { m.len() })
+}
+
+/// Total size in bytes of the files under `path`; unreadable entries count
+/// as zero.
+pub fn dir_size(path: &Path) -> u64 {
+    std::fs::read_dir(path).map_or(0, |rd| rd.flatten().map(|e| entry_size(&e)).sum())
+}
+
+#[cfg(test)]
+#[path = "size_tests.rs"]
+mod tests;
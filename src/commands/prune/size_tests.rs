@@ -1,4 +1,4 @@
-use super::{dir_size, format_bytes};
+use super::*;
 
 #[test]
 fn test_format_bytes_b() {
@@ -0,0 +1,22 @@
+/// The closing summary of a prune run from the `[pruned, kept, failed]`
+/// counts, including any workspaces kept for their unsaved work or that could
+/// not be removed.
+pub fn print_summary(counts: [usize; 3], label: &str, freed: &str, dry_run: bool) {
+    let [pruned, kept, failed] = counts;
+    if dry_run {
+        eprintln!(
+            "Would prune {pruned} {label} workspace(s), freeing {freed}. Nothing was removed."
+        );
+    } else {
+        eprintln!("Pruned {pruned} {label} workspace(s). Total freed: {freed}.");
+    }
+    if kept > 0 {
+        eprintln!(
+            "Kept {kept} {label} workspace(s) with unsaved work. \
+             Commit and push it, or run `worktree prune --force` to discard it."
+        );
+    }
+    if failed > 0 {
+        eprintln!("Failed to prune {failed} {label} workspace(s); they are still registered.");
+    }
+}
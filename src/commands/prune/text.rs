@@ -0,0 +1,81 @@
+use std::time::SystemTime;
+
+use worktree_io::config::WorkspaceConfig;
+use worktree_io::lifecycle::PruneReason;
+use worktree_io::ttl::{PrunePolicy, Ttl, TtlMode, WorkspaceRecord};
+
+use super::size::format_bytes;
+
+/// What a prune run does to each candidate.
+#[derive(Clone, Copy)]
+pub struct Action {
+    pub dry_run: bool,
+    pub hibernate: bool,
+}
+
+impl Action {
+    /// `remove` or `hibernate`.
+    pub const fn verb(self) -> &'static str {
+        if self.hibernate {
+            "hibernate"
+        } else {
+            "remove"
+        }
+    }
+}
+
+/// `Removed <path> (<why>, freed: <size>)` for a pruned workspace, or
+/// `Would remove <path> (<why>, size: <size>)` for a dry run; hibernation
+/// says `Hibernated` and `Would hibernate` instead.
+pub fn removed_line(
+    r: &WorkspaceRecord,
+    reason: PruneReason,
+    ttl: Option<(Ttl, TtlMode)>,
+    now: SystemTime,
+    freed: u64,
+    action: Action,
+) -> String {
+    let why = match (reason, ttl) {
+        (PruneReason::Expired, Some((ttl, mode))) => {
+            let age = now.duration_since(mode.since(r)).unwrap_or_default();
+            let what = if mode == TtlMode::Idle { "idle" } else { "age" };
+            format!("{what}: {}, TTL: {ttl}", humantime::format_duration(age))
+        }
+        (PruneReason::OverBudget, _) => "over the size budget".to_owned(),
+        _ => "branch merged".to_owned(),
+    };
+    let (path, freed) = (r.path.display(), format_bytes(freed));
+    match (action.dry_run, action.hibernate) {
+        (true, false) => format!("Would remove {path} ({why}, size: {freed})"),
+        (true, true) => format!("Would hibernate {path} ({why}, size: {freed})"),
+        (false, false) => format!("Removed {path} ({why}, freed: {freed})"),
+        (false, true) => format!("Hibernated {path} ({why}, freed: {freed})"),
+    }
+}
+
+/// What a prune looks for, e.g. `expired (idle TTL: 7days) or merged`, and
+/// its short form for summaries, e.g. `expired or merged`. Without a global
+/// TTL only workspaces with their own TTL can expire.
+pub fn criteria(cfg: &WorkspaceConfig, policy: PrunePolicy) -> (String, String) {
+    let idle = if cfg.ttl_mode == TtlMode::Idle {
+        "idle "
+    } else {
+        ""
+    };
+    let expired = cfg.ttl.map_or_else(
+        || "expired (per-workspace TTL)".to_owned(),
+        |t| format!("expired ({idle}TTL: {t})"),
+    );
+    let budget = cfg
+        .max_total_size
+        .map(|max| (format!("over the size budget ({max})"), "over-budget"));
+    let (long, short): (Vec<String>, Vec<&str>) = [
+        policy.ttl.then_some((expired, "expired")),
+        policy.merged.then(|| ("merged".to_owned(), "merged")),
+        budget,
+    ]
+    .into_iter()
+    .flatten()
+    .unzip();
+    (long.join(" or "), short.join(" or "))
+}
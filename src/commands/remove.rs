@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::Args;
+use worktree_io::config::Config;
+use worktree_io::git::delete_ref;
+use worktree_io::lifecycle::unsaved_work;
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+use super::target::{ensure_managed, resolve_target};
+use super::trash::{empty_expired_trash, move_to_trash};
+use super::unarchive::archive_before_removal;
+
+#[derive(Args)]
+pub struct RemoveArgs {
+    /// Issue reference or path of the workspace to remove
+    #[arg(value_name = "REF|PATH")]
+    target: String,
+    /// Remove even if the workspace is pinned or has uncommitted changes or
+    /// unpushed commits
+    #[arg(long)]
+    force: bool,
+    /// Also delete the workspace's local branch from its bare clone
+    #[arg(long)]
+    delete_branch: bool,
+}
+
+/// Move one workspace to the trash, detaching its git worktree and optionally
+/// deleting its branch. Refuses directories worktree does not manage, and
+/// refuses to discard unsaved work unless `--force` is given, in which case
+/// the work is archived first. A hibernated workspace only leaves the
+/// registry, along with its stashed changes.
+pub fn cmd_remove(args: &RemoveArgs) -> Result<()> {
+    let path = resolve_target(&args.target)?;
+    let mut registry = WorkspaceRegistry::load()?;
+    empty_expired_trash(&mut registry, &Config::load()?.workspace)?;
+    if !path.exists() {
+        let stash = registry.get(&path).and_then(hibernation_stash);
+        if stash.is_some() && !args.force {
+            bail!(
+                "Refusing to remove {} — it is hibernated with unsaved work. \
+                 Run `worktree open` to wake it, or pass --force to discard it.",
+                path.display()
+            );
+        }
+        if registry.remove(&path).is_none() {
+            bail!("No workspace found at {}", path.display());
+        }
+        if let Some((repo, stash)) = stash {
+            let _ = delete_ref(&repo, &stash);
+        }
+        registry.save()?;
+        eprintln!(
+            "{} no longer exists on disk; dropped its registry entry.",
+            path.display()
+        );
+        return Ok(());
+    }
+    ensure_managed(&path, &registry)?;
+    if !args.force {
+        if registry.get(&path).is_some_and(|r| r.pinned) {
+            bail!(
+                "Refusing to remove {} — it is pinned. Run `worktree unpin` first, or pass --force.",
+                path.display()
+            );
+        }
+        let unsaved = unsaved_work(&path)?;
+        if !unsaved.is_empty() {
+            let details: Vec<String> = unsaved.iter().map(|u| format!("  {u}")).collect();
+            bail!(
+                "Refusing to remove {} — it has unsaved work:\n{}\n\
+                 Commit and push it, or pass --force to discard it.",
+                path.display(),
+                details.join("\n")
+            );
+        }
+    }
+    let now = std::time::SystemTime::now();
+    let record =
+        (registry.get(&path).cloned()).unwrap_or_else(|| WorkspaceRecord::new(path.clone(), now));
+    if args.force {
+        archive_before_removal(&record)?;
+    }
+    move_to_trash(&mut registry, &record, args.delete_branch)?;
+    registry.save()?;
+    eprintln!(
+        "Moved {} to the trash; `worktree undo` restores it.",
+        path.display()
+    );
+    Ok(())
+}
+
+/// The repository and ref holding a hibernated workspace's stashed changes.
+fn hibernation_stash(record: &WorkspaceRecord) -> Option<(PathBuf, String)> {
+    let stash = record.hibernated.as_ref()?.stash.clone()?;
+    Some((record.bare_path.clone()?, stash))
+}
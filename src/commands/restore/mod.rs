@@ -0,0 +1,67 @@
+use anyhow::Result;
+use clap::Args;
+use worktree_io::git::{git_worktree_prune, restore_worktree};
+use worktree_io::ttl::{WorkspaceKind, WorkspaceRegistry};
+
+mod orphans;
+mod report;
+
+use orphans::{targets, Target};
+use report::Report;
+
+#[derive(Args)]
+pub struct RestoreArgs {
+    /// Emit a JSON report to stdout instead of human-readable output
+    #[arg(long)]
+    json: bool,
+}
+
+/// Restore worktrees whose directories were manually deleted.
+///
+/// Every workspace kind is rebuilt from its registry metadata: the bare clone
+/// or local project, the branch, and the base ref to recreate the branch from
+/// when it is gone too. Multi-repo workspaces get each missing member back.
+pub fn cmd_restore(args: &RestoreArgs) -> Result<()> {
+    let registry = WorkspaceRegistry::load()?;
+    let orphaned: Vec<Target<'_>> = registry.workspace.iter().flat_map(targets).collect();
+    if orphaned.is_empty() && !args.json {
+        eprintln!("No orphaned worktrees found.");
+        return Ok(());
+    }
+    let mut report = Report::new(args.json, orphaned.len());
+    for target in &orphaned {
+        restore(target, &mut report);
+    }
+    report.finish();
+    Ok(())
+}
+
+fn restore(t: &Target<'_>, report: &mut Report) {
+    let what = if t.kind == WorkspaceKind::Local {
+        "project"
+    } else {
+        "bare clone"
+    };
+    let Some(repo) = t.repo else {
+        return report.skipped(t, &format!("its {what} is not recorded"));
+    };
+    if !repo.exists() {
+        let reason = format!("{what} no longer exists at {}", repo.display());
+        return report.skipped(t, &reason);
+    }
+    let Some(branch) = t.branch else {
+        return report.skipped(t, "its branch is not recorded");
+    };
+    report.restoring(t);
+    if let Err(e) = git_worktree_prune(repo) {
+        eprintln!("  Warning: worktree prune failed: {e}");
+    }
+    let parent = t.path.parent().unwrap_or(t.path);
+    let result = std::fs::create_dir_all(parent)
+        .map_err(Into::into)
+        .and_then(|()| restore_worktree(repo, t.path, branch, t.base_ref));
+    match result {
+        Ok(()) => report.restored(t),
+        Err(e) => report.failed(t, &e),
+    }
+}
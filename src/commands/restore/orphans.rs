@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use worktree_io::ttl::{WorkspaceKind, WorkspaceRecord};
+
+/// One worktree to recreate: a single or local workspace, or one member of a
+/// multi-repo workspace.
+pub struct Target<'a> {
+    pub path: &'a Path,
+    pub kind: WorkspaceKind,
+    pub repo: Option<&'a Path>,
+    pub branch: Option<&'a str>,
+    pub base_ref: Option<&'a str>,
+}
+
+/// The missing worktrees of `record`. Trashed and hibernated workspaces are
+/// not missing; `worktree trash restore` and `worktree open` bring them back.
+pub fn targets(record: &WorkspaceRecord) -> Vec<Target<'_>> {
+    if record.trashed_at.is_some() || record.hibernated.is_some() {
+        return Vec::new();
+    }
+    if record.kind == WorkspaceKind::Multi {
+        let missing = record.members.iter().filter(|m| !m.path.exists());
+        return missing
+            .map(|m| Target {
+                path: &m.path,
+                kind: WorkspaceKind::Multi,
+                repo: m.bare_path.as_deref(),
+                branch: m.branch.as_deref(),
+                base_ref: m.base_ref.as_deref(),
+            })
+            .collect();
+    }
+    if record.path.exists() {
+        return Vec::new();
+    }
+    vec![Target {
+        path: &record.path,
+        kind: record.kind,
+        repo: record.bare_path.as_deref(),
+        branch: record.branch.as_deref(),
+        base_ref: record.base_ref.as_deref(),
+    }]
+}
@@ -0,0 +1,93 @@
+use anyhow::Error;
+
+use super::super::json::escape;
+use super::Target;
+use worktree_io::ttl::WorkspaceKind;
+
+fn entry_json(t: &Target<'_>, field: &str, detail: &str) -> String {
+    let path_esc = escape(&t.path.display().to_string());
+    let kind = t.kind.as_str();
+    let detail = escape(detail);
+    format!("{{\"path\":\"{path_esc}\",\"kind\":\"{kind}\",\"{field}\":\"{detail}\"}}")
+}
+
+/// Collects the outcome of a restore run and prints it as text or JSON.
+pub struct Report {
+    json: bool,
+    orphaned: usize,
+    restored: Vec<String>,
+    skipped: Vec<String>,
+    failed: Vec<String>,
+}
+
+impl Report {
+    pub fn new(json: bool, orphaned: usize) -> Self {
+        if !json {
+            eprintln!("Found {orphaned} orphaned worktree(s).");
+        }
+        Self {
+            json,
+            orphaned,
+            restored: Vec::new(),
+            skipped: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    pub fn skipped(&mut self, t: &Target<'_>, reason: &str) {
+        self.skipped.push(entry_json(t, "reason", reason));
+        if !self.json {
+            let label = match t.kind {
+                WorkspaceKind::Single => "worktree",
+                WorkspaceKind::Local => "local worktree",
+                WorkspaceKind::Multi => "multi-workspace member",
+            };
+            eprintln!("Skipping {label} {} — {reason}.", t.path.display());
+            if t.kind == WorkspaceKind::Local {
+                eprintln!("  Run `worktree open <issue-ref>` to restore it.");
+            }
+        }
+    }
+
+    pub fn restoring(&self, t: &Target<'_>) {
+        if !self.json {
+            eprintln!("Restoring {}…", t.path.display());
+        }
+    }
+
+    pub fn restored(&mut self, t: &Target<'_>) {
+        self.restored
+            .push(entry_json(t, "branch", t.branch.unwrap_or("")));
+        if !self.json {
+            eprintln!("  Restored: {}", t.path.display());
+        }
+    }
+
+    pub fn failed(&mut self, t: &Target<'_>, e: &Error) {
+        self.failed.push(entry_json(t, "error", &format!("{e:#}")));
+        eprintln!("  Failed to restore {}: {e:#}", t.path.display());
+    }
+
+    pub fn finish(&self) {
+        let (restored, skipped, failed) = (&self.restored, &self.skipped, &self.failed);
+        if self.json {
+            println!(
+                "{{\"orphaned\":{},\"restored\":[{}],\"skipped\":[{}],\"failed\":[{}]}}",
+                self.orphaned,
+                restored.join(","),
+                skipped.join(","),
+                failed.join(",")
+            );
+            return;
+        }
+        eprintln!(
+            "Restored {} of {} orphaned worktree(s).",
+            restored.len(),
+            self.orphaned
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "report_tests.rs"]
+mod tests;
@@ -0,0 +1,20 @@
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn test_failed_escapes_multi_line_errors() {
+    let t = Target {
+        path: Path::new("/w/issue-1"),
+        kind: WorkspaceKind::Single,
+        repo: None,
+        branch: None,
+        base_ref: None,
+    };
+    let mut report = Report::new(true, 1);
+    report.failed(&t, &anyhow::anyhow!("fatal: bad\nhint: \"retry\""));
+    assert_eq!(
+        report.failed[0],
+        r#"{"path":"/w/issue-1","kind":"single","error":"fatal: bad\u000ahint: \"retry\""}"#
+    );
+}
@@ -0,0 +1,66 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use std::process::Command;
+use worktree_io::hooks::HookContext;
+use worktree_io::opener::augmented_path;
+use worktree_io::ttl::WorkspaceRegistry;
+use worktree_io::workspace_env::workspace_env;
+
+use super::open::build_hook_context;
+use super::target::resolve_target;
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Issue reference or path of the workspace
+    #[arg(value_name = "REF|PATH")]
+    target: String,
+    /// Command to run and its arguments
+    #[arg(value_name = "COMMAND", required = true, last = true)]
+    command: Vec<String>,
+}
+
+/// Run a command in a workspace with its environment.
+///
+/// The command gets the `WORKTREE_*` variables hooks see, the shared cache
+/// variables, the repo's `[env]` table, and the variables saved by
+/// `open --env`. Its exit code becomes this process's.
+pub fn cmd_run(args: &RunArgs) -> Result<()> {
+    let path = resolve_target(&args.target)?;
+    if !path.is_dir() {
+        bail!("No workspace at {}", path.display());
+    }
+    let record = WorkspaceRegistry::load()?.get(&path).cloned();
+    let issue = record.as_ref().and_then(|r| r.issue.as_ref());
+    let mut ctx = issue.map_or_else(
+        || HookContext {
+            worktree_path: path.to_string_lossy().into_owned(),
+            ..HookContext::default()
+        },
+        |i| build_hook_context(i, &path),
+    );
+    if let Some(record) = &record {
+        if let Some(branch) = &record.branch {
+            ctx.branch.clone_from(branch);
+        }
+        ctx.port = record.port.map(|p| p.to_string()).unwrap_or_default();
+    }
+    ctx.extra_env = workspace_env(issue, &ctx, &path);
+    let (program, rest) = args.command.split_first().context("No command given")?;
+    let status = Command::new(program)
+        .args(rest)
+        .current_dir(&path)
+        .env("PATH", augmented_path())
+        .env("WORKTREE_OWNER", &ctx.owner)
+        .env("WORKTREE_REPO", &ctx.repo)
+        .env("WORKTREE_ISSUE", &ctx.issue)
+        .env("WORKTREE_BRANCH", &ctx.branch)
+        .env("WORKTREE_PATH", &ctx.worktree_path)
+        .env("WORKTREE_PORT", &ctx.port)
+        .envs(ctx.extra_env.iter().map(|(k, v)| (k, v)))
+        .status()
+        .with_context(|| format!("Failed to run {program}"))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use worktree_io::git::{
+    ahead_behind, current_branch, default_base, dirty_paths, last_commit, upstream_branch,
+};
+use worktree_io::lifecycle::members;
+use worktree_io::ttl::WorkspaceRecord;
+
+use super::super::prune::{cached_size, dir_size};
+use super::{Row, Tracking};
+
+/// Status rows for every workspace in `records` except trashed ones, in
+/// registry order. Workspaces are inspected on parallel threads, since most
+/// of the time goes to waiting on git.
+pub fn gather(records: &mut [WorkspaceRecord], now: SystemTime) -> Vec<Row> {
+    let threads = std::thread::available_parallelism().map_or(4, |n| n.get() * 2);
+    let chunk = records.len().div_ceil(threads).max(1);
+    std::thread::scope(|s| {
+        let workers: Vec<_> = records
+            .chunks_mut(chunk)
+            .map(|c| s.spawn(move || c.iter_mut().flat_map(|r| rows(r, now)).collect()))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_else(|_| Vec::new()))
+            .collect()
+    })
+}
+
+fn rows(record: &mut WorkspaceRecord, now: SystemTime) -> Vec<Row> {
+    let path = record.path.clone();
+    if record.trashed_at.is_some() {
+        return Vec::new();
+    }
+    if record.hibernated.is_some() {
+        return vec![Row::gone(path, "hibernated")];
+    }
+    if !path.exists() {
+        return vec![Row::gone(path, "missing")];
+    }
+    let repos = members(&path);
+    if repos == [path.clone()] {
+        let mut row = inspect(&path, record.base_ref.as_deref());
+        row.size = Some(cached_size(record, now));
+        return vec![row];
+    }
+    repos
+        .into_iter()
+        .map(|wt| Row {
+            size: Some(dir_size(&wt)),
+            ..inspect(&wt, None)
+        })
+        .collect()
+}
+
+fn inspect(wt: &Path, base: Option<&str>) -> Row {
+    let tracking = |name: String| {
+        let (ahead, behind) = ahead_behind(wt, &name).ok()?;
+        Some(Tracking {
+            name,
+            ahead,
+            behind,
+        })
+    };
+    let base = base.map(str::to_owned).or_else(|| default_base(wt));
+    Row {
+        branch: current_branch(wt).ok().flatten(),
+        dirty: dirty_paths(wt).ok().map(|d| d.len()),
+        upstream: upstream_branch(wt).and_then(tracking),
+        base: base.and_then(tracking),
+        last_commit: last_commit(wt),
+        ..Row::gone(wt.to_path_buf(), "ok")
+    }
+}
@@ -0,0 +1,57 @@
+use super::super::json::escape;
+use super::{Row, Tracking};
+
+fn string(s: Option<&str>) -> String {
+    s.map_or_else(|| "null".to_owned(), |s| format!("\"{}\"", escape(s)))
+}
+
+fn number(n: Option<impl ToString>) -> String {
+    n.map_or_else(|| "null".to_owned(), |n| n.to_string())
+}
+
+fn tracking(t: Option<&Tracking>) -> String {
+    t.map_or_else(
+        || "null".to_owned(),
+        |t| {
+            format!(
+                "{{\"name\":\"{}\",\"ahead\":{},\"behind\":{}}}",
+                escape(&t.name),
+                t.ahead,
+                t.behind
+            )
+        },
+    )
+}
+
+/// Print `rows` as a JSON report to stdout.
+pub fn print(rows: &[Row]) {
+    let entries: Vec<String> = rows.iter().map(entry).collect();
+    println!("{{\"workspaces\":[{}]}}", entries.join(","));
+}
+
+fn entry(r: &Row) -> String {
+    let last = r.last_commit.as_ref().map_or_else(
+        || "null".to_owned(),
+        |(at, subject)| {
+            let date = humantime::format_rfc3339_seconds(*at);
+            format!(
+                "{{\"subject\":\"{}\",\"date\":\"{date}\"}}",
+                escape(subject)
+            )
+        },
+    );
+    format!(
+        "{{\"path\":\"{}\",\"state\":\"{}\",\"branch\":{},\"dirty\":{},\"upstream\":{},\"base\":{},\"last_commit\":{last},\"size_bytes\":{}}}",
+        escape(&r.path.display().to_string()),
+        r.state,
+        string(r.branch.as_deref()),
+        number(r.dirty),
+        tracking(r.upstream.as_ref()),
+        tracking(r.base.as_ref()),
+        number(r.size),
+    )
+}
+
+#[cfg(test)]
+#[path = "json_tests.rs"]
+mod tests;
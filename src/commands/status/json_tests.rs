@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn test_tracking_and_nulls() {
+    let t = Tracking {
+        name: "origin/main".to_owned(),
+        ahead: 2,
+        behind: 1,
+    };
+    assert_eq!(
+        tracking(Some(&t)),
+        r#"{"name":"origin/main","ahead":2,"behind":1}"#
+    );
+    assert_eq!(tracking(None), "null");
+    assert_eq!(number(None::<u64>), "null");
+    assert_eq!(string(None), "null");
+}
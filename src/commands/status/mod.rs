@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use clap::Args;
+use worktree_io::ttl::WorkspaceRegistry;
+
+mod gather;
+mod json;
+mod table;
+
+/// How far `HEAD` is from another branch.
+pub struct Tracking {
+    pub name: String,
+    pub ahead: usize,
+    pub behind: usize,
+}
+
+/// The status of one workspace, or of one repository of a multi-repo
+/// workspace. Git fields are `None` when the worktree is gone.
+#[derive(Default)]
+pub struct Row {
+    pub path: PathBuf,
+    pub state: &'static str,
+    pub branch: Option<String>,
+    pub dirty: Option<usize>,
+    pub upstream: Option<Tracking>,
+    pub base: Option<Tracking>,
+    pub last_commit: Option<(SystemTime, String)>,
+    pub size: Option<u64>,
+}
+
+impl Row {
+    fn gone(path: PathBuf, state: &'static str) -> Self {
+        Self {
+            path,
+            state,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct StatusArgs {
+    /// Emit a JSON report to stdout instead of a table
+    #[arg(long)]
+    json: bool,
+}
+
+/// Show the branch, uncommitted changes, position against the upstream and
+/// base branch, last commit, and size of every registered workspace. Git is
+/// queried for many workspaces at once, so this stays fast with hundreds.
+pub fn cmd_status(args: &StatusArgs) -> Result<()> {
+    let mut registry = WorkspaceRegistry::load()?;
+    let now = SystemTime::now();
+    let rows = gather::gather(&mut registry.workspace, now);
+    // Sizes measured on the way are cached for `prune` and the next run.
+    registry.save()?;
+    if args.json {
+        json::print(&rows);
+    } else {
+        table::print(&rows, now);
+    }
+    Ok(())
+}
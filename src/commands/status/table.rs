@@ -0,0 +1,92 @@
+use std::time::{Duration, SystemTime};
+
+use super::super::prune::format_bytes;
+use super::{Row, Tracking};
+
+const HEADER: [&str; 7] = [
+    "WORKSPACE",
+    "BRANCH",
+    "DIRTY",
+    "UPSTREAM",
+    "BASE",
+    "SIZE",
+    "LAST COMMIT",
+];
+
+/// Longest commit subject shown before it is cut short.
+const SUBJECT_WIDTH: usize = 50;
+
+/// Print `rows` as an aligned table, one line per workspace.
+pub fn print(rows: &[Row], now: SystemTime) {
+    if rows.is_empty() {
+        eprintln!("No workspaces registered.");
+        return;
+    }
+    let mut lines = vec![HEADER.map(str::to_owned)];
+    lines.extend(rows.iter().map(|r| cells(r, now)));
+    let mut widths = [0; 7];
+    for line in &lines {
+        for (w, cell) in widths.iter_mut().zip(line) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+    for line in &lines {
+        let padded: Vec<String> = line
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{cell:<w$}"))
+            .collect();
+        eprintln!("{}", padded.join("  ").trim_end());
+    }
+}
+
+fn cells(r: &Row, now: SystemTime) -> [String; 7] {
+    let dash = || "-".to_owned();
+    let branch = match r.state {
+        "ok" => r.branch.clone().unwrap_or_else(|| "(detached)".to_owned()),
+        state => format!("({state})"),
+    };
+    let last = r.last_commit.as_ref().map_or_else(dash, |(at, subject)| {
+        let subject: String = subject.chars().take(SUBJECT_WIDTH).collect();
+        format!(
+            "{} ago  {subject}",
+            ago(now.duration_since(*at).unwrap_or_default())
+        )
+    });
+    [
+        r.path.display().to_string(),
+        branch,
+        r.dirty.map_or_else(dash, |n| n.to_string()),
+        r.upstream.as_ref().map_or_else(dash, counts),
+        r.base
+            .as_ref()
+            .map_or_else(dash, |t| format!("{} {}", counts(t), t.name)),
+        r.size.map_or_else(dash, format_bytes),
+        last,
+    ]
+}
+
+fn counts(t: &Tracking) -> String {
+    format!("↑{} ↓{}", t.ahead, t.behind)
+}
+
+/// `d` in its largest whole unit, e.g. `3d` or `5m`.
+fn ago(d: Duration) -> String {
+    const UNITS: [(&str, u64); 5] = [
+        ("w", 604_800),
+        ("d", 86_400),
+        ("h", 3_600),
+        ("m", 60),
+        ("s", 1),
+    ];
+    let secs = d.as_secs();
+    let (unit, size) = UNITS
+        .into_iter()
+        .find(|&(_, s)| secs >= s)
+        .unwrap_or(("s", 1));
+    format!("{}{unit}", secs / size)
+}
+
+#[cfg(test)]
+#[path = "table_tests.rs"]
+mod tests;
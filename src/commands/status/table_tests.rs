@@ -0,0 +1,10 @@
+use super::*;
+
+#[test]
+fn test_ago_uses_largest_whole_unit() {
+    assert_eq!(ago(Duration::from_secs(0)), "0s");
+    assert_eq!(ago(Duration::from_secs(59)), "59s");
+    assert_eq!(ago(Duration::from_secs(3_700)), "1h");
+    assert_eq!(ago(Duration::from_secs(3 * 86_400 + 5)), "3d");
+    assert_eq!(ago(Duration::from_secs(1_300_000)), "2w");
+}
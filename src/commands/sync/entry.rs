@@ -0,0 +1,43 @@
+use std::path::{Path, PathBuf};
+
+use super::super::json::escape;
+
+fn quoted(s: Option<&str>) -> String {
+    s.map_or_else(|| "null".to_owned(), |s| format!("\"{}\"", escape(s)))
+}
+
+/// What happened to one workspace.
+pub struct Entry {
+    pub path: PathBuf,
+    pub base: Option<String>,
+    /// `updated`, `up-to-date`, `skipped`, `conflict`, or `failed`.
+    pub outcome: &'static str,
+    pub new_commits: Option<usize>,
+    pub reason: Option<String>,
+}
+
+impl Entry {
+    pub fn new(path: &Path, base: Option<&str>, outcome: &'static str) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            base: base.map(str::to_owned),
+            outcome,
+            new_commits: None,
+            reason: None,
+        }
+    }
+
+    /// This entry as a JSON object.
+    pub fn json(&self) -> String {
+        let commits = self
+            .new_commits
+            .map_or_else(|| "null".to_owned(), |n| n.to_string());
+        format!(
+            "{{\"path\":\"{}\",\"base\":{},\"outcome\":\"{}\",\"new_commits\":{commits},\"reason\":{}}}",
+            escape(&self.path.display().to_string()),
+            quoted(self.base.as_deref()),
+            self.outcome,
+            quoted(self.reason.as_deref()),
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::Args;
+use worktree_io::config::Config;
+use worktree_io::git::{common_dir, default_base, get_remote_url, git_fetch};
+use worktree_io::lifecycle::{members, sync_worktree, SyncStrategy};
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+use super::target::resolve_target;
+
+mod entry;
+mod report;
+
+use report::SyncReport;
+
+#[derive(Args)]
+pub struct SyncArgs {
+    /// Issue references or paths of the workspaces to sync; all by default
+    #[arg(value_name = "REF|PATH")]
+    targets: Vec<String>,
+    /// Rebase onto or merge in the base branch, instead of
+    /// `workspace.sync_strategy`
+    #[arg(long, value_name = "rebase|merge")]
+    strategy: Option<SyncStrategy>,
+    /// Emit a JSON report to stdout instead of human-readable output
+    #[arg(long)]
+    json: bool,
+}
+
+/// Fetch the repository of every selected workspace once, then rebase each
+/// workspace's branch onto its base branch, or merge the base branch in.
+/// Workspaces with uncommitted changes are skipped, and conflicts are
+/// aborted and reported, so no branch is left half-synced.
+pub fn cmd_sync(args: &SyncArgs) -> Result<()> {
+    let strategy = match args.strategy {
+        Some(s) => s,
+        None => Config::load()?.workspace.sync_strategy,
+    };
+    let registry = WorkspaceRegistry::load()?;
+    let mut report = SyncReport::new(args.json, strategy);
+    let mut worktrees: Vec<(PathBuf, Option<String>)> = Vec::new();
+    for record in selected(&registry, &args.targets)? {
+        if record.hibernated.is_some() {
+            report.skipped(&record.path, "hibernated");
+        } else if !record.path.exists() {
+            report.skipped(&record.path, "missing");
+        } else if members(&record.path) == [record.path.clone()] {
+            worktrees.push((record.path.clone(), record.base_ref.clone()));
+        } else {
+            worktrees.extend(members(&record.path).into_iter().map(|wt| (wt, None)));
+        }
+    }
+    let repos: BTreeSet<PathBuf> = worktrees
+        .iter()
+        .filter_map(|(wt, _)| common_dir(wt).ok())
+        .collect();
+    for repo in repos.iter().filter(|r| get_remote_url(r, "origin").is_ok()) {
+        if !args.json {
+            eprintln!("Fetching {}…", repo.display());
+        }
+        match git_fetch(repo) {
+            Ok(()) => report.fetched(),
+            Err(e) => eprintln!("Warning: failed to fetch {}: {e}", repo.display()),
+        }
+    }
+    for (wt, base) in worktrees {
+        match base.or_else(|| default_base(&wt)) {
+            Some(base) => report.synced(&wt, &base, sync_worktree(&wt, &base, strategy)),
+            None => report.skipped(&wt, "no base branch"),
+        }
+    }
+    report.finish()
+}
+
+/// The registered, untrashed workspaces named by `targets`, or all of them.
+fn selected<'a>(
+    registry: &'a WorkspaceRegistry,
+    targets: &[String],
+) -> Result<Vec<&'a WorkspaceRecord>> {
+    if targets.is_empty() {
+        let live = registry.workspace.iter().filter(|r| r.trashed_at.is_none());
+        return Ok(live.collect());
+    }
+    targets
+        .iter()
+        .map(|t| {
+            let path = resolve_target(t)?;
+            match registry.get(&path) {
+                Some(r) if r.trashed_at.is_none() => Ok(r),
+                _ => bail!("No workspace registered at {}", path.display()),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use worktree_io::lifecycle::{SyncStrategy, Synced};
+
+use super::entry::Entry;
+
+/// Collects the outcome of a sync run and prints it as text or JSON.
+pub struct SyncReport {
+    json: bool,
+    strategy: SyncStrategy,
+    fetched: usize,
+    entries: Vec<Entry>,
+}
+
+impl SyncReport {
+    pub const fn new(json: bool, strategy: SyncStrategy) -> Self {
+        Self {
+            json,
+            strategy,
+            fetched: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Record that one repository was fetched.
+    pub const fn fetched(&mut self) {
+        self.fetched += 1;
+    }
+
+    /// Record a workspace left alone for `reason`.
+    pub fn skipped(&mut self, path: &Path, reason: &str) {
+        let line = format!("Skipped {}: {reason}", path.display());
+        let mut entry = Entry::new(path, None, "skipped");
+        entry.reason = Some(reason.to_owned());
+        self.push(entry, &line);
+    }
+
+    /// Record the outcome of syncing the worktree at `path` with `base`.
+    pub fn synced(&mut self, path: &Path, base: &str, result: Result<Synced>) {
+        let (p, s) = (path.display(), self.strategy);
+        let mut entry = Entry::new(path, Some(base), "updated");
+        let line = match result {
+            Ok(Synced::Updated(n)) => {
+                entry.new_commits = Some(n);
+                match s {
+                    SyncStrategy::Rebase => format!("Rebased {p} onto {base} ({n} new commit(s))"),
+                    SyncStrategy::Merge => format!("Merged {base} into {p} ({n} new commit(s))"),
+                }
+            }
+            Ok(Synced::UpToDate) => {
+                entry.outcome = "up-to-date";
+                format!("{p} is up to date with {base}")
+            }
+            Ok(Synced::Dirty) => return self.skipped(path, "uncommitted changes"),
+            Ok(Synced::Detached) => return self.skipped(path, "detached HEAD"),
+            Ok(Synced::Conflict) => {
+                entry.outcome = "conflict";
+                format!("Conflict trying to {s} {p} with {base}; aborted, the branch is unchanged")
+            }
+            Err(e) => {
+                entry.outcome = "failed";
+                entry.reason = Some(format!("{e:#}"));
+                format!("Failed to sync {p}: {e:#}")
+            }
+        };
+        self.push(entry, &line);
+    }
+
+    fn push(&mut self, entry: Entry, line: &str) {
+        if !self.json {
+            eprintln!("{line}");
+        }
+        self.entries.push(entry);
+    }
+
+    /// Print the summary, or the JSON report, and fail when any workspace
+    /// conflicted or could not be synced.
+    pub fn finish(self) -> Result<()> {
+        let count = |o: &str| self.entries.iter().filter(|e| e.outcome == o).count();
+        let [updated, current, skipped, conflicts, failed] =
+            ["updated", "up-to-date", "skipped", "conflict", "failed"].map(count);
+        if self.json {
+            let entries: Vec<String> = self.entries.iter().map(Entry::json).collect();
+            println!(
+                "{{\"strategy\":\"{}\",\"fetched\":{},\"updated\":{updated},\"up_to_date\":{current},\"skipped\":{skipped},\"conflicts\":{conflicts},\"failed\":{failed},\"workspaces\":[{}]}}",
+                self.strategy,
+                self.fetched,
+                entries.join(",")
+            );
+        } else {
+            eprintln!("{updated} updated, {current} up to date, {skipped} skipped, {conflicts} with conflicts, {failed} failed.");
+        }
+        if conflicts + failed > 0 {
+            bail!("{} workspace(s) could not be synced", conflicts + failed);
+        }
+        Ok(())
+    }
+}
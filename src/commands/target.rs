@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use worktree_io::git::common_dir;
+use worktree_io::issue::{worktrees_root, IssueRef};
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+/// Resolve a `<ref|path>` command argument to a workspace directory.
+///
+/// An existing path wins. Otherwise the argument is parsed as an issue
+/// reference and mapped to its worktree path; ad-hoc `owner/repo` slugs are
+/// not references to a specific workspace, so they fall back to being
+/// treated as a (possibly deleted) path.
+pub fn resolve_target(arg: &str) -> Result<PathBuf> {
+    let path = Path::new(arg);
+    if path.exists() {
+        return std::fs::canonicalize(path)
+            .with_context(|| format!("Failed to resolve {}", path.display()));
+    }
+    match IssueRef::parse(arg) {
+        Ok(IssueRef::Adhoc { .. }) | Err(_) => std::path::absolute(path)
+            .with_context(|| format!("Failed to resolve {}", path.display())),
+        Ok(issue) => Ok(issue.temp_path()),
+    }
+}
+
+/// Check that `path` is a workspace worktree manages: a registered one, or a
+/// linked worktree of a managed bare clone or of a repository a registered
+/// workspace was created from.
+///
+/// # Errors
+///
+/// Returns an error naming `path` when it is neither.
+pub fn ensure_managed(path: &Path, registry: &WorkspaceRegistry) -> Result<()> {
+    if registry.contains(path) || is_managed_worktree(path, registry) {
+        return Ok(());
+    }
+    bail!("{} is not a workspace managed by worktree", path.display())
+}
+
+fn is_managed_worktree(path: &Path, registry: &WorkspaceRegistry) -> bool {
+    if !path.join(".git").is_file() {
+        return false;
+    }
+    let Some(common) = common_dir(path).ok().and_then(|c| c.canonicalize().ok()) else {
+        return false;
+    };
+    let is_common = |p: &Path| p.canonicalize().is_ok_and(|p| p == common);
+    let mut repos = registry.workspace.iter().flat_map(WorkspaceRecord::repos);
+    worktrees_root()
+        .canonicalize()
+        .is_ok_and(|root| common.starts_with(root))
+        || repos.any(|r| is_common(r) || is_common(&r.join(".git")))
+}
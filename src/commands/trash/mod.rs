@@ -0,0 +1,71 @@
+use std::time::SystemTime;
+
+use anyhow::{bail, Result};
+use clap::Subcommand;
+use worktree_io::lifecycle::{empty_trash, restore_trashed, TrashEntry};
+use worktree_io::ttl::WorkspaceRegistry;
+
+mod ops;
+
+use ops::forget;
+pub use ops::{empty_expired_trash, move_to_trash};
+
+#[derive(Subcommand)]
+pub enum TrashAction {
+    /// List the workspaces in the trash
+    List,
+    /// Move a workspace out of the trash back to where it was
+    Restore {
+        /// Trash entry to restore, as printed by `worktree trash list`
+        id: String,
+    },
+    /// Delete everything in the trash for good
+    Empty,
+}
+
+pub fn cmd_trash(action: TrashAction) -> Result<()> {
+    let root = TrashEntry::root()?;
+    match action {
+        TrashAction::List => {
+            let entries = TrashEntry::list(&root);
+            if entries.is_empty() {
+                eprintln!("The trash is empty.");
+            }
+            for e in entries {
+                let when = humantime::format_rfc3339_seconds(e.trashed_at);
+                eprintln!("  {}  {when}  {}", e.id, e.workspace.path.display());
+            }
+        }
+        TrashAction::Restore { id } => restore(&id)?,
+        TrashAction::Empty => {
+            let mut registry = WorkspaceRegistry::load()?;
+            let purged = empty_trash(&root, None, SystemTime::now())?;
+            forget(&mut registry, &purged);
+            registry.save()?;
+            eprintln!("Deleted {} workspace(s) from the trash.", purged.len());
+        }
+    }
+    Ok(())
+}
+
+/// Restore the workspace most recently moved to the trash.
+pub fn cmd_undo() -> Result<()> {
+    let Some(last) = TrashEntry::list(&TrashEntry::root()?).pop() else {
+        bail!("The trash is empty; nothing to undo.");
+    };
+    restore(&last.id)
+}
+
+fn restore(id: &str) -> Result<()> {
+    let entry = restore_trashed(&TrashEntry::root()?, id)?;
+    let mut record = entry.workspace;
+    record.trashed_at = None;
+    record.last_opened_at = Some(SystemTime::now());
+    let path = record.path.clone();
+    let mut registry = WorkspaceRegistry::load()?;
+    registry.remove(&path);
+    registry.insert(record);
+    registry.save()?;
+    eprintln!("Restored {} from the trash.", path.display());
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use worktree_io::config::WorkspaceConfig;
+use worktree_io::lifecycle::{empty_trash, trash_workspace, TrashEntry, DEFAULT_TRASH_RETENTION};
+use worktree_io::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+/// Move `record`'s workspace to the trash and mark it trashed in `registry`,
+/// which keeps its entry until the trash retention runs out.
+pub fn move_to_trash(
+    registry: &mut WorkspaceRegistry,
+    record: &WorkspaceRecord,
+    drop_branch: bool,
+) -> Result<()> {
+    let (root, now) = (TrashEntry::root()?, SystemTime::now());
+    let stored = registry.get(&record.path).cloned();
+    let record = stored.as_ref().unwrap_or(record);
+    let entry = trash_workspace(record, drop_branch, &root, now)?;
+    registry.remove(&entry.workspace.path);
+    let mut record = entry.workspace;
+    record.trashed_at = Some(entry.trashed_at);
+    record.port = None;
+    registry.workspace.push(record);
+    Ok(())
+}
+
+/// Delete workspaces trashed longer ago than `workspace.trash_retention`.
+pub fn empty_expired_trash(registry: &mut WorkspaceRegistry, cfg: &WorkspaceConfig) -> Result<()> {
+    let retention = cfg.trash_retention.unwrap_or(DEFAULT_TRASH_RETENTION);
+    let purged = empty_trash(&TrashEntry::root()?, Some(retention), SystemTime::now())?;
+    forget(registry, &purged);
+    Ok(())
+}
+
+/// Drop the registry entries of trashed workspaces deleted for good.
+pub(super) fn forget(registry: &mut WorkspaceRegistry, purged: &[TrashEntry]) {
+    registry.workspace.retain(|r| {
+        let gone =
+            |e: &TrashEntry| e.workspace.path == r.path && Some(e.trashed_at) == r.trashed_at;
+        !purged.iter().any(gone)
+    });
+}
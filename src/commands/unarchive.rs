@@ -0,0 +1,54 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use clap::Args;
+use worktree_io::lifecycle::{archive_unsaved, unarchive, Archive};
+use worktree_io::ttl::{WorkspaceKind, WorkspaceRecord, WorkspaceRegistry};
+
+#[derive(Args)]
+pub struct UnarchiveArgs {
+    /// Archive to restore (omit to list archives)
+    #[arg(value_name = "ID")]
+    id: Option<String>,
+}
+
+/// Save the unsaved work of `record`'s workspace before it is force-removed,
+/// telling the user how to get it back.
+pub fn archive_before_removal(record: &WorkspaceRecord) -> Result<()> {
+    if let Some(id) = archive_unsaved(record)? {
+        eprintln!(
+            "Archived unsaved work from {} as {id}; restore it with `worktree unarchive {id}`.",
+            record.path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Recreate an archived workspace and re-register it, or list the archives.
+pub fn cmd_unarchive(args: &UnarchiveArgs) -> Result<()> {
+    let Some(id) = &args.id else {
+        let archives = Archive::list()?;
+        if archives.is_empty() {
+            eprintln!("No archives.");
+        }
+        for a in archives {
+            let when = humantime::format_rfc3339_seconds(a.archived_at);
+            eprintln!("  {}  {when}  {}", a.id, a.workspace.path.display());
+        }
+        return Ok(());
+    };
+    let archive = unarchive(id)?;
+    let mut record = archive.workspace;
+    record.last_opened_at = Some(SystemTime::now());
+    let path = record.path.clone();
+    let multi = record.kind == WorkspaceKind::Multi;
+    let mut registry = WorkspaceRegistry::load()?;
+    registry.remove(&path);
+    registry.insert(record);
+    registry.save()?;
+    eprintln!("Restored {} from archive {id}.", path.display());
+    if multi {
+        eprintln!("Run `worktree restore` to recreate its members that had no unsaved work.");
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::fmt::Write as _;
+
+use anyhow::Result;
+
+use super::ser::toml_quoted;
+use crate::cache::CacheConfig;
+
+impl CacheConfig {
+    /// Get a `cache.*` value; `key` omits the `cache.` prefix.
+    pub(super) fn get_value(&self, key: &str) -> Result<String> {
+        if let Some(var) = key.strip_prefix("env.") {
+            return Ok(self.env.get(var).cloned().unwrap_or_default());
+        }
+        if let Some(path) = key.strip_prefix("link.") {
+            return Ok(self.link.get(path).cloned().unwrap_or_default());
+        }
+        match key {
+            "sibling" => Ok(self.sibling.join(",")),
+            _ => anyhow::bail!("Unknown config key: cache.{key}"),
+        }
+    }
+
+    /// Set a `cache.*` value; `key` omits the `cache.` prefix, and the empty
+    /// string removes the entry.
+    pub(super) fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        let mut next = self.clone();
+        let (map, name) = if let Some(var) = key.strip_prefix("env.") {
+            (&mut next.env, var)
+        } else if let Some(path) = key.strip_prefix("link.") {
+            (&mut next.link, path)
+        } else if key == "sibling" {
+            let items = value.split(',').map(str::trim).filter(|s| !s.is_empty());
+            next.sibling = items.map(str::to_owned).collect();
+            next.validate().map_err(|e| anyhow::anyhow!(e))?;
+            *self = next;
+            return Ok(());
+        } else {
+            anyhow::bail!("Unknown config key: cache.{key}");
+        };
+        if value.is_empty() {
+            map.remove(name);
+        } else {
+            map.insert(name.to_owned(), value.to_owned());
+        }
+        next.validate().map_err(|e| anyhow::anyhow!(e))?;
+        *self = next;
+        Ok(())
+    }
+
+    /// Append the `[cache]` section, with comments, to `out` when anything is
+    /// shared.
+    pub(super) fn write_toml_with_comments(&self, out: &mut String) {
+        if self.is_empty() {
+            return;
+        }
+        out.push_str("# Dependency and build caches shared by all worktrees of a repo.\n");
+        out.push_str("[cache]\n");
+        if !self.sibling.is_empty() {
+            let items: Vec<String> = self.sibling.iter().map(|s| toml_quoted(s)).collect();
+            out.push_str("# Directories cloned from the freshest sibling worktree.\n");
+            writeln!(out, "sibling = [{}]", items.join(", ")).unwrap();
+        }
+        let tables = [
+            ("env", "Variables set to shared directories.", &self.env),
+            (
+                "link",
+                "Worktree paths symlinked to shared directories.",
+                &self.link,
+            ),
+        ];
+        for (name, doc, map) in tables.into_iter().filter(|(.., m)| !m.is_empty()) {
+            writeln!(out, "# {doc}\n[cache.{name}]").unwrap();
+            for (k, v) in map {
+                writeln!(out, "{} = {}", toml_quoted(k), toml_quoted(v)).unwrap();
+            }
+        }
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+#[path = "ops_cache_tests.rs"]
+mod tests;
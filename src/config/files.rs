@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::local_files::LocalFiles;
+
+/// Local-only files copied or symlinked into new worktrees.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FilesConfig {
+    /// The files to add and where they come from; a repo's `.worktree.toml`
+    /// can add to these.
+    #[serde(flatten)]
+    pub local: LocalFiles,
+    /// The designated primary worktree of each repository, keyed by
+    /// `owner/repo`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub primary: BTreeMap<String, PathBuf>,
+}
+
+/// Split a comma-separated list; the empty string clears it.
+fn parse_list(value: &str) -> Result<Vec<String>> {
+    let items = value.split(',').map(str::trim).filter(|p| !p.is_empty());
+    items
+        .map(|p| {
+            glob::Pattern::new(p).map_err(|e| anyhow::anyhow!("Invalid pattern {p:?}: {e}"))?;
+            Ok(p.to_owned())
+        })
+        .collect()
+}
+
+impl FilesConfig {
+    /// Get a `files.*` value; `key` omits the `files.` prefix.
+    pub(super) fn get_value(&self, key: &str) -> Result<String> {
+        if let Some(slug) = key.strip_prefix("primary.") {
+            let path = self.primary.get(slug);
+            return Ok(path.map_or_else(String::new, |p| p.display().to_string()));
+        }
+        match key {
+            "source" => Ok(self
+                .local
+                .source
+                .as_ref()
+                .map_or_else(String::new, ToString::to_string)),
+            "copy" => Ok(self.local.copy.join(",")),
+            "symlink" => Ok(self.local.symlink.join(",")),
+            _ => anyhow::bail!("Unknown config key: files.{key}"),
+        }
+    }
+
+    /// Set a `files.*` value; `key` omits the `files.` prefix.
+    pub(super) fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        if let Some(slug) = key.strip_prefix("primary.") {
+            if value.is_empty() {
+                self.primary.remove(slug);
+            } else {
+                self.primary.insert(slug.to_owned(), PathBuf::from(value));
+            }
+            return Ok(());
+        }
+        match key {
+            "source" => {
+                self.local.source = (!value.is_empty())
+                    .then(|| value.parse().map_err(|e| anyhow::anyhow!("{e}")))
+                    .transpose()?;
+            }
+            "copy" => self.local.copy = parse_list(value)?,
+            "symlink" => self.local.symlink = parse_list(value)?,
+            _ => anyhow::bail!("Unknown config key: files.{key}"),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Write as _;
+
+use super::ser::toml_quoted;
+use super::FilesConfig;
+use crate::local_files::LocalFiles;
+
+impl FilesConfig {
+    /// Append the `[files]` section, with comments, to `out` when anything
+    /// is set.
+    pub(super) fn write_toml_with_comments(&self, out: &mut String) {
+        let local = &self.local;
+        if *local == LocalFiles::default() && self.primary.is_empty() {
+            return;
+        }
+        out.push_str("# Local-only files copied or symlinked into new worktrees.\n");
+        out.push_str("[files]\n");
+        if let Some(source) = &local.source {
+            out.push_str(
+                "# Where they come from: \"primary\", \"template\", or \"workspace:<REF|PATH>\".\n",
+            );
+            writeln!(out, "source = {}", toml_quoted(&source.to_string())).unwrap();
+        }
+        let lists = [
+            ("copy", "copied", &local.copy),
+            ("symlink", "symlinked", &local.symlink),
+        ];
+        for (key, verb, list) in lists.into_iter().filter(|(.., l)| !l.is_empty()) {
+            let items: Vec<String> = list.iter().map(|p| toml_quoted(p)).collect();
+            writeln!(out, "# Files or globs {verb} from the source.").unwrap();
+            writeln!(out, "{key} = [{}]", items.join(", ")).unwrap();
+        }
+        if !self.primary.is_empty() {
+            out.push_str("# The primary worktree of each repository, keyed by `owner/repo`.\n");
+            out.push_str("[files.primary]\n");
+            for (slug, path) in &self.primary {
+                let path = toml_quoted(&path.display().to_string());
+                writeln!(out, "{} = {path}", toml_quoted(slug)).unwrap();
+            }
+        }
+        out.push('\n');
+    }
+}
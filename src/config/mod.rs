@@ -1,6 +1,14 @@
+mod cache_ser;
+mod files;
+mod files_ser;
+mod open;
 mod ops;
 mod ops_get_set;
+mod ports_ser;
 mod ser;
+mod workspace;
+mod workspace_ops;
+mod workspace_ser;
 
 #[cfg(test)]
 #[path = "ops_tests.rs"]
@@ -13,9 +21,27 @@ mod ops_auto_prune_tests;
 #[cfg(test)]
 #[path = "ops_temp_tests.rs"]
 mod ops_temp_tests;
+
+#[cfg(test)]
+#[path = "ops_hibernate_tests.rs"]
+mod ops_hibernate_tests;
+
+#[cfg(test)]
+#[path = "ops_sync_tests.rs"]
+mod ops_sync_tests;
+
+#[cfg(test)]
+#[path = "ops_files_tests.rs"]
+mod ops_files_tests;
+
+#[cfg(test)]
+#[path = "ops_clean_tests.rs"]
+mod ops_clean_tests;
 use serde::{Deserialize, Serialize};
 
-use crate::ttl::Ttl;
+pub use files::FilesConfig;
+pub use open::OpenConfig;
+pub use workspace::WorkspaceConfig;
 
 /// Top-level configuration for the worktree CLI.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -29,21 +55,13 @@ pub struct Config {
     pub hooks: HooksConfig,
     /// Workspace lifecycle configuration.
     pub workspace: WorkspaceConfig,
-}
-
-/// Workspace lifecycle configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(default)]
-pub struct WorkspaceConfig {
-    /// Maximum age of a workspace before it is considered expired.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ttl: Option<Ttl>,
-    /// When true, expired worktrees are pruned each time `open` is invoked.
-    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
-    pub auto_prune: bool,
-    /// When true, worktrees are stored under the OS temp directory.
-    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
-    pub temp: bool,
+    /// Local-only files copied into new worktrees.
+    pub files: FilesConfig,
+    /// Dependency and build caches shared across worktrees.
+    pub cache: crate::cache::CacheConfig,
+    /// Port blocks allocated to workspaces.
+    #[serde(skip_serializing_if = "crate::ports::PortsConfig::is_default")]
+    pub ports: crate::ports::PortsConfig,
 }
 
 /// Shell scripts executed before and after opening a workspace.
@@ -68,20 +86,6 @@ pub struct EditorConfig {
     pub background: bool,
 }
 
-/// Controls how the workspace is opened.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(default)]
-pub struct OpenConfig {
-    /// Whether to launch the configured editor when opening a workspace.
-    pub editor: bool,
-}
-
-impl Default for OpenConfig {
-    fn default() -> Self {
-        Self { editor: true }
-    }
-}
-
 #[cfg(test)]
 #[path = "mod_tests.rs"]
 mod tests;
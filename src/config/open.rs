@@ -0,0 +1,50 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Controls how the workspace is opened.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpenConfig {
+    /// Whether to launch the configured editor when opening a workspace.
+    pub editor: bool,
+    /// Whether to write the workspace environment to an `.envrc` for direnv.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub envrc: bool,
+}
+
+impl Default for OpenConfig {
+    fn default() -> Self {
+        Self {
+            editor: true,
+            envrc: false,
+        }
+    }
+}
+
+impl OpenConfig {
+    /// Get an `open.*` value; `key` omits the `open.` prefix.
+    pub(super) fn get_value(&self, key: &str) -> Result<String> {
+        match key {
+            "editor" => Ok(self.editor.to_string()),
+            "envrc" => Ok(self.envrc.to_string()),
+            _ => anyhow::bail!("Unknown config key: open.{key}"),
+        }
+    }
+
+    /// Set an `open.*` value; `key` omits the `open.` prefix.
+    pub(super) fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        let flag = match key {
+            "editor" => &mut self.editor,
+            "envrc" => &mut self.envrc,
+            _ => anyhow::bail!("Unknown config key: open.{key}"),
+        };
+        *flag = value
+            .parse::<bool>()
+            .with_context(|| format!("Invalid boolean value: {value}"))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "ops_open_tests.rs"]
+mod tests;
@@ -0,0 +1,37 @@
+use crate::config::Config;
+
+#[test]
+fn test_cache_values_round_trip() {
+    let mut c = Config::default();
+    c.set_value("cache.env.CARGO_TARGET_DIR", "target/{{base}}")
+        .unwrap();
+    c.set_value("cache.link..turbo", "turbo").unwrap();
+    c.set_value("cache.sibling", "node_modules, .venv").unwrap();
+    assert_eq!(
+        c.get_value("cache.env.CARGO_TARGET_DIR").unwrap(),
+        "target/{{base}}"
+    );
+    assert_eq!(c.get_value("cache.sibling").unwrap(), "node_modules,.venv");
+
+    let s = c.to_toml_with_comments();
+    assert!(
+        s.contains("[cache.env]\n\"CARGO_TARGET_DIR\" = \"target/{{base}}\""),
+        "{s}"
+    );
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.cache, c.cache);
+}
+
+#[test]
+fn test_cache_values_clear_and_reject() {
+    let mut c = Config::default();
+    c.set_value("cache.env.X", "x").unwrap();
+    c.set_value("cache.env.X", "").unwrap();
+    assert!(c.cache.is_empty());
+    assert!(!c.to_toml_with_comments().contains("[cache]"));
+    assert!(c.set_value("cache.env.BAD-NAME", "x").is_err());
+    assert!(c.set_value("cache.link.../up", "x").is_err());
+    assert!(c.set_value("cache.sibling", "/abs").is_err());
+    assert!(c.cache.is_empty());
+    assert!(c.get_value("cache.nope").is_err());
+}
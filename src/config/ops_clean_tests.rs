@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn test_clean_after_round_trips() {
+    let mut c = Config::default();
+    assert_eq!(c.get_value("workspace.clean_after").unwrap(), "");
+    c.set_value("workspace.clean_after", "3days").unwrap();
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("clean_after = \"3days\""));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace.clean_after, c.workspace.clean_after);
+    c.set_value("workspace.clean_after", "").unwrap();
+    assert!(c.workspace.clean_after.is_none());
+}
+
+#[test]
+fn test_clean_after_rejects_invalid_duration() {
+    assert!(Config::default()
+        .set_value("workspace.clean_after", "soon")
+        .is_err());
+}
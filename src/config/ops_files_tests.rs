@@ -0,0 +1,41 @@
+use super::*;
+use crate::local_files::FileSource;
+
+#[test]
+fn test_files_values_round_trip() {
+    let mut c = Config::default();
+    c.set_value("files.source", "workspace:acme/api#1").unwrap();
+    c.set_value("files.copy", ".env, config/*.yml").unwrap();
+    c.set_value("files.symlink", ".idea").unwrap();
+    c.set_value("files.primary.acme/api", "~/src/api").unwrap();
+    assert_eq!(c.get_value("files.copy").unwrap(), ".env,config/*.yml");
+    assert_eq!(c.get_value("files.primary.acme/api").unwrap(), "~/src/api");
+
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("[files]\n"), "{s}");
+    assert!(s.contains("copy = [\".env\", \"config/*.yml\"]"), "{s}");
+    assert!(
+        s.contains("[files.primary]\n\"acme/api\" = \"~/src/api\""),
+        "{s}"
+    );
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(
+        parsed.files.local.source,
+        Some(FileSource::Workspace("acme/api#1".into()))
+    );
+    assert_eq!(parsed.files.local.symlink, [".idea"]);
+    assert_eq!(parsed.files.primary.len(), 1);
+}
+
+#[test]
+fn test_files_values_clear_and_reject() {
+    let mut c = Config::default();
+    c.set_value("files.copy", ".env").unwrap();
+    c.set_value("files.copy", "").unwrap();
+    c.set_value("files.primary.acme/api", "").unwrap();
+    assert!(c.files.local.copy.is_empty());
+    assert!(!c.to_toml_with_comments().contains("[files]"));
+    assert!(c.set_value("files.source", "elsewhere").is_err());
+    assert!(c.set_value("files.copy", "[a").is_err());
+    assert!(c.get_value("files.nope").is_err());
+}
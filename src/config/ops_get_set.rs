@@ -9,16 +9,24 @@ impl Config {
     ///
     /// Returns an error if `key` is not a recognised config key.
     pub fn get_value(&self, key: &str) -> Result<String> {
+        if let Some(k) = key.strip_prefix("workspace.") {
+            return self.workspace.get_value(k);
+        }
+        if let Some(k) = key.strip_prefix("files.") {
+            return self.files.get_value(k);
+        }
+        if let Some(k) = key.strip_prefix("cache.") {
+            return self.cache.get_value(k);
+        }
+        if let Some(k) = key.strip_prefix("open.") {
+            return self.open.get_value(k);
+        }
+        if let Some(k) = key.strip_prefix("ports.") {
+            return self.ports.get_value(k);
+        }
         match key {
             "editor" | "editor.command" => Ok(self.editor.command.clone().unwrap_or_default()),
             "editor.background" => Ok(self.editor.background.to_string()),
-            "open.editor" => Ok(self.open.editor.to_string()),
-            "workspace.ttl" => Ok(self
-                .workspace
-                .ttl
-                .map_or_else(String::new, |t| t.to_string())),
-            "workspace.auto_prune" => Ok(self.workspace.auto_prune.to_string()),
-            "workspace.temp" => Ok(self.workspace.temp.to_string()),
             _ => anyhow::bail!("Unknown config key: {key}"),
         }
     }
@@ -30,6 +38,21 @@ impl Config {
     /// Returns an error if `key` is not a recognised config key or if the
     /// value cannot be parsed (e.g. a non-boolean for `open.editor`).
     pub fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        if let Some(k) = key.strip_prefix("workspace.") {
+            return self.workspace.set_value(k, value);
+        }
+        if let Some(k) = key.strip_prefix("files.") {
+            return self.files.set_value(k, value);
+        }
+        if let Some(k) = key.strip_prefix("cache.") {
+            return self.cache.set_value(k, value);
+        }
+        if let Some(k) = key.strip_prefix("open.") {
+            return self.open.set_value(k, value);
+        }
+        if let Some(k) = key.strip_prefix("ports.") {
+            return self.ports.set_value(k, value);
+        }
         match key {
             "editor" | "editor.command" => {
                 self.editor.command = (!value.is_empty()).then(|| value.to_string());
@@ -39,30 +62,6 @@ impl Config {
                     .parse::<bool>()
                     .with_context(|| format!("Invalid boolean value: {value}"))?;
             }
-            "open.editor" => {
-                self.open.editor = value
-                    .parse::<bool>()
-                    .with_context(|| format!("Invalid boolean value: {value}"))?;
-            }
-            "workspace.ttl" => {
-                self.workspace.ttl = (!value.is_empty())
-                    .then(|| {
-                        value
-                            .parse()
-                            .map_err(|e| anyhow::anyhow!("Invalid duration {value:?}: {e}"))
-                    })
-                    .transpose()?;
-            }
-            "workspace.auto_prune" => {
-                self.workspace.auto_prune = value
-                    .parse::<bool>()
-                    .with_context(|| format!("Invalid boolean value: {value}"))?;
-            }
-            "workspace.temp" => {
-                self.workspace.temp = value
-                    .parse::<bool>()
-                    .with_context(|| format!("Invalid boolean value: {value}"))?;
-            }
             _ => anyhow::bail!("Unknown config key: {key}"),
         }
         Ok(())
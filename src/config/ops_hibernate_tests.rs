@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn test_get_value_workspace_hibernate_default() {
+    assert_eq!(
+        Config::default().get_value("workspace.hibernate").unwrap(),
+        "false"
+    );
+}
+#[test]
+fn test_hibernate_round_trips() {
+    let mut c = Config::default();
+    c.set_value("workspace.hibernate", "true").unwrap();
+    assert!(c.workspace.hibernate);
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("hibernate = true"));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert!(parsed.workspace.hibernate);
+}
+#[test]
+fn test_set_value_workspace_hibernate_invalid() {
+    assert!(Config::default()
+        .set_value("workspace.hibernate", "sometimes")
+        .is_err());
+}
@@ -0,0 +1,19 @@
+use crate::config::Config;
+
+#[test]
+fn envrc_defaults_off_and_round_trips() {
+    let mut c = Config::default();
+    assert_eq!(c.get_value("open.envrc").unwrap(), "false");
+    assert!(!c.to_toml_with_comments().contains("envrc"));
+    c.set_value("open.envrc", "true").unwrap();
+    let parsed: Config = toml::from_str(&c.to_toml_with_comments()).unwrap();
+    assert!(parsed.open.envrc);
+    assert!(parsed.open.editor);
+}
+
+#[test]
+fn unknown_open_key_is_rejected() {
+    let mut c = Config::default();
+    assert!(c.get_value("open.nope").is_err());
+    assert!(c.set_value("open.envrc", "maybe").is_err());
+}
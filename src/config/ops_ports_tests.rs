@@ -0,0 +1,23 @@
+use crate::config::Config;
+
+#[test]
+fn ports_default_and_round_trip() {
+    let mut c = Config::default();
+    assert_eq!(c.get_value("ports.base").unwrap(), "4000");
+    assert_eq!(c.get_value("ports.block").unwrap(), "10");
+    assert!(!c.to_toml_with_comments().contains("[ports]"));
+    c.set_value("ports.base", "5000").unwrap();
+    c.set_value("ports.block", "20").unwrap();
+    let parsed: Config = toml::from_str(&c.to_toml_with_comments()).unwrap();
+    assert_eq!((parsed.ports.base, parsed.ports.block), (5000, 20));
+}
+
+#[test]
+fn invalid_ports_values_are_rejected() {
+    let mut c = Config::default();
+    assert!(c.set_value("ports.base", "70000").is_err());
+    assert!(c.set_value("ports.block", "0").is_err());
+    assert!(c.set_value("ports.size", "5").is_err());
+    assert!(c.get_value("ports.size").is_err());
+    assert_eq!(c.ports.block, 10);
+}
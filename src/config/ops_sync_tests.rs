@@ -0,0 +1,28 @@
+use super::*;
+use crate::lifecycle::SyncStrategy;
+
+#[test]
+fn test_get_value_workspace_sync_strategy_default() {
+    assert_eq!(
+        Config::default()
+            .get_value("workspace.sync_strategy")
+            .unwrap(),
+        "rebase"
+    );
+}
+#[test]
+fn test_sync_strategy_round_trips() {
+    let mut c = Config::default();
+    c.set_value("workspace.sync_strategy", "merge").unwrap();
+    assert_eq!(c.workspace.sync_strategy, SyncStrategy::Merge);
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("sync_strategy = \"merge\""));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace.sync_strategy, SyncStrategy::Merge);
+}
+#[test]
+fn test_set_value_workspace_sync_strategy_invalid() {
+    assert!(Config::default()
+        .set_value("workspace.sync_strategy", "squash")
+        .is_err());
+}
@@ -0,0 +1,48 @@
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result};
+
+use crate::ports::PortsConfig;
+
+impl PortsConfig {
+    /// Get a `ports.*` value; `key` omits the `ports.` prefix.
+    pub(super) fn get_value(self, key: &str) -> Result<String> {
+        match key {
+            "base" => Ok(self.base.to_string()),
+            "block" => Ok(self.block.to_string()),
+            _ => anyhow::bail!("Unknown config key: ports.{key}"),
+        }
+    }
+
+    /// Set a `ports.*` value; `key` omits the `ports.` prefix.
+    pub(super) fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        let port = value
+            .parse::<u16>()
+            .with_context(|| format!("Invalid port number: {value}"))?;
+        match key {
+            "base" => self.base = port,
+            "block" if port == 0 => anyhow::bail!("`ports.block` must be at least 1"),
+            "block" => self.block = port,
+            _ => anyhow::bail!("Unknown config key: ports.{key}"),
+        }
+        Ok(())
+    }
+
+    /// Append the `[ports]` section, with comments, to `out` unless the
+    /// defaults are in use.
+    pub(super) fn write_toml_with_comments(self, out: &mut String) {
+        if self.is_default() {
+            return;
+        }
+        out.push_str("# Port blocks allocated to workspaces.\n");
+        out.push_str("[ports]\n");
+        out.push_str("# First port handed out.\n");
+        writeln!(out, "base = {}", self.base).unwrap();
+        out.push_str("# Number of ports in each workspace's block.\n");
+        writeln!(out, "block = {}\n", self.block).unwrap();
+    }
+}
+
+#[cfg(test)]
+#[path = "ops_ports_tests.rs"]
+mod tests;
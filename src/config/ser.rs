@@ -6,7 +6,7 @@ use super::Config;
 ///
 /// When the value contains newlines, a TOML multiline basic string (`"""..."""`) is
 /// used so that hook scripts remain human-readable in the config file.
-fn toml_quoted(s: &str) -> String {
+pub(super) fn toml_quoted(s: &str) -> String {
     if s.contains('\n') {
         let escaped = s.replace('\\', "\\\\").replace('"', "\\\"");
         return format!("\"\"\"\n{escaped}\"\"\"");
@@ -50,6 +50,10 @@ impl Config {
         out.push_str("[open]\n");
         out.push_str("# Whether to launch the configured editor when opening a workspace.\n");
         writeln!(out, "editor = {}", self.open.editor).unwrap();
+        if self.open.envrc {
+            out.push_str("# Write the workspace environment to an `.envrc` for direnv.\n");
+            out.push_str("envrc = true\n");
+        }
         out.push('\n');
 
         // [hooks] -------------------------------------------------------------
@@ -65,23 +69,17 @@ impl Config {
         }
         out.push('\n');
 
+        // [files] -------------------------------------------------------------
+        self.files.write_toml_with_comments(&mut out);
+
+        // [cache] -------------------------------------------------------------
+        self.cache.write_toml_with_comments(&mut out);
+
+        // [ports] -------------------------------------------------------------
+        self.ports.write_toml_with_comments(&mut out);
+
         // [workspace] ---------------------------------------------------------
-        out.push_str("# Workspace lifecycle configuration.\n");
-        out.push_str("[workspace]\n");
-        if let Some(ttl) = &self.workspace.ttl {
-            out.push_str("# Maximum age of a workspace before it is considered expired.\n");
-            writeln!(out, "ttl = {}", toml_quoted(&ttl.to_string())).unwrap();
-        }
-        if self.workspace.auto_prune {
-            out.push_str(
-                "# When true, expired worktrees are pruned each time `open` is invoked.\n",
-            );
-            writeln!(out, "auto_prune = {}", self.workspace.auto_prune).unwrap();
-        }
-        if self.workspace.temp {
-            out.push_str("# When true, worktrees are stored under the OS temp directory.\n");
-            writeln!(out, "temp = {}", self.workspace.temp).unwrap();
-        }
+        self.workspace.write_toml_with_comments(&mut out);
 
         out
     }
@@ -75,3 +75,19 @@ fn test_temp_round_trips() {
     let parsed: Config = toml::from_str(&s).unwrap();
     assert!(parsed.workspace.temp);
 }
+
+#[test]
+fn test_trash_retention_round_trips() {
+    let mut c = Config::default();
+    assert_eq!(c.get_value("workspace.trash_retention").unwrap(), "");
+    c.set_value("workspace.trash_retention", "2days").unwrap();
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("trash_retention = \"2days\""));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(
+        parsed.workspace.trash_retention,
+        c.workspace.trash_retention
+    );
+    c.set_value("workspace.trash_retention", "").unwrap();
+    assert!(c.workspace.trash_retention.is_none());
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::lifecycle::SyncStrategy;
+use crate::ttl::{ByteSize, PrunePolicy, Ttl, TtlMode};
+
+/// Workspace lifecycle configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct WorkspaceConfig {
+    /// Maximum age of a workspace before it is considered expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<Ttl>,
+    /// Whether the TTL counts from creation ("created") or last open ("idle").
+    #[serde(default, skip_serializing_if = "is_default_mode")]
+    pub ttl_mode: TtlMode,
+    /// When true, expired worktrees are pruned each time `open` is invoked.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_prune: bool,
+    /// When true, worktrees are stored under the OS temp directory.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub temp: bool,
+    /// Criteria used by `prune`: "ttl", "merged", or "ttl,merged".
+    #[serde(default, skip_serializing_if = "is_default_policy")]
+    pub prune_policy: PrunePolicy,
+    /// How long an unused bare clone is kept before `gc` deletes it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gc_grace: Option<Ttl>,
+    /// How long removed workspaces stay in the trash before they are deleted
+    /// for good.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trash_retention: Option<Ttl>,
+    /// How long a workspace sits idle before `clean` deletes its build
+    /// artifacts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clean_after: Option<Ttl>,
+    /// When true, `prune` and auto-prune hibernate workspaces instead of
+    /// moving them to the trash.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hibernate: bool,
+    /// How `sync` updates branches: "rebase" onto or "merge" in the base branch.
+    #[serde(default, skip_serializing_if = "is_default_strategy")]
+    pub sync_strategy: SyncStrategy,
+    /// Disk budget for all workspaces; `prune` evicts the least recently
+    /// opened clean ones until usage fits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_size: Option<ByteSize>,
+    /// Per-repository TTL overrides keyed by `owner/repo` or just `owner`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub repo_ttl: BTreeMap<String, Ttl>,
+}
+
+impl WorkspaceConfig {
+    /// The `repo_ttl` rule for the repository `slug` (see
+    /// [`crate::IssueRef::repo_slug`]). The longest matching key wins, so
+    /// `acme/core` overrides `acme`.
+    #[must_use]
+    pub fn repo_ttl_for(&self, slug: &str) -> Option<Ttl> {
+        let mut key = slug;
+        loop {
+            if let Some(ttl) = self.repo_ttl.get(key) {
+                return Some(*ttl);
+            }
+            key = key.rsplit_once('/')?.0;
+        }
+    }
+}
+
+#[allow(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "serde's skip_serializing_if passes the field by reference"
+)]
+fn is_default_policy(p: &PrunePolicy) -> bool {
+    *p == PrunePolicy::default()
+}
+
+#[allow(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "serde's skip_serializing_if passes the field by reference"
+)]
+fn is_default_mode(m: &TtlMode) -> bool {
+    *m == TtlMode::default()
+}
+
+#[allow(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "serde's skip_serializing_if passes the field by reference"
+)]
+fn is_default_strategy(s: &SyncStrategy) -> bool {
+    *s == SyncStrategy::default()
+}
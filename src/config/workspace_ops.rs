@@ -0,0 +1,87 @@
+use anyhow::{Context, Result};
+
+use super::WorkspaceConfig;
+use crate::ttl::Ttl;
+
+fn parse_bool(value: &str) -> Result<bool> {
+    value
+        .parse::<bool>()
+        .with_context(|| format!("Invalid boolean value: {value}"))
+}
+
+/// Parse an optional duration; the empty string clears the setting.
+fn parse_duration(value: &str) -> Result<Option<Ttl>> {
+    (!value.is_empty())
+        .then(|| {
+            value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid duration {value:?}: {e}"))
+        })
+        .transpose()
+}
+
+impl WorkspaceConfig {
+    /// Get a `workspace.*` value; `key` omits the `workspace.` prefix.
+    pub(super) fn get_value(&self, key: &str) -> Result<String> {
+        if let Some(slug) = key.strip_prefix("repo_ttl.") {
+            let ttl = self.repo_ttl.get(slug);
+            return Ok(ttl.map_or_else(String::new, ToString::to_string));
+        }
+        match key {
+            "ttl" => Ok(self.ttl.map_or_else(String::new, |t| t.to_string())),
+            "ttl_mode" => Ok(self.ttl_mode.to_string()),
+            "auto_prune" => Ok(self.auto_prune.to_string()),
+            "temp" => Ok(self.temp.to_string()),
+            "hibernate" => Ok(self.hibernate.to_string()),
+            "prune_policy" => Ok(self.prune_policy.to_string()),
+            "sync_strategy" => Ok(self.sync_strategy.to_string()),
+            "gc_grace" => Ok(self.gc_grace.map_or_else(String::new, |t| t.to_string())),
+            "trash_retention" => Ok(self
+                .trash_retention
+                .map_or_else(String::new, |t| t.to_string())),
+            "clean_after" => Ok(self.clean_after.map_or_else(String::new, |t| t.to_string())),
+            "max_total_size" => Ok(self
+                .max_total_size
+                .map_or_else(String::new, |s| s.to_string())),
+            _ => anyhow::bail!("Unknown config key: workspace.{key}"),
+        }
+    }
+
+    /// Set a `workspace.*` value; `key` omits the `workspace.` prefix.
+    pub(super) fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        if let Some(slug) = key.strip_prefix("repo_ttl.") {
+            match parse_duration(value)? {
+                Some(ttl) => self.repo_ttl.insert(slug.to_owned(), ttl),
+                None => self.repo_ttl.remove(slug),
+            };
+            return Ok(());
+        }
+        match key {
+            "ttl" => self.ttl = parse_duration(value)?,
+            "gc_grace" => self.gc_grace = parse_duration(value)?,
+            "trash_retention" => self.trash_retention = parse_duration(value)?,
+            "clean_after" => self.clean_after = parse_duration(value)?,
+            "auto_prune" => self.auto_prune = parse_bool(value)?,
+            "temp" => self.temp = parse_bool(value)?,
+            "hibernate" => self.hibernate = parse_bool(value)?,
+            "ttl_mode" => self.ttl_mode = value.parse().map_err(|e| anyhow::anyhow!("{e}"))?,
+            "sync_strategy" => {
+                self.sync_strategy = value.parse().map_err(|e| anyhow::anyhow!("{e}"))?;
+            }
+            "prune_policy" => {
+                self.prune_policy = value.parse().map_err(|e| anyhow::anyhow!("{e}"))?;
+            }
+            "max_total_size" => {
+                self.max_total_size = (!value.is_empty())
+                    .then(|| value.parse().map_err(|e| anyhow::anyhow!("{e}")))
+                    .transpose()?;
+            }
+            _ => anyhow::bail!("Unknown config key: workspace.{key}"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "workspace_ops_tests.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+use super::super::Config;
+
+#[test]
+fn test_prune_policy_default() {
+    let c = Config::default();
+    assert_eq!(c.get_value("workspace.prune_policy").unwrap(), "ttl");
+    assert!(!c.to_toml_with_comments().contains("prune_policy"));
+}
+
+#[test]
+fn test_set_prune_policy_round_trips() {
+    let mut c = Config::default();
+    c.set_value("workspace.prune_policy", "ttl,merged").unwrap();
+    assert!(c.workspace.prune_policy.merged);
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("prune_policy = \"ttl,merged\""));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace.prune_policy, c.workspace.prune_policy);
+}
+
+#[test]
+fn test_set_prune_policy_invalid() {
+    let err = Config::default()
+        .set_value("workspace.prune_policy", "weekly")
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown prune policy"));
+}
+
+#[test]
+fn test_unknown_workspace_key() {
+    let err = Config::default().get_value("workspace.nope").unwrap_err();
+    assert_eq!(err.to_string(), "Unknown config key: workspace.nope");
+}
+
+#[test]
+fn test_gc_grace_round_trips() {
+    let mut c = Config::default();
+    assert_eq!(c.get_value("workspace.gc_grace").unwrap(), "");
+    c.set_value("workspace.gc_grace", "3days").unwrap();
+    assert_eq!(c.get_value("workspace.gc_grace").unwrap(), "3days");
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("gc_grace = \"3days\""));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace.gc_grace, c.workspace.gc_grace);
+    c.set_value("workspace.gc_grace", "").unwrap();
+    assert!(c.workspace.gc_grace.is_none());
+    assert!(c.set_value("workspace.gc_grace", "soon").is_err());
+}
+
+#[test]
+fn test_ttl_mode_round_trips() {
+    let mut c = Config::default();
+    assert_eq!(c.get_value("workspace.ttl_mode").unwrap(), "created");
+    assert!(!c.to_toml_with_comments().contains("ttl_mode"));
+    c.set_value("workspace.ttl_mode", "idle").unwrap();
+    let s = c.to_toml_with_comments();
+    assert!(s.contains("ttl_mode = \"idle\""));
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace.ttl_mode, crate::ttl::TtlMode::Idle);
+    let err = c.set_value("workspace.ttl_mode", "opened").unwrap_err();
+    assert!(err.to_string().contains("unknown TTL mode"));
+}
+
+#[test]
+fn test_repo_ttl_rules_round_trip_and_match() {
+    let mut c = Config::default();
+    c.set_value("workspace.repo_ttl.acme", "30days").unwrap();
+    c.set_value("workspace.repo_ttl.acme/review", "1day")
+        .unwrap();
+    assert_eq!(c.get_value("workspace.repo_ttl.acme").unwrap(), "30days");
+    let s = c.to_toml_with_comments();
+    assert!(
+        s.contains("[workspace.repo_ttl]\n\"acme\" = \"30days\""),
+        "{s}"
+    );
+    let parsed: Config = toml::from_str(&s).unwrap();
+    let ws = &parsed.workspace;
+    assert_eq!(ws.repo_ttl_for("acme/review").unwrap().to_string(), "1day");
+    assert_eq!(ws.repo_ttl_for("acme/core").unwrap().to_string(), "30days");
+    assert!(ws.repo_ttl_for("other/core").is_none());
+    c.set_value("workspace.repo_ttl.acme", "").unwrap();
+    assert_eq!(c.get_value("workspace.repo_ttl.acme").unwrap(), "");
+}
+
+#[test]
+fn test_max_total_size_round_trip() {
+    let mut c = Config::default();
+    c.set_value("workspace.max_total_size", "50gb").unwrap();
+    assert_eq!(c.get_value("workspace.max_total_size").unwrap(), "50GB");
+    let s = c.to_toml_with_comments();
+    let parsed: Config = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace.max_total_size, c.workspace.max_total_size);
+    assert!(c.set_value("workspace.max_total_size", "lots").is_err());
+    c.set_value("workspace.max_total_size", "").unwrap();
+    assert!(c.workspace.max_total_size.is_none());
+}
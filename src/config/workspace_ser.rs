@@ -0,0 +1,74 @@
+use std::fmt::Write as _;
+
+use super::ser::toml_quoted;
+use super::WorkspaceConfig;
+
+impl WorkspaceConfig {
+    /// Append the `[workspace]` section, with comments, to `out`.
+    pub(super) fn write_toml_with_comments(&self, out: &mut String) {
+        out.push_str("# Workspace lifecycle configuration.\n");
+        out.push_str("[workspace]\n");
+        if let Some(ttl) = &self.ttl {
+            out.push_str("# Maximum age of a workspace before it is considered expired.\n");
+            writeln!(out, "ttl = {}", toml_quoted(&ttl.to_string())).unwrap();
+        }
+        if self.ttl_mode != crate::ttl::TtlMode::default() {
+            out.push_str(
+                "# Whether the TTL counts from creation (\"created\") or last open (\"idle\").\n",
+            );
+            writeln!(out, "ttl_mode = {}", toml_quoted(self.ttl_mode.as_str())).unwrap();
+        }
+        if self.auto_prune {
+            out.push_str(
+                "# When true, expired worktrees are pruned each time `open` is invoked.\n",
+            );
+            writeln!(out, "auto_prune = {}", self.auto_prune).unwrap();
+        }
+        if self.temp {
+            out.push_str("# When true, worktrees are stored under the OS temp directory.\n");
+            writeln!(out, "temp = {}", self.temp).unwrap();
+        }
+        if self.prune_policy != crate::ttl::PrunePolicy::default() {
+            out.push_str("# Criteria used by `prune`: \"ttl\", \"merged\", or \"ttl,merged\".\n");
+            let policy = self.prune_policy.to_string();
+            writeln!(out, "prune_policy = {}", toml_quoted(&policy)).unwrap();
+        }
+        if let Some(grace) = &self.gc_grace {
+            out.push_str("# How long an unused bare clone is kept before `gc` deletes it.\n");
+            writeln!(out, "gc_grace = {}", toml_quoted(&grace.to_string())).unwrap();
+        }
+        if let Some(keep) = &self.trash_retention {
+            out.push_str("# How long removed workspaces stay in the trash.\n");
+            writeln!(out, "trash_retention = {}", toml_quoted(&keep.to_string())).unwrap();
+        }
+        if let Some(idle) = &self.clean_after {
+            out.push_str(
+                "# How long a workspace sits idle before `clean` deletes its artifacts.\n",
+            );
+            writeln!(out, "clean_after = {}", toml_quoted(&idle.to_string())).unwrap();
+        }
+        if self.hibernate {
+            out.push_str("# When true, `prune` hibernates workspaces instead of trashing them.\n");
+            writeln!(out, "hibernate = {}", self.hibernate).unwrap();
+        }
+        if self.sync_strategy != crate::lifecycle::SyncStrategy::default() {
+            out.push_str("# How `sync` updates branches: \"rebase\" or \"merge\".\n");
+            let strategy = self.sync_strategy.as_str();
+            writeln!(out, "sync_strategy = {}", toml_quoted(strategy)).unwrap();
+        }
+        if let Some(max) = &self.max_total_size {
+            out.push_str(
+                "# Disk budget for all workspaces; `prune` evicts the least recently opened.\n",
+            );
+            writeln!(out, "max_total_size = {}", toml_quoted(&max.to_string())).unwrap();
+        }
+        if !self.repo_ttl.is_empty() {
+            out.push_str("# Per-repository TTL overrides keyed by `owner/repo` or `owner`.\n");
+            out.push_str("[workspace.repo_ttl]\n");
+            for (slug, ttl) in &self.repo_ttl {
+                let ttl = toml_quoted(&ttl.to_string());
+                writeln!(out, "{} = {ttl}", toml_quoted(slug)).unwrap();
+            }
+        }
+    }
+}
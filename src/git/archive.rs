@@ -0,0 +1,98 @@
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+use super::status::pushed_exclusions;
+use super::{git_cmd, run_git};
+
+/// Write the commits on `branch` that are not pushed anywhere to a `git
+/// bundle` at `dest`. Returns `false`, writing nothing, when there are none.
+///
+/// # Errors
+///
+/// Returns an error if git cannot list or bundle the commits.
+pub fn bundle_unpushed(wt: &Path, branch: &str, dest: &Path) -> Result<bool> {
+    if super::unpushed_count(wt)? == 0 {
+        return Ok(false);
+    }
+    let (dest, refname) = (dest.to_string_lossy(), format!("refs/heads/{branch}"));
+    let mut args = vec![
+        "bundle".to_owned(),
+        "create".to_owned(),
+        dest.into_owned(),
+        refname,
+    ];
+    args.extend(pushed_exclusions(wt)?);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_git(wt, &args)?;
+    Ok(true)
+}
+
+/// Write every uncommitted change in `wt` as a binary patch to `dest`.
+///
+/// Staged, unstaged, and untracked changes are diffed against `HEAD` through
+/// a throwaway index. Returns `false`, writing nothing, for a clean tree.
+///
+/// # Errors
+///
+/// Returns an error if git fails or the patch cannot be written.
+pub fn snapshot_patch(wt: &Path, dest: &Path) -> Result<bool> {
+    let index = dest.with_extension("index");
+    let git = |args: &[&str]| {
+        let out = git_cmd()
+            .arg("-C")
+            .arg(wt)
+            .args(args)
+            .env("GIT_INDEX_FILE", &index)
+            .output()
+            .with_context(|| format!("Failed to run `git {}`", args.join(" ")))?;
+        if !out.status.success() {
+            bail!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+        }
+        Ok(out.stdout)
+    };
+    let patch = git(&["read-tree", "HEAD"])
+        .and_then(|_| git(&["add", "-A"]))
+        .and_then(|_| git(&["diff", "--cached", "--binary", "HEAD"]));
+    let _ = std::fs::remove_file(&index);
+    let patch = patch?;
+    if patch.is_empty() {
+        return Ok(false);
+    }
+    std::fs::write(dest, patch).with_context(|| format!("Failed to write {}", dest.display()))?;
+    Ok(true)
+}
+
+/// Fetch `branch` from the bundle at `bundle` into `repo`. Fails rather than
+/// overwrite a local branch that has moved on since the bundle was made.
+///
+/// # Errors
+///
+/// Returns an error if the fetch fails or is not a fast-forward.
+pub fn fetch_bundle(repo: &Path, bundle: &Path, branch: &str) -> Result<()> {
+    let refspec = format!("refs/heads/{branch}:refs/heads/{branch}");
+    run_git(repo, &["fetch", &bundle.to_string_lossy(), &refspec])?;
+    Ok(())
+}
+
+/// Apply a patch written by [`snapshot_patch`] to the working tree of `wt`.
+///
+/// # Errors
+///
+/// Returns an error if the patch does not apply cleanly.
+pub fn apply_patch(wt: &Path, patch: &Path) -> Result<()> {
+    run_git(wt, &["apply", "--binary", &patch.to_string_lossy()])?;
+    Ok(())
+}
+
+/// Full hash of the commit checked out in `wt`.
+///
+/// # Errors
+///
+/// Returns an error if `wt` has no commits or is not a worktree.
+pub fn head_commit(wt: &Path) -> Result<String> {
+    run_git(wt, &["rev-parse", "HEAD"])
+}
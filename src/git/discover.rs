@@ -0,0 +1,80 @@
+use std::path::{Path, PathBuf};
+
+/// Deepest layout under the worktrees root:
+/// `azuredevops/<org>/<project>/<repo>`.
+const MAX_DEPTH: usize = 4;
+
+/// Find every bare clone stored under `root`.
+///
+/// A directory counts as a bare clone when it holds `HEAD`, `objects/`, and
+/// `refs/` directly. The search does not descend into bare clones (their
+/// worktrees live inside them) nor into regular checkouts.
+#[must_use]
+pub fn find_bare_clones(root: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    walk(root, 0, &mut found);
+    found.sort();
+    found
+}
+
+fn is_bare_repo(dir: &Path) -> bool {
+    dir.join("HEAD").is_file() && dir.join("objects").is_dir() && dir.join("refs").is_dir()
+}
+
+fn walk(dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+    if is_bare_repo(dir) {
+        found.push(dir.to_path_buf());
+        return;
+    }
+    if depth >= MAX_DEPTH || dir.join(".git").exists() {
+        return;
+    }
+    let Ok(rd) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in rd.flatten() {
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            walk(&entry.path(), depth + 1, found);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_bare(dir: &Path) {
+        std::fs::create_dir_all(dir.join("objects")).unwrap();
+        std::fs::create_dir_all(dir.join("refs")).unwrap();
+        std::fs::write(dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+    }
+
+    #[test]
+    fn test_find_bare_clones_nested_layouts() {
+        let root = tempfile::tempdir().unwrap();
+        let gh = root.path().join("github/acme/api");
+        let ado = root.path().join("azuredevops/org/proj/repo");
+        fake_bare(&gh);
+        fake_bare(&ado);
+        // A bare clone inside a bare clone is never reported.
+        fake_bare(&gh.join("issue-1"));
+        let found = find_bare_clones(root.path());
+        assert_eq!(found, vec![ado, gh]);
+    }
+
+    #[test]
+    fn test_find_bare_clones_skips_checkouts_and_deep_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        let local = root.path().join("local/proj/issue-1");
+        std::fs::create_dir_all(&local).unwrap();
+        std::fs::write(local.join(".git"), "gitdir: /x").unwrap();
+        fake_bare(&local.join("nested"));
+        fake_bare(&root.path().join("a/b/c/d/e"));
+        assert!(find_bare_clones(root.path()).is_empty());
+    }
+
+    #[test]
+    fn test_find_bare_clones_missing_root() {
+        assert!(find_bare_clones(Path::new("/nonexistent/worktrees/root")).is_empty());
+    }
+}
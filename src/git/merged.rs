@@ -0,0 +1,62 @@
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Whether `commit` is reachable from `target` in the repository at `dir`.
+///
+/// # Errors
+///
+/// Returns an error if either revision cannot be resolved.
+pub fn is_ancestor(dir: &Path, commit: &str, target: &str) -> Result<bool> {
+    let output = super::git_cmd()
+        .arg("-C")
+        .arg(dir)
+        .args(["merge-base", "--is-ancestor", commit, target])
+        .output()
+        .context("Failed to run `git merge-base`")?;
+    match output.status.code() {
+        Some(0) => Ok(true),
+        Some(1) => Ok(false),
+        _ => bail!(
+            "git merge-base --is-ancestor {commit} {target} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
+
+/// Branches whose `refs/remotes/origin/*` ref is still present locally but
+/// which no longer exist on `origin`, i.e. were deleted upstream.
+///
+/// Uses `git remote prune --dry-run`, so the stale refs are left in place.
+///
+/// # Errors
+///
+/// Returns an error if `origin` cannot be contacted.
+pub fn stale_remote_branches(repo: &Path) -> Result<Vec<String>> {
+    let out = super::run_git(repo, &["remote", "prune", "--dry-run", "origin"])?;
+    Ok(parse_stale(&out))
+}
+
+fn parse_stale(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|l| l.trim().strip_prefix("* [would prune] origin/"))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Committer time of `HEAD` in the worktree at `wt`.
+///
+/// # Errors
+///
+/// Returns an error if `HEAD` cannot be read.
+pub fn head_commit_time(wt: &Path) -> Result<SystemTime> {
+    let secs = super::run_git(wt, &["log", "-1", "--format=%ct", "HEAD"])?;
+    let secs: u64 = secs
+        .parse()
+        .with_context(|| format!("Unexpected commit timestamp {secs:?}"))?;
+    Ok(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+#[path = "merged_tests.rs"]
+mod tests;
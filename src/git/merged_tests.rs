@@ -0,0 +1,32 @@
+use super::*;
+use crate::test_support::git_in;
+
+#[test]
+fn test_parse_stale() {
+    let text =
+        "Pruning origin\nURL: /x\n * [would prune] origin/issue-2\n * [would prune] origin/a/b\n";
+    assert_eq!(parse_stale(text), vec!["issue-2", "a/b"]);
+    assert!(parse_stale("").is_empty());
+}
+
+#[test]
+fn test_is_ancestor_and_head_time() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+    git_in(repo, &["init", "-b", "main"]);
+    git_in(repo, &["commit", "--allow-empty", "-m", "one"]);
+    git_in(repo, &["branch", "old"]);
+    git_in(repo, &["commit", "--allow-empty", "-m", "two"]);
+    assert!(is_ancestor(repo, "old", "main").unwrap());
+    assert!(!is_ancestor(repo, "main", "old").unwrap());
+    assert!(is_ancestor(repo, "main", "missing").is_err());
+    let t = head_commit_time(repo).unwrap();
+    assert!(t <= SystemTime::now() && t > SystemTime::UNIX_EPOCH);
+}
+
+#[test]
+fn test_stale_remote_branches_without_origin_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    git_in(dir.path(), &["init"]);
+    assert!(stale_remote_branches(dir.path()).is_err());
+}
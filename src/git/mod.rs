@@ -1,17 +1,43 @@
+mod archive;
 mod branch;
 mod clone;
+mod discover;
 mod local_branch;
+mod merged;
 mod prune;
 mod remote;
+mod stash;
+mod status;
+mod summary;
+mod sync;
+mod worktree;
+mod worktree_list;
+mod worktree_lock;
+mod worktree_move;
+mod worktree_relink;
+mod worktree_remove;
+mod worktree_restore;
 
+pub use archive::{apply_patch, bundle_unpushed, fetch_bundle, head_commit, snapshot_patch};
 pub use branch::{branch_exists_remote, detect_default_branch};
 pub use clone::{bare_clone, git_fetch};
+pub use discover::find_bare_clones;
 pub use local_branch::{branch_exists_local, detect_local_default_branch};
-pub use prune::git_worktree_prune;
+pub use merged::{head_commit_time, is_ancestor, stale_remote_branches};
+pub use prune::{git_gc, git_worktree_prune};
 pub use remote::get_remote_url;
+pub use stash::{apply_stash_ref, delete_ref, stash_to_ref, untracked_files};
+pub use status::{current_branch, dirty_paths, is_ignored, unpushed_count};
+pub use summary::{ahead_behind, base_branch_name, default_base, last_commit, upstream_branch};
+pub use sync::{has_tracked_changes, merge_from, rebase_onto};
+pub use worktree::{create_local_worktree, create_worktree};
+pub use worktree_list::{list_worktrees, WorktreeEntry};
+pub use worktree_lock::{is_worktree_locked, lock_worktree, unlock_worktree};
+pub use worktree_move::{move_worktree, rename_branch};
+pub use worktree_relink::{admin_dir, create_branch, relink_worktree, repair_worktree};
+pub use worktree_remove::{common_dir, delete_branch, remove_worktree};
+pub use worktree_restore::restore_worktree;
 
-use anyhow::{bail, Context, Result};
-use std::path::Path;
 use std::process::Command;
 
 /// Build a `git` [`Command`] with worktree-related environment variables unset.
@@ -28,69 +54,24 @@ pub(super) fn git_cmd() -> Command {
     cmd
 }
 
-/// Create a worktree from a local (non-bare) repository without referencing a remote.
+/// Run `git -C <dir> <args>` and return its trimmed stdout.
 ///
-/// When `branch_exists` is false a new branch is created from HEAD.
-/// When `branch_exists` is true the existing local branch is checked out.
-///
-/// # Errors
-///
-/// Returns an error if the git command fails to spawn or exits non-zero.
-pub fn create_local_worktree(
-    repo: &Path,
-    dest: &Path,
-    branch: &str,
-    branch_exists: bool,
-) -> Result<()> {
-    let mut cmd = git_cmd();
-    cmd.args(["-C"]).arg(repo).arg("worktree").arg("add");
-
-    if branch_exists {
-        cmd.arg(dest).arg(branch);
-    } else {
-        // Create new branch from HEAD (no origin/ reference needed)
-        cmd.arg(dest).arg("-b").arg(branch);
-    }
-
-    let status = cmd.status().context("Failed to run `git worktree add`")?;
-
-    if !status.success() {
-        bail!("git worktree add failed for branch {branch}"); // LLVM_COV_EXCL_LINE
-    }
-    Ok(())
-}
-
-/// Create a worktree inside a bare clone.
-///
-/// When `branch_exists` is false a new branch is created from `origin/<base_branch>`.
-/// When `branch_exists` is true the existing branch is checked out.
-///
-/// # Errors
-///
-/// Returns an error if the git command fails to spawn or exits non-zero.
-pub fn create_worktree(
-    bare: &Path,
-    dest: &Path,
-    branch: &str,
-    base_branch: &str,
-    branch_exists: bool,
-) -> Result<()> {
-    let mut cmd = git_cmd();
-    cmd.args(["-C"]).arg(bare).arg("worktree").arg("add");
-
-    if branch_exists {
-        cmd.arg(dest).arg(branch);
-    } else {
-        cmd.arg(dest)
-            .arg("-b")
-            .arg(branch)
-            .arg(format!("origin/{base_branch}"));
-    }
-
-    let status = cmd.status().context("Failed to run `git worktree add`")?;
-
-    if !status.success() {
-        bail!("git worktree add failed for branch {branch}"); // LLVM_COV_EXCL_LINE
+/// Fails with git's own stderr message when the command exits non-zero.
+fn run_git(dir: &std::path::Path, args: &[&str]) -> anyhow::Result<String> {
+    use anyhow::Context as _;
+    let output = git_cmd()
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run `git {}`", args.join(" ")))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed in {}: {}",
+            args.join(" "),
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
     }
-    Ok(())
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
@@ -23,3 +23,12 @@ pub fn git_worktree_prune(repo: &Path) -> Result<()> {
     }
     Ok(())
 }
+
+/// Repack `repo` with `git gc --quiet`, dropping unreachable objects.
+///
+/// # Errors
+///
+/// Returns an error if the git command fails to spawn or exits non-zero.
+pub fn git_gc(repo: &Path) -> Result<()> {
+    super::run_git(repo, &["gc", "--quiet"]).map(drop)
+}
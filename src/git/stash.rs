@@ -0,0 +1,69 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::{dirty_paths, git_cmd, run_git};
+
+/// Stash every uncommitted change in `wt`, staged or not and including
+/// untracked files, and keep the stash at `refname` instead of on the stash
+/// list. Returns `false`, creating nothing, for a clean tree.
+///
+/// Falls back to a placeholder identity when git has none configured, since
+/// the stash is a commit.
+///
+/// # Errors
+///
+/// Returns an error if git cannot stash the changes or write the ref.
+pub fn stash_to_ref(wt: &Path, refname: &str) -> Result<bool> {
+    if dirty_paths(wt)?.is_empty() {
+        return Ok(false);
+    }
+    let has_ident = git_cmd()
+        .arg("-C")
+        .arg(wt)
+        .args(["var", "GIT_COMMITTER_IDENT"])
+        .output()
+        .is_ok_and(|o| o.status.success());
+    let mut args = vec!["stash", "push", "--include-untracked", "-m", refname];
+    if !has_ident {
+        let ident = [
+            "-c",
+            "user.name=worktree",
+            "-c",
+            "user.email=worktree@localhost",
+        ];
+        args.splice(0..0, ident);
+    }
+    run_git(wt, &args)?;
+    let commit = run_git(wt, &["rev-parse", "refs/stash"])?;
+    run_git(wt, &["update-ref", refname, &commit])?;
+    run_git(wt, &["stash", "drop", "--quiet"])?;
+    Ok(true)
+}
+
+/// Reapply the stash at `refname`, staged changes included, to `wt`.
+///
+/// # Errors
+///
+/// Returns an error if the stash does not apply cleanly.
+pub fn apply_stash_ref(wt: &Path, refname: &str) -> Result<()> {
+    run_git(wt, &["stash", "apply", "--index", refname]).map(drop)
+}
+
+/// Delete `refname` from `repo`.
+///
+/// # Errors
+///
+/// Returns an error if the ref cannot be deleted.
+pub fn delete_ref(repo: &Path, refname: &str) -> Result<()> {
+    run_git(repo, &["update-ref", "-d", refname]).map(drop)
+}
+
+/// Untracked, non-ignored files in `wt`, relative to it.
+///
+/// # Errors
+///
+/// Returns an error if `wt` is not a git worktree.
+pub fn untracked_files(wt: &Path) -> Result<Vec<String>> {
+    let out = run_git(wt, &["ls-files", "--others", "--exclude-standard"])?;
+    Ok(out.lines().map(str::to_owned).collect())
+}
@@ -0,0 +1,81 @@
+use anyhow::Result;
+use std::path::Path;
+
+/// Uncommitted changes (modified, staged, or untracked paths) in the
+/// worktree at `wt`, one `git status --porcelain` line each.
+///
+/// # Errors
+///
+/// Returns an error if `git status` fails, e.g. because `wt` is not a worktree.
+pub fn dirty_paths(wt: &Path) -> Result<Vec<String>> {
+    let out = super::run_git(wt, &["status", "--porcelain"])?;
+    Ok(out.lines().map(str::to_owned).collect())
+}
+
+/// Whether git ignores `path` in worktree `wt` and tracks nothing under it,
+/// so deleting it cannot lose source or unsaved work.
+#[must_use]
+pub fn is_ignored(wt: &Path, path: &Path) -> bool {
+    let git = |args: &[&str]| {
+        super::git_cmd()
+            .arg("-C")
+            .arg(wt)
+            .args(args)
+            .arg(path)
+            .output()
+    };
+    git(&["check-ignore", "-q", "--"]).is_ok_and(|o| o.status.success())
+        && git(&["ls-files", "--"]).is_ok_and(|o| o.status.success() && o.stdout.is_empty())
+}
+
+/// Number of commits on `HEAD` that exist only in this worktree.
+///
+/// When the repository has remotes these are the commits not reachable from
+/// any remote-tracking branch. A repository without remotes (a local project)
+/// counts commits not reachable from any other local branch instead.
+///
+/// # Errors
+///
+/// Returns an error if any of the underlying git commands fail.
+pub fn unpushed_count(wt: &Path) -> Result<usize> {
+    let mut args = vec![
+        "rev-list".to_owned(),
+        "--count".to_owned(),
+        "HEAD".to_owned(),
+    ];
+    args.extend(pushed_exclusions(wt)?);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let count = super::run_git(wt, &args)?;
+    Ok(count.parse().unwrap_or(0))
+}
+
+/// Rev-list arguments that exclude every commit considered pushed: those on
+/// remote-tracking branches, or without remotes those on other branches.
+pub(super) fn pushed_exclusions(wt: &Path) -> Result<Vec<String>> {
+    let mut args = vec!["--not".to_owned()];
+    if super::run_git(wt, &["remote"])?.is_empty() {
+        if let Some(branch) = current_branch(wt)? {
+            args.push(format!("--exclude={branch}"));
+        }
+        args.push("--branches".to_owned());
+    } else {
+        args.push("--remotes".to_owned());
+    }
+    Ok(args)
+}
+
+/// Branch checked out in the worktree at `wt`, or `None` when `HEAD` is
+/// detached.
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run in `wt`.
+pub fn current_branch(wt: &Path) -> Result<Option<String>> {
+    let output = super::git_cmd()
+        .arg("-C")
+        .arg(wt)
+        .args(["symbolic-ref", "--short", "-q", "HEAD"])
+        .output()?;
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((output.status.success() && !branch.is_empty()).then_some(branch))
+}
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::run_git;
+
+/// Upstream of the branch checked out in `wt`, e.g. `origin/issue-42`, or
+/// `None` when it has no upstream or `HEAD` is detached.
+#[must_use]
+pub fn upstream_branch(wt: &Path) -> Option<String> {
+    run_git(wt, &["rev-parse", "--abbrev-ref", "@{upstream}"]).ok()
+}
+
+/// The ref new branches of the repository owning `wt` start from:
+/// `origin/HEAD`, else the first of `origin/main`, `origin/master`, `main`
+/// and `master` that exists. Only local refs are consulted.
+#[must_use]
+pub fn default_base(wt: &Path) -> Option<String> {
+    [
+        "origin/HEAD",
+        "origin/main",
+        "origin/master",
+        "main",
+        "master",
+    ]
+    .into_iter()
+    .find(|r| run_git(wt, &["rev-parse", "--verify", "-q", r]).is_ok())
+    .map(str::to_owned)
+}
+
+/// The branch `base` (by default [`default_base`]) names, without its remote:
+/// `main` for `origin/main`, or for an `origin/HEAD` pointing at it.
+#[must_use]
+pub fn base_branch_name(wt: &Path, base: Option<&str>) -> Option<String> {
+    let base = base.map(str::to_owned).or_else(|| default_base(wt))?;
+    let full = run_git(wt, &["rev-parse", "--abbrev-ref", &base]).unwrap_or(base);
+    Some(full.strip_prefix("origin/").unwrap_or(&full).to_owned())
+}
+
+/// Commits on `HEAD` but not `other`, and on `other` but not `HEAD`.
+///
+/// # Errors
+///
+/// Returns an error if `other` cannot be resolved in `wt`.
+pub fn ahead_behind(wt: &Path, other: &str) -> Result<(usize, usize)> {
+    let range = format!("HEAD...{other}");
+    let out = run_git(wt, &["rev-list", "--left-right", "--count", &range])?;
+    let mut counts = out.split_whitespace().map(|n| n.parse().unwrap_or(0));
+    Ok((counts.next().unwrap_or(0), counts.next().unwrap_or(0)))
+}
+
+/// Commit time and subject of `HEAD` in `wt`, or `None` before the first
+/// commit.
+#[must_use]
+pub fn last_commit(wt: &Path) -> Option<(SystemTime, String)> {
+    let out = run_git(wt, &["log", "-1", "--format=%ct %s"]).ok()?;
+    let (secs, subject) = out.split_once(' ').unwrap_or((&out, ""));
+    let time = UNIX_EPOCH + Duration::from_secs(secs.parse().ok()?);
+    Some((time, subject.to_owned()))
+}
+
+#[cfg(test)]
+#[path = "summary_tests.rs"]
+mod tests;
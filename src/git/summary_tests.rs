@@ -0,0 +1,37 @@
+use super::*;
+use crate::test_support::git_in;
+
+#[test]
+fn test_ahead_behind_and_last_commit() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+    git_in(repo, &["init", "-b", "main"]);
+    assert!(last_commit(repo).is_none());
+    git_in(repo, &["commit", "--allow-empty", "-m", "one"]);
+    git_in(repo, &["switch", "-c", "topic"]);
+    git_in(repo, &["commit", "--allow-empty", "-m", "two"]);
+    git_in(repo, &["commit", "--allow-empty", "-m", "three \"quoted\""]);
+    assert_eq!(default_base(repo).as_deref(), Some("main"));
+    assert_eq!(base_branch_name(repo, None).as_deref(), Some("main"));
+    assert_eq!(
+        base_branch_name(repo, Some("origin/dev")).as_deref(),
+        Some("dev")
+    );
+    assert_eq!(ahead_behind(repo, "main").unwrap(), (2, 0));
+    assert!(ahead_behind(repo, "missing").is_err());
+    let (time, subject) = last_commit(repo).unwrap();
+    assert_eq!(subject, "three \"quoted\"");
+    assert!(time <= SystemTime::now());
+}
+
+#[test]
+fn test_upstream_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+    git_in(repo, &["init", "-b", "main"]);
+    git_in(repo, &["commit", "--allow-empty", "-m", "one"]);
+    assert!(upstream_branch(repo).is_none());
+    git_in(repo, &["branch", "topic"]);
+    git_in(repo, &["branch", "--set-upstream-to", "topic"]);
+    assert_eq!(upstream_branch(repo).as_deref(), Some("topic"));
+}
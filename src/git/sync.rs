@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::run_git;
+
+/// Whether the worktree at `wt` has staged or unstaged changes to tracked
+/// files. Untracked files are ignored, since they do not stop a rebase.
+///
+/// # Errors
+///
+/// Returns an error if `git status` fails, e.g. because `wt` is not a worktree.
+pub fn has_tracked_changes(wt: &Path) -> Result<bool> {
+    let out = run_git(wt, &["status", "--porcelain", "--untracked-files=no"])?;
+    Ok(!out.is_empty())
+}
+
+/// Rebase the branch checked out in `wt` onto `base`. On a conflict the
+/// rebase is aborted, leaving the branch as it was, and `false` is returned.
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or the rebase cannot be aborted.
+pub fn rebase_onto(wt: &Path, base: &str) -> Result<bool> {
+    integrate(wt, &["rebase", base], &["rebase", "--abort"])
+}
+
+/// Merge `base` into the branch checked out in `wt`. On a conflict the merge
+/// is aborted, leaving the branch as it was, and `false` is returned.
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or the merge cannot be aborted.
+pub fn merge_from(wt: &Path, base: &str) -> Result<bool> {
+    integrate(wt, &["merge", "--no-edit", base], &["merge", "--abort"])
+}
+
+fn integrate(wt: &Path, args: &[&str], abort: &[&str]) -> Result<bool> {
+    let output = super::git_cmd()
+        .arg("-C")
+        .arg(wt)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run `git {}`", args.join(" ")))?;
+    if output.status.success() {
+        return Ok(true);
+    }
+    run_git(wt, abort)?;
+    Ok(false)
+}
+
+#[cfg(test)]
+#[path = "sync_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_support::git_in;
+
+/// A repository on branch `topic`, whose own commit adds the file `topic`,
+/// one commit behind `main`, whose commit adds the file `main`.
+fn diverged(dir: &Path, topic: &str, main: &str) {
+    git_in(dir, &["init", "-b", "main"]);
+    git_in(dir, &["config", "user.name", "T"]);
+    git_in(dir, &["config", "user.email", "t@t.com"]);
+    std::fs::write(dir.join("base"), "x").unwrap();
+    git_in(dir, &["add", "."]);
+    git_in(dir, &["commit", "-m", "base"]);
+    git_in(dir, &["switch", "-c", "topic"]);
+    std::fs::write(dir.join(topic), "topic").unwrap();
+    git_in(dir, &["add", "."]);
+    git_in(dir, &["commit", "-m", "topic"]);
+    git_in(dir, &["switch", "main"]);
+    std::fs::write(dir.join(main), "main").unwrap();
+    git_in(dir, &["add", "."]);
+    git_in(dir, &["commit", "-m", "main"]);
+    git_in(dir, &["switch", "topic"]);
+}
+
+#[test]
+fn test_rebase_and_merge_bring_in_base() {
+    let dir = tempfile::tempdir().unwrap();
+    diverged(dir.path(), "a", "b");
+    assert!(rebase_onto(dir.path(), "main").unwrap());
+    assert!(dir.path().join("b").exists());
+    let dir = tempfile::tempdir().unwrap();
+    diverged(dir.path(), "a", "b");
+    assert!(merge_from(dir.path(), "main").unwrap());
+    assert!(dir.path().join("b").exists());
+}
+
+#[test]
+fn test_conflicts_are_aborted() {
+    for strategy in [rebase_onto, merge_from] {
+        let dir = tempfile::tempdir().unwrap();
+        diverged(dir.path(), "same", "same");
+        assert!(!strategy(dir.path(), "main").unwrap());
+        assert!(!has_tracked_changes(dir.path()).unwrap());
+        let file = std::fs::read_to_string(dir.path().join("same")).unwrap();
+        assert_eq!(file, "topic");
+    }
+}
+
+#[test]
+fn test_has_tracked_changes_ignores_untracked() {
+    let dir = tempfile::tempdir().unwrap();
+    diverged(dir.path(), "a", "b");
+    std::fs::write(dir.path().join("new"), "x").unwrap();
+    assert!(!has_tracked_changes(dir.path()).unwrap());
+    std::fs::write(dir.path().join("a"), "edited").unwrap();
+    assert!(has_tracked_changes(dir.path()).unwrap());
+}
@@ -0,0 +1,83 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// One entry of `git worktree list --porcelain`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorktreeEntry {
+    /// Absolute path to the worktree (or to the repository itself for the
+    /// main entry).
+    pub path: PathBuf,
+    /// Checked-out commit, absent for the bare entry.
+    pub head: Option<String>,
+    /// Checked-out branch without the `refs/heads/` prefix; `None` when
+    /// detached or bare.
+    pub branch: Option<String>,
+    /// `true` for the bare repository entry.
+    pub bare: bool,
+    /// `true` when the worktree is locked against pruning.
+    pub locked: bool,
+    /// `true` when git considers the worktree stale (its directory is gone).
+    pub prunable: bool,
+}
+
+/// List every worktree attached to `repo` via `git worktree list --porcelain`.
+///
+/// The first entry is always the main working tree, or the repository itself
+/// when `repo` is bare.
+///
+/// # Errors
+///
+/// Returns an error if the git command fails to spawn or exits non-zero.
+pub fn list_worktrees(repo: &Path) -> Result<Vec<WorktreeEntry>> {
+    let output = super::git_cmd()
+        .args(["-C"])
+        .arg(repo)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .context("Failed to run `git worktree list`")?;
+    if !output.status.success() {
+        bail!(
+            "git worktree list failed in {}: {}",
+            repo.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(parse_porcelain(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse the output of `git worktree list --porcelain`.
+fn parse_porcelain(text: &str) -> Vec<WorktreeEntry> {
+    let mut entries = Vec::new();
+    let mut current: Option<WorktreeEntry> = None;
+    for line in text.lines() {
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        if key == "worktree" {
+            entries.extend(current.take());
+            current = Some(WorktreeEntry {
+                path: PathBuf::from(value),
+                ..WorktreeEntry::default()
+            });
+            continue;
+        }
+        let Some(entry) = current.as_mut() else {
+            continue;
+        };
+        match key {
+            "HEAD" => entry.head = Some(value.to_string()),
+            "branch" => {
+                let name = value.strip_prefix("refs/heads/").unwrap_or(value);
+                entry.branch = Some(name.to_string());
+            }
+            "bare" => entry.bare = true,
+            "locked" => entry.locked = true,
+            "prunable" => entry.prunable = true,
+            _ => {}
+        }
+    }
+    entries.extend(current);
+    entries
+}
+
+#[cfg(test)]
+#[path = "worktree_list_tests.rs"]
+mod tests;
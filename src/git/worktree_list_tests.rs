@@ -0,0 +1,55 @@
+use super::*;
+
+const SAMPLE: &str = "\
+worktree /home/u/worktrees/github/acme/api
+bare
+
+worktree /home/u/worktrees/github/acme/api/issue-1
+HEAD 1111111111111111111111111111111111111111
+branch refs/heads/issue-1
+
+worktree /tmp/detached
+HEAD 2222222222222222222222222222222222222222
+detached
+locked reason text
+
+worktree /tmp/gone
+HEAD 3333333333333333333333333333333333333333
+branch refs/heads/old
+prunable gitdir file points to non-existent location
+";
+
+#[test]
+fn test_parse_porcelain_entries() {
+    let entries = parse_porcelain(SAMPLE);
+    assert_eq!(entries.len(), 4);
+    assert!(entries[0].bare);
+    assert_eq!(entries[0].head, None);
+    assert_eq!(entries[1].branch.as_deref(), Some("issue-1"));
+    assert_eq!(
+        entries[1].path,
+        PathBuf::from("/home/u/worktrees/github/acme/api/issue-1")
+    );
+    assert_eq!(entries[2].branch, None);
+    assert!(entries[2].locked);
+    assert!(entries[3].prunable);
+    assert!(!entries[1].locked && !entries[1].prunable);
+}
+
+#[test]
+fn test_parse_porcelain_empty() {
+    assert!(parse_porcelain("").is_empty());
+}
+
+#[test]
+fn test_parse_porcelain_ignores_leading_attributes() {
+    let entries = parse_porcelain("HEAD abc\nworktree /x\n");
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].head, None);
+}
+
+#[test]
+fn test_list_worktrees_not_a_repo() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(list_worktrees(dir.path()).is_err());
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::run_git;
+
+/// Return `true` if the worktree at `wt` is locked with `git worktree lock`.
+///
+/// # Errors
+///
+/// Returns an error if `wt` is not a git worktree.
+pub fn is_worktree_locked(wt: &Path) -> Result<bool> {
+    let args = [
+        "rev-parse",
+        "--path-format=absolute",
+        "--git-path",
+        "locked",
+    ];
+    Ok(Path::new(&run_git(wt, &args)?).exists())
+}
+
+/// Lock the worktree at `wt` so `git worktree prune` and `git worktree remove`
+/// leave it alone. Does nothing when it is already locked.
+///
+/// # Errors
+///
+/// Returns an error if `wt` is not a git worktree or the lock fails.
+pub fn lock_worktree(wt: &Path, reason: &str) -> Result<()> {
+    if is_worktree_locked(wt)? {
+        return Ok(());
+    }
+    let path = wt.to_string_lossy();
+    run_git(wt, &["worktree", "lock", "--reason", reason, &path]).map(drop)
+}
+
+/// Unlock the worktree at `wt`. Does nothing when it is not locked.
+///
+/// # Errors
+///
+/// Returns an error if `wt` is not a git worktree or the unlock fails.
+pub fn unlock_worktree(wt: &Path) -> Result<()> {
+    if !is_worktree_locked(wt)? {
+        return Ok(());
+    }
+    let path = wt.to_string_lossy();
+    run_git(wt, &["worktree", "unlock", &path]).map(drop)
+}
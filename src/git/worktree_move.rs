@@ -0,0 +1,26 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::run_git;
+
+/// Move the worktree at `wt` to `dest` with `git worktree move`, which also
+/// updates its administrative files inside `repo`.
+///
+/// # Errors
+///
+/// Returns an error if git refuses, e.g. because the worktree is locked or
+/// `dest` is on another file system.
+pub fn move_worktree(repo: &Path, wt: &Path, dest: &Path) -> Result<()> {
+    let (wt, dest) = (wt.to_string_lossy(), dest.to_string_lossy());
+    run_git(repo, &["worktree", "move", &wt, &dest]).map(drop)
+}
+
+/// Rename the local branch `from` to `to` in `repo`, following it in every
+/// worktree that has it checked out.
+///
+/// # Errors
+///
+/// Returns an error if `from` does not exist or `to` already does.
+pub fn rename_branch(repo: &Path, from: &str, to: &str) -> Result<()> {
+    run_git(repo, &["branch", "-m", from, to]).map(drop)
+}
@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::run_git;
+
+/// Absolute path of the administrative directory of the worktree at `wt`,
+/// e.g. `<repo>/worktrees/<name>` for a linked worktree.
+///
+/// # Errors
+///
+/// Returns an error if `wt` is not inside a git repository.
+pub fn admin_dir(wt: &Path) -> Result<PathBuf> {
+    run_git(wt, &["rev-parse", "--absolute-git-dir"]).map(PathBuf::from)
+}
+
+/// Point the worktree at `wt` back at its administrative directory `admin`
+/// inside `repo` after either was moved, with `git worktree repair`.
+///
+/// # Errors
+///
+/// Returns an error if the `.git` file cannot be written or git fails.
+pub fn relink_worktree(repo: &Path, wt: &Path, admin: &Path) -> Result<()> {
+    let gitfile = wt.join(".git");
+    std::fs::write(&gitfile, format!("gitdir: {}\n", admin.display()))
+        .with_context(|| format!("Failed to write {}", gitfile.display()))?;
+    repair_worktree(repo, wt)
+}
+
+/// Run `git worktree repair` in `repo` for the worktree at `wt`.
+///
+/// # Errors
+///
+/// Returns an error if git cannot repair the links.
+pub fn repair_worktree(repo: &Path, wt: &Path) -> Result<()> {
+    run_git(repo, &["worktree", "repair", &wt.to_string_lossy()]).map(drop)
+}
+
+/// Create the local `branch` in `repo` pointing at `commit`.
+///
+/// # Errors
+///
+/// Returns an error if the branch exists or `commit` is unknown.
+pub fn create_branch(repo: &Path, branch: &str, commit: &str) -> Result<()> {
+    run_git(repo, &["branch", branch, commit]).map(drop)
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Absolute path of the repository that owns the worktree at `wt`: the bare
+/// clone, or the `.git` directory of a regular checkout.
+///
+/// # Errors
+///
+/// Returns an error if `wt` is not inside a git repository.
+pub fn common_dir(wt: &Path) -> Result<PathBuf> {
+    let out = super::run_git(
+        wt,
+        &["rev-parse", "--path-format=absolute", "--git-common-dir"],
+    )?;
+    Ok(PathBuf::from(out))
+}
+
+/// Remove the worktree at `wt` with `git worktree remove`, which deletes the
+/// directory and its administrative files inside `repo`.
+///
+/// Without `force`, git refuses to remove a worktree with modified or
+/// untracked files.
+///
+/// # Errors
+///
+/// Returns an error if the git command fails or exits non-zero.
+pub fn remove_worktree(repo: &Path, wt: &Path, force: bool) -> Result<()> {
+    let wt = wt.to_string_lossy();
+    let mut args = vec!["worktree", "remove"];
+    if force {
+        args.push("--force");
+    }
+    args.push(&wt);
+    super::run_git(repo, &args).map(drop)
+}
+
+/// Delete the local `branch` from `repo`, even if it is not merged.
+///
+/// # Errors
+///
+/// Returns an error if the git command fails or exits non-zero.
+pub fn delete_branch(repo: &Path, branch: &str) -> Result<()> {
+    super::run_git(repo, &["branch", "-D", branch]).map(drop)
+}
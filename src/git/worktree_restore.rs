@@ -0,0 +1,31 @@
+use anyhow::{bail, Result};
+use std::path::Path;
+
+use super::{branch_exists_local, branch_exists_remote, run_git};
+
+/// Re-add the worktree for `branch` at `dest` inside `repo`, which may be a
+/// bare clone or a regular checkout.
+///
+/// An existing local or remote-tracking branch is checked out as is. When the
+/// branch is gone it is recreated from `base_ref`.
+///
+/// # Errors
+///
+/// Returns an error if the branch no longer exists and no `base_ref` is
+/// given, or if `git worktree add` fails.
+pub fn restore_worktree(
+    repo: &Path,
+    dest: &Path,
+    branch: &str,
+    base_ref: Option<&str>,
+) -> Result<()> {
+    let dest = dest.to_string_lossy();
+    if branch_exists_local(repo, branch) || branch_exists_remote(repo, branch) {
+        run_git(repo, &["worktree", "add", &dest, branch])?;
+    } else if let Some(base) = base_ref {
+        run_git(repo, &["worktree", "add", "-b", branch, &dest, base])?;
+    } else {
+        bail!("branch {branch} no longer exists and no base ref is recorded");
+    }
+    Ok(())
+}
@@ -36,7 +36,8 @@ pub fn run_hook(script: &str, ctx: &HookContext) -> Result<()> {
             .env("WORKTREE_REPO", &ctx.repo)
             .env("WORKTREE_ISSUE", &ctx.issue)
             .env("WORKTREE_BRANCH", &ctx.branch)
-            .env("WORKTREE_PATH", &ctx.worktree_path);
+            .env("WORKTREE_PATH", &ctx.worktree_path)
+            .env("WORKTREE_PORT", &ctx.port);
         for (k, v) in &ctx.extra_env {
             cmd.env(k, v);
         }
@@ -51,7 +52,8 @@ pub fn run_hook(script: &str, ctx: &HookContext) -> Result<()> {
             .env("WORKTREE_REPO", &ctx.repo)
             .env("WORKTREE_ISSUE", &ctx.issue)
             .env("WORKTREE_BRANCH", &ctx.branch)
-            .env("WORKTREE_PATH", &ctx.worktree_path);
+            .env("WORKTREE_PATH", &ctx.worktree_path)
+            .env("WORKTREE_PORT", &ctx.port);
         for (k, v) in &ctx.extra_env {
             cmd.env(k, v);
         }
@@ -1,4 +1,5 @@
 /// Template variables available to hook scripts.
+#[derive(Debug, Clone, Default)]
 pub struct HookContext {
     /// GitHub owner / organization name.
     pub owner: String,
@@ -10,13 +11,15 @@ pub struct HookContext {
     pub branch: String,
     /// Absolute path to the worktree directory.
     pub worktree_path: String,
+    /// First port of the workspace's port block, empty when it has none.
+    pub port: String,
     /// Extra environment variables to inject into the hook process.
     pub extra_env: Vec<(String, String)>,
 }
 
 impl HookContext {
-    /// Expand `{{owner}}`, `{{repo}}`, `{{issue}}`, `{{branch}}`, and
-    /// `{{worktree_path}}` placeholders in `template`.
+    /// Expand `{{owner}}`, `{{repo}}`, `{{issue}}`, `{{branch}}`,
+    /// `{{worktree_path}}`, and `{{port}}` placeholders in `template`.
     #[must_use]
     pub fn render(&self, template: &str) -> String {
         template
@@ -25,5 +28,6 @@ impl HookContext {
             .replace("{{issue}}", &self.issue)
             .replace("{{branch}}", &self.branch)
             .replace("{{worktree_path}}", &self.worktree_path)
+            .replace("{{port}}", &self.port)
     }
 }
@@ -7,6 +7,7 @@ fn ctx() -> HookContext {
         issue: "42".into(),
         branch: "issue-42".into(),
         worktree_path: "/tmp/wt".into(),
+        port: "4010".into(),
         extra_env: vec![],
     }
 }
@@ -42,15 +43,16 @@ fn test_run_hook_context_env_vars_injected() {
     let _ = std::fs::remove_file(&f);
     run_hook(
         &format!(
-            "printf '%s:%s:%s:%s:%s' \"$WORKTREE_OWNER\" \"$WORKTREE_REPO\" \
-             \"$WORKTREE_ISSUE\" \"$WORKTREE_BRANCH\" \"$WORKTREE_PATH\" > '{p}'"
+            "printf '%s:%s:%s:%s:%s:%s:%s' \"$WORKTREE_OWNER\" \"$WORKTREE_REPO\" \
+             \"$WORKTREE_ISSUE\" \"$WORKTREE_BRANCH\" \"$WORKTREE_PATH\" \
+             \"$WORKTREE_PORT\" '{{{{port}}}}' > '{p}'"
         ),
         &ctx(),
     )
     .unwrap();
     let got = std::fs::read_to_string(&f).unwrap_or_default();
     std::fs::remove_file(&f).ok();
-    assert_eq!(got, "acme:api:42:issue-42:/tmp/wt");
+    assert_eq!(got, "acme:api:42:issue-42:/tmp/wt:4010:4010");
 }
 
 #[cfg(not(windows))]
@@ -34,3 +34,11 @@ fn parse_worktree_url_env_malformed_ignored() {
         IssueRef::parse_with_options("worktree://open?owner=acme&repo=api&env=NOCOLON").unwrap();
     assert!(opts.extra_env.is_empty());
 }
+
+#[test]
+fn parse_worktree_url_ttl_param() {
+    let (_r, opts) =
+        IssueRef::parse_with_options("worktree://open?owner=acme&repo=api&ttl=2d").unwrap();
+    assert_eq!(opts.ttl.unwrap().to_string(), "2days");
+    assert!(IssueRef::parse_with_options("worktree://open?owner=acme&repo=api&ttl=x").is_err());
+}
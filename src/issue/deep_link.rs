@@ -8,4 +8,6 @@ pub struct DeepLinkOptions {
     pub no_hooks: bool,
     /// Extra environment variables from `env=KEY:VALUE` query params.
     pub extra_env: Vec<(String, String)>,
+    /// Workspace TTL from the `ttl` query param, e.g. `ttl=2d`.
+    pub ttl: Option<crate::ttl::Ttl>,
 }
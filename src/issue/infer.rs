@@ -0,0 +1,78 @@
+use std::path::{Component, Path};
+
+use super::IssueRef;
+
+impl IssueRef {
+    /// Best-guess the issue a worktree was created for from its branch name.
+    ///
+    /// `repo` is the repository the worktree belongs to and `root` the managed
+    /// worktrees root (see [`super::worktrees_root`]). Bare clones under `root`
+    /// yield the provider, owner, and repo from their path; any other
+    /// repository is treated as a local project.
+    ///
+    /// Recognised branch names mirror [`Self::branch_name`]: `issue-N`,
+    /// `linear-<id>`, `jira-<key>`, and `workitem-N`. The Jira host is not in
+    /// the branch name, so `jira-<key>` needs `jira_host`; without it the
+    /// branch, like any other branch of a GitHub clone, is recorded as an
+    /// ad-hoc workspace. Returns `None` when no sensible guess can be made.
+    #[must_use]
+    pub fn infer(root: &Path, repo: &Path, branch: &str, jira_host: Option<&str>) -> Option<Self> {
+        let Ok(rel) = repo.strip_prefix(root) else {
+            let display_number = branch.strip_prefix("issue-")?.parse().ok()?;
+            return Some(Self::Local {
+                project_path: repo.to_path_buf(),
+                display_number,
+            });
+        };
+        let parts: Vec<String> = rel
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(s) => Some(s.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect();
+        match parts.as_slice() {
+            [provider, owner, repo] if provider == "github" => {
+                Some(infer_github(owner.clone(), repo.clone(), branch, jira_host))
+            }
+            [provider, owner, repo] if provider == "gitlab" => Some(Self::GitLab {
+                owner: owner.clone(),
+                repo: repo.clone(),
+                number: branch.strip_prefix("issue-")?.parse().ok()?,
+            }),
+            [provider, org, project, repo] if provider == "azuredevops" => {
+                Some(Self::AzureDevOps {
+                    org: org.clone(),
+                    project: project.clone(),
+                    repo: repo.clone(),
+                    id: branch.strip_prefix("workitem-")?.parse().ok()?,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn infer_github(owner: String, repo: String, branch: &str, jira_host: Option<&str>) -> IssueRef {
+    if let Some(number) = branch.strip_prefix("issue-").and_then(|n| n.parse().ok()) {
+        return IssueRef::GitHub {
+            owner,
+            repo,
+            number,
+        };
+    }
+    if let Some(id) = branch.strip_prefix("linear-") {
+        let id = id.to_string();
+        return IssueRef::Linear { owner, repo, id };
+    }
+    if let (Some(key), Some(host)) = (branch.strip_prefix("jira-"), jira_host) {
+        return IssueRef::Jira {
+            host: host.to_string(),
+            issue_key: key.to_uppercase(),
+            owner,
+            repo,
+        };
+    }
+    let name = branch.to_string();
+    IssueRef::Adhoc { owner, repo, name }
+}
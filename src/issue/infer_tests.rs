@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+use super::IssueRef;
+
+fn root() -> PathBuf {
+    PathBuf::from("/home/u/worktrees")
+}
+
+fn github(branch: &str) -> Option<IssueRef> {
+    IssueRef::infer(&root(), &root().join("github/acme/api"), branch, None)
+}
+
+#[test]
+fn test_infer_github_issue() {
+    let issue = github("issue-42").unwrap();
+    assert_eq!(issue, IssueRef::parse("acme/api#42").unwrap());
+}
+
+#[test]
+fn test_infer_github_linear() {
+    let Some(IssueRef::Linear { id, .. }) = github("linear-abc") else {
+        unreachable!("expected a Linear issue")
+    };
+    assert_eq!(id, "abc");
+}
+
+#[test]
+fn test_infer_github_jira_needs_host() {
+    assert!(matches!(github("jira-a-7"), Some(IssueRef::Adhoc { .. })));
+    let bare = root().join("github/acme/api");
+    let expected = IssueRef::Jira {
+        host: "acme.atlassian.net".into(),
+        issue_key: "A-7".into(),
+        owner: "acme".into(),
+        repo: "api".into(),
+    };
+    let jira = IssueRef::infer(&root(), &bare, "jira-a-7", Some("acme.atlassian.net"));
+    assert_eq!(jira, Some(expected));
+}
+
+#[test]
+fn test_infer_github_other_branch_is_adhoc() {
+    let Some(IssueRef::Adhoc { name, .. }) = github("bold_turing") else {
+        unreachable!("expected an ad-hoc workspace")
+    };
+    assert_eq!(name, "bold_turing");
+}
+
+#[test]
+fn test_infer_github_non_numeric_issue_is_adhoc() {
+    assert!(matches!(github("issue-x"), Some(IssueRef::Adhoc { .. })));
+}
+
+#[test]
+fn test_infer_gitlab() {
+    let bare = root().join("gitlab/grp/svc");
+    assert_eq!(
+        IssueRef::infer(&root(), &bare, "issue-3", None),
+        Some(IssueRef::GitLab {
+            owner: "grp".into(),
+            repo: "svc".into(),
+            number: 3,
+        })
+    );
+    assert_eq!(IssueRef::infer(&root(), &bare, "feature", None), None);
+}
+
+#[test]
+fn test_infer_azure() {
+    let bare = root().join("azuredevops/org/proj/repo");
+    assert_eq!(
+        IssueRef::infer(&root(), &bare, "workitem-9", None),
+        Some(IssueRef::AzureDevOps {
+            org: "org".into(),
+            project: "proj".into(),
+            repo: "repo".into(),
+            id: 9,
+        })
+    );
+    assert_eq!(IssueRef::infer(&root(), &bare, "issue-9", None), None);
+}
+
+#[test]
+fn test_infer_unknown_layout() {
+    let bare = root().join("bitbucket/a/b");
+    assert_eq!(IssueRef::infer(&root(), &bare, "issue-1", None), None);
+}
+
+#[test]
+fn test_infer_local_repo() {
+    let repo = Path::new("/src/myproj");
+    assert_eq!(
+        IssueRef::infer(&root(), repo, "issue-5", None),
+        Some(IssueRef::Local {
+            project_path: repo.to_path_buf(),
+            display_number: 5,
+        })
+    );
+    assert_eq!(IssueRef::infer(&root(), repo, "main", None), None);
+}
@@ -1,10 +1,14 @@
 mod deep_link;
 mod def;
 mod impls;
+mod infer;
 mod parse;
 mod paths;
+mod provider;
 pub use deep_link::DeepLinkOptions;
 pub use def::IssueRef;
+pub use paths::worktrees_root;
+pub use provider::provider_host;
 
 #[cfg(test)]
 mod adhoc_env_tests;
@@ -17,6 +21,8 @@ mod azure_tests;
 #[cfg(test)]
 mod gitlab_tests;
 #[cfg(test)]
+mod infer_tests;
+#[cfg(test)]
 mod jira_tests;
 #[cfg(test)]
 mod linear_tests;
@@ -11,6 +11,7 @@ pub(super) fn parse_worktree_url(s: &str) -> Result<(IssueRef, DeepLinkOptions)>
         editor: p.editor,
         no_hooks: p.no_hooks,
         extra_env: p.extra_env,
+        ttl: p.ttl,
     };
     if let Some(url_str) = p.url_param {
         return Ok((super::github::parse_github_url(&url_str)?, opts));
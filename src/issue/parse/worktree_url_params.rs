@@ -1,6 +1,8 @@
 use anyhow::{bail, Context, Result};
 use url::Url;
 
+use crate::ttl::Ttl;
+
 pub struct QueryParams {
     pub owner: Option<String>,
     pub repo: Option<String>,
@@ -18,6 +20,7 @@ pub struct QueryParams {
     pub gitlab_host: Option<String>,
     pub extra_env: Vec<(String, String)>,
     pub adhoc_name: Option<String>,
+    pub ttl: Option<Ttl>,
 }
 
 pub fn parse_query_params(url: &Url) -> Result<QueryParams> {
@@ -38,6 +41,7 @@ pub fn parse_query_params(url: &Url) -> Result<QueryParams> {
         gitlab_host: None,
         extra_env: Vec::new(),
         adhoc_name: None,
+        ttl: None,
     };
     for (key, val) in url.query_pairs() {
         match key.as_ref() {
@@ -77,6 +81,12 @@ pub fn parse_query_params(url: &Url) -> Result<QueryParams> {
                 }
             }
             "adhoc" => p.adhoc_name = Some(val.into_owned()),
+            "ttl" => {
+                let ttl = val
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid ttl {val:?}: {e}"))?;
+                p.ttl = Some(ttl);
+            }
             _ => {}
         }
     }
@@ -26,17 +26,7 @@ impl IssueRef {
     }
 
     pub(crate) fn bare_clone_path_rooted(&self, temp: bool) -> PathBuf {
-        let base = if temp {
-            std::env::temp_dir()
-        } else {
-            // Documented in `bare_clone_path`'s `# Panics` section: this is a
-            // narrow, path-building helper with no `Result` to propagate
-            // into, and a missing home directory is an unrecoverable
-            // environment problem for every caller.
-            #[allow(clippy::expect_used)]
-            dirs::home_dir().expect("could not determine home directory")
-        }
-        .join("worktrees");
+        let base = worktrees_root_for(temp);
         match self {
             Self::GitHub { owner, repo, .. }
             | Self::Linear { owner, repo, .. }
@@ -57,6 +47,31 @@ impl IssueRef {
     }
 }
 
+/// Root directory holding every managed bare clone: `~/worktrees`, or
+/// `<tmp>/worktrees` when `workspace.temp` is enabled.
+///
+/// # Panics
+///
+/// Panics if the home directory cannot be determined.
+#[must_use]
+pub fn worktrees_root() -> PathBuf {
+    worktrees_root_for(Config::load().is_ok_and(|c| c.workspace.temp))
+}
+
+fn worktrees_root_for(temp: bool) -> PathBuf {
+    if temp {
+        std::env::temp_dir()
+    } else {
+        // Documented in `bare_clone_path`'s `# Panics` section: this is a
+        // narrow, path-building helper with no `Result` to propagate
+        // into, and a missing home directory is an unrecoverable
+        // environment problem for every caller.
+        #[allow(clippy::expect_used)]
+        dirs::home_dir().expect("could not determine home directory")
+    }
+    .join("worktrees")
+}
+
 #[cfg(test)]
 #[path = "paths_tests.rs"]
 mod paths_tests;
@@ -0,0 +1,63 @@
+use super::IssueRef;
+
+/// Host serving repositories for a provider directory under the worktrees
+/// root, e.g. `github` → `github.com`.
+#[must_use]
+pub fn provider_host(provider: &str) -> Option<&'static str> {
+    match provider {
+        "github" => Some("github.com"),
+        "gitlab" => Some("gitlab.com"),
+        "azuredevops" => Some("dev.azure.com"),
+        _ => None,
+    }
+}
+
+impl IssueRef {
+    /// Provider hosting the repository, matching the first path component of
+    /// [`Self::bare_clone_path`]: `github`, `gitlab`, `azuredevops`, or
+    /// `local`. Linear, Jira, and ad-hoc workspaces live in GitHub repos.
+    #[must_use]
+    pub const fn provider(&self) -> &'static str {
+        match self {
+            Self::GitHub { .. } | Self::Linear { .. } | Self::Jira { .. } | Self::Adhoc { .. } => {
+                "github"
+            }
+            Self::GitLab { .. } => "gitlab",
+            Self::AzureDevOps { .. } => "azuredevops",
+            Self::Local { .. } => "local",
+        }
+    }
+
+    /// The repository as `owner/repo` (`org/project/repo` for Azure DevOps,
+    /// the project directory name for local projects), used to match
+    /// per-repository settings.
+    #[must_use]
+    pub fn repo_slug(&self) -> String {
+        match self {
+            Self::GitHub { owner, repo, .. }
+            | Self::Linear { owner, repo, .. }
+            | Self::Jira { owner, repo, .. }
+            | Self::GitLab { owner, repo, .. }
+            | Self::Adhoc { owner, repo, .. } => format!("{owner}/{repo}"),
+            Self::AzureDevOps {
+                org, project, repo, ..
+            } => format!("{org}/{project}/{repo}"),
+            Self::Local { project_path, .. } => project_path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+
+    /// Host the repository is cloned from, e.g. `github.com`; `None` for
+    /// local projects.
+    #[must_use]
+    pub fn host(&self) -> Option<&'static str> {
+        provider_host(self.provider())
+    }
+}
+
+#[cfg(test)]
+#[path = "provider_tests.rs"]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn test_provider_and_host() {
+    let gh = IssueRef::parse("acme/api#1").unwrap();
+    assert_eq!((gh.provider(), gh.host()), ("github", Some("github.com")));
+    let gl = IssueRef::GitLab {
+        owner: "g".into(),
+        repo: "r".into(),
+        number: 1,
+    };
+    assert_eq!((gl.provider(), gl.host()), ("gitlab", Some("gitlab.com")));
+    let local = IssueRef::Local {
+        project_path: "/p".into(),
+        display_number: 1,
+    };
+    assert_eq!((local.provider(), local.host()), ("local", None));
+}
+
+#[test]
+fn test_provider_host_azure_and_unknown() {
+    assert_eq!(provider_host("azuredevops"), Some("dev.azure.com"));
+    assert_eq!(provider_host("local"), None);
+}
+
+#[test]
+fn test_repo_slug() {
+    assert_eq!(
+        IssueRef::parse("acme/api#1").unwrap().repo_slug(),
+        "acme/api"
+    );
+    let ado = IssueRef::AzureDevOps {
+        org: "o".into(),
+        project: "p".into(),
+        repo: "r".into(),
+        id: 1,
+    };
+    assert_eq!(ado.repo_slug(), "o/p/r");
+    let local = IssueRef::Local {
+        project_path: "/src/proj".into(),
+        display_number: 1,
+    };
+    assert_eq!(local.repo_slug(), "proj");
+}
@@ -3,6 +3,8 @@
 // fast on a broken fixture; only production code paths need to avoid it.
 #![cfg_attr(test, allow(clippy::expect_used))]
 
+/// Dependency and build caches shared across worktrees.
+pub mod cache;
 /// Configuration loading and serialization.
 pub mod config;
 /// Git operations: cloning, fetching, branch detection, and worktree creation.
@@ -12,12 +14,18 @@ pub mod hooks;
 pub(crate) mod hooks_ctx;
 /// Issue reference types and parsing.
 pub mod issue;
+/// Safe workspace teardown: unsaved-work checks and git-aware removal.
+pub mod lifecycle;
+/// Local-only files copied or symlinked into new worktrees.
+pub mod local_files;
 /// Multi-repo unified workspace creation.
 pub mod multi_workspace;
 /// Random human-friendly workspace name generator.
 pub mod name_gen;
 /// Editor and terminal openers.
 pub mod opener;
+/// Port blocks allocated to workspaces.
+pub mod ports;
 /// Per-repository hook configuration loaded from `.worktree.toml`.
 pub mod repo_hooks;
 /// Parser for the `.worktree.toml` flat-key hook syntax.
@@ -28,10 +36,15 @@ pub mod repo_hooks_scaffold;
 pub mod scheme;
 /// Compile-time TOML template assets embedded via `include_str!`.
 pub mod templates;
+/// Git fixtures shared by the unit tests.
+#[cfg(test)]
+mod test_support;
 /// Workspace TTL management and registry.
 pub mod ttl;
 /// Workspace creation and lifecycle.
 pub mod workspace;
+/// Per-workspace environment variables persisted across opens.
+pub mod workspace_env;
 
 pub use config::Config;
 pub use issue::{DeepLinkOptions, IssueRef};
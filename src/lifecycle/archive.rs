@@ -0,0 +1,86 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::ttl::WorkspaceRecord;
+
+/// Unsaved work rescued from a workspace before it was removed, as described
+/// by the `archive.toml` in its archive directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Archive {
+    /// Name of the archive directory, passed to `worktree unarchive`.
+    pub id: String,
+    /// When the workspace was archived.
+    #[serde(with = "humantime_serde")]
+    pub archived_at: SystemTime,
+    /// The workspace's registry entry at the time.
+    pub workspace: WorkspaceRecord,
+    /// One entry per member worktree that had unsaved work.
+    #[serde(default, rename = "member")]
+    pub members: Vec<ArchivedMember>,
+}
+
+/// The saved state of one member worktree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedMember {
+    /// Where the worktree was checked out.
+    pub path: PathBuf,
+    /// The bare clone or repository that owned the worktree.
+    pub repo: PathBuf,
+    /// The branch that was checked out.
+    pub branch: String,
+    /// The commit that was checked out.
+    pub head: String,
+    /// `git bundle` of the unpushed commits, relative to the archive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<String>,
+    /// Binary patch of the uncommitted changes, relative to the archive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+}
+
+impl Archive {
+    /// Directory holding all archives (`~/.local/share/worktree/archive`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the home directory cannot be determined.
+    pub fn root() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Could not determine home directory")?;
+        Ok(home.join(".local/share/worktree/archive"))
+    }
+
+    /// Read the archive with the given `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive does not exist or cannot be parsed.
+    pub fn load(id: &str) -> Result<Self> {
+        let path = Self::root()?.join(id).join("archive.toml");
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("No archive {id:?} at {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Every readable archive, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the home directory cannot be determined.
+    pub fn list() -> Result<Vec<Self>> {
+        let Ok(rd) = std::fs::read_dir(Self::root()?) else {
+            return Ok(Vec::new());
+        };
+        let names = rd
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().into_owned());
+        let mut archives: Vec<Self> = names.filter_map(|id| Self::load(&id).ok()).collect();
+        archives.sort_by_key(|a| a.archived_at);
+        Ok(archives)
+    }
+}
+
+#[cfg(test)]
+#[path = "archive_tests.rs"]
+mod tests;
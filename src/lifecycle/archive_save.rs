@@ -0,0 +1,81 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::git::{bundle_unpushed, common_dir, current_branch, head_commit, snapshot_patch};
+use crate::ttl::WorkspaceRecord;
+
+use super::archive::{Archive, ArchivedMember};
+use super::entry_dir::new_entry_dir;
+use super::{unsaved_work, UnsavedWork};
+
+/// Archive the unsaved work of `record`'s workspace before a forced removal.
+///
+/// Returns the archive's id, or `None` when there is nothing to save.
+///
+/// # Errors
+///
+/// Returns an error if git cannot inspect the workspace or the unsaved work
+/// cannot be archived, so that the removal does not go ahead unarchived.
+pub fn archive_unsaved(record: &WorkspaceRecord) -> Result<Option<String>> {
+    let unsaved = unsaved_work(&record.path).with_context(|| {
+        format!(
+            "Cannot check {} for unsaved work to archive",
+            record.path.display()
+        )
+    })?;
+    if unsaved.is_empty() {
+        return Ok(None);
+    }
+    archive_workspace(record, &unsaved, &Archive::root()?).map(Some)
+}
+
+/// Save the `unsaved` work of `record`'s workspace into a new dir under `root`.
+///
+/// Each member worktree gets a bundle of unpushed commits and a patch of
+/// uncommitted changes. Returns the archive's id.
+///
+/// # Errors
+///
+/// Returns an error if git cannot bundle or diff a worktree or the archive
+/// cannot be written.
+pub fn archive_workspace(
+    record: &WorkspaceRecord,
+    unsaved: &[UnsavedWork],
+    root: &Path,
+) -> Result<String> {
+    let now = SystemTime::now();
+    let (id, dir) = new_entry_dir(root, &record.path, now)?;
+    let members = unsaved
+        .iter()
+        .map(|u| archive_member(&u.path, &dir))
+        .collect::<Result<Vec<_>>>()?;
+    let workspace = record.clone();
+    let archive = Archive {
+        id: id.clone(),
+        archived_at: now,
+        workspace,
+        members,
+    };
+    let content = toml::to_string(&archive).context("Failed to serialize archive metadata")?;
+    std::fs::write(dir.join("archive.toml"), content)
+        .with_context(|| format!("Failed to write archive metadata in {}", dir.display()))?;
+    Ok(id)
+}
+
+fn archive_member(wt: &Path, dir: &Path) -> Result<ArchivedMember> {
+    let name = wt.file_name().unwrap_or_default().to_string_lossy();
+    let branch =
+        current_branch(wt)?.with_context(|| format!("{} has a detached HEAD", wt.display()))?;
+    let (bundle, patch) = (format!("{name}.bundle"), format!("{name}.patch"));
+    let bundled = bundle_unpushed(wt, &branch, &dir.join(&bundle))?;
+    let patched = snapshot_patch(wt, &dir.join(&patch))?;
+    Ok(ArchivedMember {
+        path: wt.to_path_buf(),
+        repo: common_dir(wt)?,
+        head: head_commit(wt)?,
+        branch,
+        bundle: bundled.then_some(bundle),
+        patch: patched.then_some(patch),
+    })
+}
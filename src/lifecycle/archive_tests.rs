@@ -0,0 +1,45 @@
+use super::*;
+use crate::lifecycle::{archive_unsaved, archive_workspace, unsaved_work};
+use crate::test_support::{commit_file, git_out, repo_with_worktree};
+
+#[test]
+fn test_archive_workspace_saves_bundle_patch_and_metadata() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    commit_file(&wt, "g");
+    std::fs::write(wt.join("f"), "edited").unwrap();
+    std::fs::write(wt.join("new"), "untracked").unwrap();
+
+    let record = WorkspaceRecord::new(wt.clone(), SystemTime::now());
+    let unsaved = unsaved_work(&wt).unwrap();
+    let root = dir.path().join("archive");
+    let id = archive_workspace(&record, &unsaved, &root).unwrap();
+    assert!(id.starts_with("wt-"), "{id}");
+
+    let meta = std::fs::read_to_string(root.join(&id).join("archive.toml")).unwrap();
+    let archive: Archive = toml::from_str(&meta).unwrap();
+    let member = &archive.members[0];
+    assert_eq!(member.branch, "issue-1");
+    assert_eq!(member.head, git_out(&wt, &["rev-parse", "HEAD"]));
+    let patch =
+        std::fs::read_to_string(root.join(&id).join(member.patch.as_ref().unwrap())).unwrap();
+    assert!(
+        patch.contains("+edited") && patch.contains("+untracked"),
+        "{patch}"
+    );
+    assert!(root
+        .join(&id)
+        .join(member.bundle.as_ref().unwrap())
+        .exists());
+    assert_eq!(git_out(&wt, &["status", "--porcelain"]).lines().count(), 2);
+}
+
+#[test]
+fn test_archive_unsaved_fails_when_git_cannot_inspect() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dir.path().join("wt");
+    std::fs::create_dir(&wt).unwrap();
+    std::fs::write(wt.join(".git"), "gitdir: /nonexistent/worktrees/wt\n").unwrap();
+    let record = WorkspaceRecord::new(wt, SystemTime::now());
+    assert!(archive_unsaved(&record).is_err());
+}
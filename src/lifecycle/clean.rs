@@ -0,0 +1,76 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::git::is_ignored;
+use crate::repo_hooks::RepoConfig;
+
+use super::members;
+
+/// Build artifacts each ecosystem leaves behind, keyed by the file that marks
+/// a project of it.
+const ECOSYSTEMS: &[(&str, &[&str])] = &[
+    ("Cargo.toml", &["target"]),
+    ("package.json", &["node_modules", ".next", ".turbo"]),
+    (
+        "pyproject.toml",
+        &[".venv", "**/__pycache__", ".pytest_cache"],
+    ),
+    ("requirements.txt", &[".venv", "**/__pycache__"]),
+    ("pom.xml", &["target"]),
+    ("build.gradle", &["build", ".gradle"]),
+    ("build.gradle.kts", &["build", ".gradle"]),
+    ("Gemfile", &["vendor/bundle"]),
+    ("mix.exs", &["_build", "deps"]),
+    ("composer.json", &["vendor"]),
+];
+
+/// The artifact globs for worktree `wt`: the defaults of every ecosystem
+/// detected at its root plus the `clean` list of its `.worktree.toml`.
+#[must_use]
+pub fn artifact_patterns(wt: &Path) -> Vec<String> {
+    let mut patterns: Vec<String> = ECOSYSTEMS
+        .iter()
+        .filter(|(marker, _)| wt.join(marker).is_file())
+        .flat_map(|(_, globs)| globs.iter().map(|g| (*g).to_owned()))
+        .collect();
+    patterns.extend(RepoConfig::load_from(wt).unwrap_or_default().clean);
+    patterns.sort();
+    patterns.dedup();
+    patterns
+}
+
+/// The build artifacts `worktree clean` may delete from the workspace at
+/// `path`.
+///
+/// These are the paths matching [`artifact_patterns`] in each member
+/// worktree that git ignores and tracks nothing under. Paths inside another
+/// match are left out, since deleting the outer one covers them.
+#[must_use]
+pub fn find_artifacts(path: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for wt in members(path) {
+        let root = glob::Pattern::escape(&wt.to_string_lossy());
+        let inside = |p: &String| {
+            let mut parts = Path::new(p).components();
+            parts.all(|c| matches!(c, Component::Normal(_)))
+        };
+        for pattern in artifact_patterns(&wt).iter().filter(|p| inside(p)) {
+            let Ok(paths) = glob::glob(&format!("{root}/{pattern}")) else {
+                continue;
+            };
+            found.extend(paths.flatten().filter(|p| is_ignored(&wt, p)));
+        }
+    }
+    found.sort();
+    found.dedup();
+    let mut outermost: Vec<PathBuf> = Vec::new();
+    for p in found {
+        if !outermost.last().is_some_and(|o| p.starts_with(o)) {
+            outermost.push(p);
+        }
+    }
+    outermost
+}
+
+#[cfg(test)]
+#[path = "clean_tests.rs"]
+mod tests;
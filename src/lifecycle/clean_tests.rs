@@ -0,0 +1,45 @@
+use super::*;
+use crate::test_support::git_in;
+
+fn write(path: &Path, body: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, body).unwrap();
+}
+
+#[test]
+fn test_artifact_patterns_detects_ecosystems() {
+    let dir = tempfile::tempdir().unwrap();
+    write(&dir.path().join("Cargo.toml"), "");
+    write(&dir.path().join("package.json"), "{}");
+    write(&dir.path().join(".worktree.toml"), "clean = [\"dist\"]\n");
+    let patterns = artifact_patterns(dir.path());
+    for p in ["target", "node_modules", "dist"] {
+        assert!(patterns.iter().any(|x| x == p), "{p} in {patterns:?}");
+    }
+    assert!(!patterns.iter().any(|x| x == ".venv"));
+}
+
+#[test]
+fn test_find_artifacts_only_returns_ignored_outermost_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dir.path();
+    git_in(wt, &["init", "-b", "main"]);
+    write(&wt.join("package.json"), "{}");
+    write(&wt.join(".gitignore"), "node_modules/\n");
+    write(
+        &wt.join(".worktree.toml"),
+        "clean = [\"**/node_modules\", \"src\"]\n",
+    );
+    write(&wt.join("src/main.js"), "source");
+    git_in(wt, &["add", "."]);
+    git_in(wt, &["commit", "-m", "init"]);
+    write(&wt.join("node_modules/a/node_modules/b.js"), "dep");
+    write(&wt.join("pkg/node_modules/c.js"), "dep");
+
+    let found = find_artifacts(wt);
+    assert_eq!(
+        found,
+        [wt.join("node_modules"), wt.join("pkg/node_modules")],
+        "tracked `src` and nested matches are left out"
+    );
+}
@@ -0,0 +1,34 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Create a new, empty entry dir for the workspace at `path` under `root`.
+///
+/// The id is `<dir name>-<unix secs>`, with a `-2`, `-3`, ... suffix when
+/// that dir already exists, so entries made in the same second never share
+/// a dir. Returns the id and the dir.
+///
+/// # Errors
+///
+/// Returns an error if `root` or the entry dir cannot be created.
+pub fn new_entry_dir(root: &Path, path: &Path, now: SystemTime) -> Result<(String, PathBuf)> {
+    std::fs::create_dir_all(root)
+        .with_context(|| format!("Failed to create {}", root.display()))?;
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let base = format!("{name}-{secs}");
+    let ids = std::iter::once(base.clone()).chain((2..).map(|n| format!("{base}-{n}")));
+    for id in ids {
+        let dir = root.join(&id);
+        match std::fs::create_dir(&dir) {
+            Ok(()) => return Ok((id, dir)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e).with_context(|| format!("Failed to create {}", dir.display())),
+        }
+    }
+    unreachable!("the id suffixes never run out")
+}
+
+#[cfg(test)]
+#[path = "entry_dir_tests.rs"]
+mod tests;
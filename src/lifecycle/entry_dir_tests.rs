@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn entries_made_in_the_same_second_get_distinct_dirs() {
+    let root = tempfile::tempdir().unwrap();
+    let now = UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+    let (a, b) = (
+        Path::new("/w/acme/api/issue-1"),
+        Path::new("/w/acme/web/issue-1"),
+    );
+    let (first, dir_a) = new_entry_dir(root.path(), a, now).unwrap();
+    let (second, dir_b) = new_entry_dir(root.path(), b, now).unwrap();
+    assert_eq!(first, "issue-1-1700000000");
+    assert_eq!(second, "issue-1-1700000000-2");
+    assert!(dir_a.is_dir() && dir_b.is_dir());
+    assert_ne!(dir_a, dir_b);
+}
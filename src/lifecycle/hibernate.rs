@@ -0,0 +1,95 @@
+use anyhow::{bail, Context, Result};
+use std::time::SystemTime;
+
+use crate::git::{
+    apply_stash_ref, common_dir, current_branch, delete_ref, git_worktree_prune, remove_worktree,
+    restore_worktree, stash_to_ref, unlock_worktree, untracked_files,
+};
+use crate::ttl::{Hibernation, WorkspaceRecord};
+
+use super::members;
+
+/// Hibernate the workspace described by `record`: stash its uncommitted and
+/// untracked changes into its repository and delete its working tree.
+///
+/// Committed work stays on the branch, so nothing is lost, but ignored files
+/// such as build output and installed dependencies are. `record` is marked
+/// hibernated and gains the branch and repository that
+/// [`wake_workspace`] needs.
+///
+/// # Errors
+///
+/// Returns an error if the workspace is not a single git worktree on a
+/// branch, or git cannot stash its changes or remove it.
+pub fn hibernate_workspace(record: &mut WorkspaceRecord, now: SystemTime) -> Result<()> {
+    let wt = record.path.clone();
+    if members(&wt) != [wt.clone()] {
+        bail!(
+            "{} is not a single git worktree; only single-repository workspaces can hibernate",
+            wt.display()
+        );
+    }
+    let repo = common_dir(&wt)?;
+    let branch =
+        current_branch(&wt)?.with_context(|| format!("{} has a detached HEAD", wt.display()))?;
+    let untracked = untracked_files(&wt)?;
+    let stash = format!("refs/hibernate/{branch}");
+    let stashed = stash_to_ref(&wt, &stash)?;
+    unlock_worktree(&wt)?;
+    remove_worktree(&repo, &wt, true)?;
+    if wt.exists() {
+        std::fs::remove_dir_all(&wt)
+            .with_context(|| format!("Failed to remove {}", wt.display()))?;
+    }
+    record.branch = Some(branch);
+    record.bare_path.get_or_insert(repo);
+    record.hibernated = Some(Hibernation {
+        at: now,
+        stash: stashed.then_some(stash),
+        untracked,
+    });
+    Ok(())
+}
+
+/// Recreate the working tree of a hibernated workspace from its branch and
+/// reapply the stashed changes, then clear its hibernation mark. Does
+/// nothing for a workspace that is not hibernated.
+///
+/// # Errors
+///
+/// Returns an error if the repository or branch is unknown, the worktree
+/// cannot be added, or the stash does not apply.
+pub fn wake_workspace(record: &mut WorkspaceRecord) -> Result<()> {
+    let Some(hibernation) = record.hibernated.clone() else {
+        return Ok(());
+    };
+    let path = record.path.clone();
+    let repo = record
+        .bare_path
+        .clone()
+        .with_context(|| format!("No repository recorded for {}", path.display()))?;
+    let branch = record.branch.as_deref().context("No branch recorded")?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let _ = git_worktree_prune(&repo);
+    restore_worktree(&repo, &path, branch, record.base_ref.as_deref())?;
+    if let Some(stash) = &hibernation.stash {
+        apply_stash_ref(&path, stash)?;
+        delete_ref(&repo, stash)?;
+    }
+    let missing = hibernation
+        .untracked
+        .iter()
+        .filter(|f| !path.join(f).exists());
+    if let n @ 1.. = missing.count() {
+        eprintln!("Warning: {n} untracked file(s) saved at hibernation were not restored");
+    }
+    record.hibernated = None;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "hibernate_tests.rs"]
+mod tests;
@@ -0,0 +1,30 @@
+use super::*;
+use crate::test_support::{commit_file, git_in, git_out, repo_with_worktree};
+
+#[test]
+fn test_hibernate_and_wake_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    commit_file(&wt, "g");
+    std::fs::write(wt.join("f"), "staged").unwrap();
+    git_in(&wt, &["add", "f"]);
+    std::fs::write(wt.join("g"), "unstaged").unwrap();
+    std::fs::write(wt.join("new"), "untracked").unwrap();
+    let before = git_out(&wt, &["status", "--porcelain"]);
+
+    let mut record = WorkspaceRecord::new(wt.clone(), SystemTime::now());
+    hibernate_workspace(&mut record, SystemTime::now()).unwrap();
+    assert!(!wt.exists());
+    let h = record.hibernated.clone().unwrap();
+    assert_eq!(h.stash.as_deref(), Some("refs/hibernate/issue-1"));
+    assert_eq!(h.untracked, ["new"]);
+    assert_eq!(record.branch.as_deref(), Some("issue-1"));
+    assert_eq!(git_out(&repo, &["worktree", "list"]).lines().count(), 1);
+    assert!(git_out(&repo, &["stash", "list"]).is_empty());
+
+    wake_workspace(&mut record).unwrap();
+    assert!(record.hibernated.is_none());
+    assert_eq!(git_out(&wt, &["status", "--porcelain"]), before);
+    assert_eq!(std::fs::read_to_string(wt.join("g")).unwrap(), "unstaged");
+    assert!(git_out(&repo, &["for-each-ref", "refs/hibernate"]).is_empty());
+}
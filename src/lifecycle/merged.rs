@@ -0,0 +1,77 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::git::{
+    common_dir, current_branch, detect_default_branch, get_remote_url, git_fetch, head_commit_time,
+    is_ancestor, stale_remote_branches,
+};
+
+use super::members;
+
+/// What a repository's `origin` looked like after fetching it.
+struct Upstream {
+    default_ref: String,
+    stale: Vec<String>,
+}
+
+/// Decides whether a workspace's branches are done, fetching each owning
+/// repository at most once.
+#[derive(Default)]
+pub struct MergeCheck {
+    repos: HashMap<PathBuf, Option<Upstream>>,
+}
+
+impl MergeCheck {
+    /// Whether every member worktree of the workspace at `path` is finished.
+    ///
+    /// A branch is finished when its remote branch was deleted upstream, or
+    /// when its tip is reachable from `origin/<default>` and was committed
+    /// after `created_at` (so a fresh, untouched branch never counts).
+    /// Anything that cannot be checked, such as a repository without an
+    /// `origin` remote, counts as not merged.
+    pub fn is_merged(&mut self, path: &Path, created_at: SystemTime) -> bool {
+        let members = members(path);
+        !members.is_empty()
+            && members
+                .iter()
+                .all(|wt| self.member_merged(wt, created_at).unwrap_or(false))
+    }
+
+    fn member_merged(&mut self, wt: &Path, created_at: SystemTime) -> Result<bool> {
+        let Some(branch) = current_branch(wt)? else {
+            return Ok(false);
+        };
+        let repo = common_dir(wt)?;
+        let upstream = self
+            .repos
+            .entry(repo)
+            .or_insert_with_key(|repo| fetch_upstream(repo));
+        let Some(upstream) = upstream else {
+            return Ok(false);
+        };
+        if upstream.stale.contains(&branch) {
+            return Ok(true);
+        }
+        // Commit timestamps have one-second resolution.
+        let committed = head_commit_time(wt)? + Duration::from_secs(1);
+        Ok(committed > created_at && is_ancestor(wt, "HEAD", &upstream.default_ref)?)
+    }
+}
+
+fn fetch_upstream(repo: &Path) -> Option<Upstream> {
+    get_remote_url(repo, "origin").ok()?;
+    if let Err(e) = git_fetch(repo) {
+        eprintln!("Warning: could not fetch {}: {e:#}", repo.display());
+    }
+    let default = detect_default_branch(repo).ok()?;
+    Some(Upstream {
+        default_ref: format!("refs/remotes/origin/{default}"),
+        stale: stale_remote_branches(repo).unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+#[path = "merged_tests.rs"]
+mod tests;
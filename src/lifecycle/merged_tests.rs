@@ -0,0 +1,46 @@
+use super::*;
+use crate::test_support::git_in;
+
+/// A checkout cloned from `origin`, with a worktree on `issue-1`.
+fn clone_with_worktree(dir: &Path) -> (PathBuf, PathBuf) {
+    let origin = dir.join("origin");
+    std::fs::create_dir(&origin).unwrap();
+    git_in(&origin, &["init", "-b", "main"]);
+    git_in(&origin, &["commit", "--allow-empty", "-m", "init"]);
+    let clone = dir.join("clone");
+    git_in(dir, &["clone", "-q", "origin", "clone"]);
+    let wt = dir.join("wt");
+    git_in(&clone, &["worktree", "add", "-q", "../wt", "-b", "issue-1"]);
+    (origin, wt)
+}
+
+#[test]
+fn test_is_merged_fresh_branch_is_not_merged() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = clone_with_worktree(dir.path());
+    let mut check = MergeCheck::default();
+    assert!(!check.is_merged(&wt, SystemTime::now() + Duration::from_secs(5)));
+}
+
+#[test]
+fn test_is_merged_after_merge_into_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let (origin, wt) = clone_with_worktree(dir.path());
+    git_in(&wt, &["commit", "--allow-empty", "-m", "work"]);
+    let created = SystemTime::now() - Duration::from_mins(1);
+    assert!(!MergeCheck::default().is_merged(&wt, created));
+    git_in(&origin, &["pull", "-q", "--ff-only", "../wt", "issue-1"]);
+    assert!(MergeCheck::default().is_merged(&wt, created));
+}
+
+#[test]
+fn test_is_merged_without_origin_or_worktree() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().join("repo");
+    std::fs::create_dir(&repo).unwrap();
+    git_in(&repo, &["init", "-b", "main"]);
+    git_in(&repo, &["commit", "--allow-empty", "-m", "init"]);
+    let mut check = MergeCheck::default();
+    assert!(!check.is_merged(&repo, SystemTime::UNIX_EPOCH));
+    assert!(!check.is_merged(dir.path().join("missing").as_path(), SystemTime::UNIX_EPOCH));
+}
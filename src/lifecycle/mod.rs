@@ -0,0 +1,42 @@
+//! Unsaved work is archived before forced removals, and removals go through
+//! the trash so they can be undone.
+mod archive;
+mod archive_save;
+mod clean;
+mod entry_dir;
+mod hibernate;
+mod merged;
+mod orphan;
+mod pin;
+mod prune;
+mod relocate;
+mod relocate_copy;
+mod remove;
+mod safety;
+mod select;
+mod sync;
+mod trash;
+mod trash_empty;
+mod trash_move;
+mod trash_restore;
+mod unarchive;
+
+pub use archive::{Archive, ArchivedMember};
+pub use archive_save::{archive_unsaved, archive_workspace};
+pub use clean::{artifact_patterns, find_artifacts};
+pub use hibernate::{hibernate_workspace, wake_workspace};
+pub use merged::MergeCheck;
+pub use orphan::{bare_clone_in_use, last_activity};
+pub use pin::set_locked;
+pub use prune::{preview_prune, prune_workspace, Pruned};
+pub use relocate::move_workspace;
+pub(crate) use relocate_copy::copy_tree;
+pub use remove::remove_workspace;
+pub use safety::{members, unsaved_work, UnsavedWork};
+pub use select::{select_prunable, PruneReason};
+pub use sync::{sync_worktree, SyncStrategy, Synced};
+pub use trash::{TrashEntry, TrashedMember};
+pub use trash_empty::{empty_trash, DEFAULT_TRASH_RETENTION};
+pub use trash_move::trash_workspace;
+pub use trash_restore::restore_trashed;
+pub use unarchive::unarchive;
@@ -0,0 +1,43 @@
+use anyhow::Result;
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::git::{git_worktree_prune, list_worktrees};
+use crate::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+/// Whether the bare clone at `bare` still backs any workspace.
+///
+/// Stale worktree entries are pruned first; the clone is in use when git
+/// still knows a worktree of it, or a registered workspace lives inside it
+/// or was created from it. The latter covers trashed workspaces, whose
+/// worktrees git no longer lists, and ones moved out of the clone.
+///
+/// # Errors
+///
+/// Returns an error if git cannot prune or list the clone's worktrees.
+pub fn bare_clone_in_use(bare: &Path, registry: &WorkspaceRegistry) -> Result<bool> {
+    git_worktree_prune(bare)?;
+    // The first entry is the bare repository itself.
+    let attached = list_worktrees(bare)?.len() > 1;
+    let recorded = |r: &WorkspaceRecord| r.path.starts_with(bare) || r.repos().any(|p| p == bare);
+    Ok(attached || registry.workspace.iter().any(recorded))
+}
+
+/// When the bare clone at `bare` was last touched by a fetch or a worktree
+/// being added or removed.
+#[must_use]
+pub fn last_activity(bare: &Path) -> SystemTime {
+    ["", "worktrees", "FETCH_HEAD"]
+        .iter()
+        .filter_map(|p| {
+            std::fs::metadata(bare.join(p))
+                .and_then(|m| m.modified())
+                .ok()
+        })
+        .max()
+        .unwrap_or(SystemTime::UNIX_EPOCH)
+}
+
+#[cfg(test)]
+#[path = "orphan_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_support::{add_worktree, setup_bare_clone};
+use crate::ttl::{MemberRecord, WorkspaceKind, WorkspaceRecord};
+
+#[test]
+fn test_bare_clone_in_use_tracks_worktrees() {
+    let dir = tempfile::tempdir().unwrap();
+    let bare = setup_bare_clone(dir.path());
+    let registry = WorkspaceRegistry::default();
+    assert!(!bare_clone_in_use(&bare, &registry).unwrap());
+    add_worktree(&bare, &bare.join("issue-1"), "issue-1");
+    assert!(bare_clone_in_use(&bare, &registry).unwrap());
+    // A worktree deleted by hand no longer counts once pruned.
+    std::fs::remove_dir_all(bare.join("issue-1")).unwrap();
+    assert!(!bare_clone_in_use(&bare, &registry).unwrap());
+}
+
+#[test]
+fn test_bare_clone_in_use_by_registered_workspace() {
+    let dir = tempfile::tempdir().unwrap();
+    let bare = setup_bare_clone(dir.path());
+    let mut registry = WorkspaceRegistry::default();
+    registry.insert(WorkspaceRecord::new(
+        bare.join("issue-9"),
+        SystemTime::now(),
+    ));
+    assert!(bare_clone_in_use(&bare, &registry).unwrap());
+}
+
+#[test]
+fn test_last_activity() {
+    let dir = tempfile::tempdir().unwrap();
+    let bare = setup_bare_clone(dir.path());
+    assert!(last_activity(&bare) > SystemTime::UNIX_EPOCH);
+    assert_eq!(
+        last_activity(&dir.path().join("missing")),
+        SystemTime::UNIX_EPOCH
+    );
+}
+
+#[test]
+fn test_bare_clone_in_use_by_trashed_multi_workspace() {
+    let dir = tempfile::tempdir().unwrap();
+    let bare = setup_bare_clone(dir.path());
+    let mut record = WorkspaceRecord::new(dir.path().join("multi"), SystemTime::now());
+    record.kind = WorkspaceKind::Multi;
+    record.trashed_at = Some(SystemTime::now());
+    record.members.push(MemberRecord {
+        path: dir.path().join("multi").join("api"),
+        bare_path: Some(bare.clone()),
+        ..MemberRecord::default()
+    });
+    let mut registry = WorkspaceRegistry::default();
+    registry.insert(record);
+    assert!(bare_clone_in_use(&bare, &registry).unwrap());
+}
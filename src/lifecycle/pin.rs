@@ -0,0 +1,30 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::git::{lock_worktree, unlock_worktree};
+
+use super::members;
+
+/// Reason recorded by `git worktree lock` for pinned workspaces.
+const LOCK_REASON: &str = "pinned by worktree";
+
+/// Lock or unlock every member worktree of the workspace at `path` with
+/// `git worktree lock`, so git's own pruning leaves pinned workspaces alone.
+///
+/// # Errors
+///
+/// Returns an error if a worktree cannot be locked or unlocked.
+pub fn set_locked(path: &Path, locked: bool) -> Result<()> {
+    for wt in members(path) {
+        if locked {
+            lock_worktree(&wt, LOCK_REASON)?;
+        } else {
+            unlock_worktree(&wt)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "pin_tests.rs"]
+mod tests;
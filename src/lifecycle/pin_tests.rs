@@ -0,0 +1,27 @@
+use super::*;
+use crate::git::is_worktree_locked;
+use crate::lifecycle::remove_workspace;
+use crate::test_support::{git_out, repo_with_worktree};
+
+#[test]
+fn test_set_locked_is_idempotent_both_ways() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    set_locked(&wt, true).unwrap();
+    set_locked(&wt, true).unwrap();
+    assert!(is_worktree_locked(&wt).unwrap());
+    set_locked(&wt, false).unwrap();
+    set_locked(&wt, false).unwrap();
+    assert!(!is_worktree_locked(&wt).unwrap());
+}
+
+#[test]
+fn test_remove_workspace_unlocks_pinned_worktree() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    set_locked(&wt, true).unwrap();
+    remove_workspace(&wt, false).unwrap();
+    assert!(!wt.exists());
+    let listed = git_out(&repo, &["worktree", "list", "--porcelain"]);
+    assert!(!listed.contains("issue-1"));
+}
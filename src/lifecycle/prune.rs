@@ -0,0 +1,52 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::{remove_workspace, unsaved_work, UnsavedWork};
+
+/// What [`prune_workspace`] did with an expired workspace.
+#[derive(Debug)]
+pub enum Pruned {
+    /// The workspace was removed from disk (or was already gone); from
+    /// [`preview_prune`], it would be.
+    Removed,
+    /// The workspace was left in place because it holds unsaved work.
+    Kept(Vec<UnsavedWork>),
+}
+
+/// Remove an expired workspace unless it holds unsaved work.
+///
+/// Member worktrees with uncommitted changes or unpushed commits keep the
+/// whole workspace on disk; `force` skips that check. Removal goes through
+/// [`remove_workspace`], so bare clones forget their worktrees.
+///
+/// # Errors
+///
+/// Returns an error if git cannot inspect a member worktree or the workspace
+/// cannot be removed.
+pub fn prune_workspace(path: &Path, force: bool) -> Result<Pruned> {
+    let outcome = preview_prune(path, force)?;
+    if matches!(outcome, Pruned::Removed) {
+        remove_workspace(path, false)?;
+    }
+    Ok(outcome)
+}
+
+/// What [`prune_workspace`] would do with the workspace at `path`, without
+/// touching it.
+///
+/// # Errors
+///
+/// Returns an error if git cannot inspect a member worktree.
+pub fn preview_prune(path: &Path, force: bool) -> Result<Pruned> {
+    if !force {
+        let unsaved = unsaved_work(path)?;
+        if !unsaved.is_empty() {
+            return Ok(Pruned::Kept(unsaved));
+        }
+    }
+    Ok(Pruned::Removed)
+}
+
+#[cfg(test)]
+#[path = "prune_tests.rs"]
+mod tests;
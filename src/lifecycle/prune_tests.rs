@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_support::repo_with_worktree;
+
+fn dirty_worktree(dir: &Path) -> std::path::PathBuf {
+    let (_, wt) = repo_with_worktree(dir);
+    std::fs::write(wt.join("f"), "edited").unwrap();
+    wt
+}
+
+#[test]
+fn test_prune_workspace_keeps_unsaved_work() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dirty_worktree(dir.path());
+    let Pruned::Kept(unsaved) = prune_workspace(&wt, false).unwrap() else {
+        unreachable!("dirty worktree must be kept");
+    };
+    assert_eq!(unsaved[0].dirty, 1);
+    assert!(wt.join("f").exists());
+}
+
+#[test]
+fn test_prune_workspace_force_removes_unsaved_work() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dirty_worktree(dir.path());
+    let outcome = prune_workspace(&wt, true).unwrap();
+    assert!(matches!(outcome, Pruned::Removed));
+    assert!(!wt.exists());
+}
+
+#[test]
+fn test_prune_workspace_plain_dir_and_missing() {
+    let dir = tempfile::tempdir().unwrap();
+    let plain = dir.path().join("plain");
+    std::fs::create_dir(&plain).unwrap();
+    assert!(matches!(
+        prune_workspace(&plain, false).unwrap(),
+        Pruned::Removed
+    ));
+    assert!(!plain.exists());
+    assert!(matches!(
+        prune_workspace(&plain, false).unwrap(),
+        Pruned::Removed
+    ));
+}
+
+#[test]
+fn test_preview_prune_leaves_workspace_in_place() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dirty_worktree(dir.path());
+    assert!(matches!(
+        preview_prune(&wt, false).unwrap(),
+        Pruned::Kept(_)
+    ));
+    assert!(matches!(preview_prune(&wt, true).unwrap(), Pruned::Removed));
+    assert!(wt.join("f").exists());
+}
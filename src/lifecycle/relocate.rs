@@ -0,0 +1,82 @@
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+use crate::git::{
+    branch_exists_local, common_dir, current_branch, move_worktree, rename_branch, unlock_worktree,
+};
+use crate::ttl::WorkspaceRecord;
+
+use super::relocate_copy::copy_worktree;
+use super::{members, set_locked};
+
+/// Move the workspace described by `record` to `dest` with
+/// `git worktree move`, optionally renaming its branch to `branch` first.
+///
+/// When `dest` is on another file system, which `git worktree move` cannot
+/// handle, the tree is copied there instead and its git links repaired.
+/// `record` gains the new path and branch; everything else, including when
+/// it was created, is kept. Pinned workspaces stay locked.
+///
+/// # Errors
+///
+/// Returns an error if the workspace is not a single git worktree, `dest`
+/// exists, the new branch already exists, or git cannot move the worktree.
+pub fn move_workspace(
+    record: &mut WorkspaceRecord,
+    dest: &Path,
+    branch: Option<&str>,
+) -> Result<()> {
+    let wt = record.path.clone();
+    if members(&wt) != [wt.clone()] {
+        bail!(
+            "{} is not a single git worktree; only single-repository workspaces can be moved",
+            wt.display()
+        );
+    }
+    if dest.exists() {
+        bail!("{} already exists", dest.display());
+    }
+    let repo = common_dir(&wt)?;
+    let old = current_branch(&wt)?;
+    let rename = match (branch, &old) {
+        (None, _) => None,
+        (Some(new), Some(old)) if new == old => None,
+        (Some(_), None) => bail!("{} has a detached HEAD", wt.display()),
+        (Some(new), Some(old)) if branch_exists_local(&repo, new) => {
+            bail!("Cannot rename {old}: branch {new} already exists")
+        }
+        (Some(new), Some(old)) => Some((old.as_str(), new)),
+    };
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    if let Some((old, new)) = rename {
+        rename_branch(&repo, old, new)?;
+    }
+    unlock_worktree(&wt)?;
+    let moved = move_worktree(&repo, &wt, dest).or_else(|e| {
+        if !format!("{e:#}").to_lowercase().contains("cross-device") {
+            return Err(e);
+        }
+        copy_worktree(&repo, &wt, dest)
+    });
+    if let Err(e) = moved {
+        if let Some((old, new)) = rename {
+            let _ = rename_branch(&repo, new, old);
+        }
+        let _ = set_locked(&wt, record.pinned);
+        return Err(e);
+    }
+    set_locked(dest, record.pinned)?;
+    record.path = dest.to_path_buf();
+    record.bare_path.get_or_insert(repo);
+    if let Some((_, new)) = rename {
+        record.branch = Some(new.to_owned());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "relocate_tests.rs"]
+mod tests;
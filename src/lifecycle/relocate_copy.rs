@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::git::repair_worktree;
+
+/// Copy the worktree at `wt` to `dest` on another file system, point git at
+/// the copy, and delete the original.
+///
+/// When copying or repairing fails, the partial copy is removed and git is
+/// pointed back at `wt`.
+pub(super) fn copy_worktree(repo: &Path, wt: &Path, dest: &Path) -> Result<()> {
+    let copied = copy_tree(wt, dest)
+        .with_context(|| format!("Failed to copy {} to {}", wt.display(), dest.display()))
+        .and_then(|()| repair_worktree(repo, dest));
+    if let Err(e) = copied {
+        let _ = std::fs::remove_dir_all(dest);
+        let _ = repair_worktree(repo, wt);
+        return Err(e);
+    }
+    std::fs::remove_dir_all(wt).with_context(|| format!("Failed to remove {}", wt.display()))
+}
+
+/// Copy the directory `from` to the new directory `to`, keeping symlinks as
+/// symlinks where the platform allows.
+pub fn copy_tree(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let (src, dst) = (entry.path(), to.join(entry.file_name()));
+        let kind = entry.file_type()?;
+        if kind.is_dir() {
+            copy_tree(&src, &dst)?;
+        } else if kind.is_symlink() && cfg!(unix) {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(std::fs::read_link(&src)?, &dst)?;
+        } else {
+            std::fs::copy(&src, &dst)?;
+        }
+    }
+    Ok(())
+}
+
+/// Rename `from` to `to`, falling back to copying and then deleting `from`
+/// when the two are on different file systems.
+pub fn move_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    match std::fs::rename(from, to) {
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            if let Err(e) = copy_tree(from, to) {
+                let _ = std::fs::remove_dir_all(to);
+                return Err(e);
+            }
+            std::fs::remove_dir_all(from)
+        }
+        moved => moved,
+    }
+}
+
+#[cfg(test)]
+#[path = "relocate_copy_tests.rs"]
+mod tests;
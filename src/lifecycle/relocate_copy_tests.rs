@@ -0,0 +1,47 @@
+use super::*;
+
+fn tree(dir: &Path) -> std::path::PathBuf {
+    let from = dir.join("from");
+    std::fs::create_dir_all(from.join("sub")).unwrap();
+    std::fs::write(from.join("sub").join("f"), "x").unwrap();
+    from
+}
+
+#[test]
+fn test_move_dir_renames() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = tree(dir.path());
+    let to = dir.path().join("to");
+    move_dir(&from, &to).unwrap();
+    assert!(!from.exists());
+    assert_eq!(std::fs::read_to_string(to.join("sub/f")).unwrap(), "x");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_move_dir_copies_across_file_systems() {
+    use std::os::unix::fs::MetadataExt;
+    let dir = tempfile::tempdir().unwrap();
+    let Ok(other) = tempfile::tempdir_in("/dev/shm") else {
+        return;
+    };
+    let dev = |p: &Path| std::fs::metadata(p).unwrap().dev();
+    if dev(dir.path()) == dev(other.path()) {
+        return;
+    }
+    let from = tree(dir.path());
+    let to = other.path().join("to");
+    move_dir(&from, &to).unwrap();
+    assert!(!from.exists());
+    assert_eq!(std::fs::read_to_string(to.join("sub/f")).unwrap(), "x");
+}
+
+#[test]
+fn test_copy_worktree_removes_partial_copy_on_failure() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = tree(dir.path());
+    let to = dir.path().join("to");
+    assert!(copy_worktree(&dir.path().join("missing"), &from, &to).is_err());
+    assert!(!to.exists());
+    assert!(from.join("sub/f").exists());
+}
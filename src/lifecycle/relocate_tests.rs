@@ -0,0 +1,75 @@
+use super::super::relocate_copy::copy_tree;
+use super::*;
+use crate::git::is_worktree_locked;
+use crate::test_support::{add_worktree, init_repo};
+use std::time::SystemTime;
+
+/// A repository with a worktree on `bold_turing` at `<dir>/wt`, and its record.
+fn workspace(dir: &Path) -> WorkspaceRecord {
+    let wt = dir.join("wt");
+    add_worktree(&init_repo(dir), &wt, "bold_turing");
+    WorkspaceRecord::new(wt, SystemTime::UNIX_EPOCH)
+}
+
+#[test]
+fn test_move_workspace_renames_branch_and_keeps_created_at() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut record = workspace(dir.path());
+    std::fs::write(record.path.join("notes.txt"), "mine").unwrap();
+    let dest = dir.path().join("moved").join("issue-42");
+    move_workspace(&mut record, &dest, Some("issue-42")).unwrap();
+    assert_eq!(record.path, dest);
+    assert_eq!(record.branch.as_deref(), Some("issue-42"));
+    assert_eq!(record.created_at, SystemTime::UNIX_EPOCH);
+    assert!(!dir.path().join("wt").exists());
+    assert!(dest.join("notes.txt").exists());
+    assert_eq!(current_branch(&dest).unwrap().as_deref(), Some("issue-42"));
+}
+
+#[test]
+fn test_move_workspace_keeps_pinned_worktree_locked() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut record = workspace(dir.path());
+    record.pinned = true;
+    set_locked(&record.path, true).unwrap();
+    let dest = dir.path().join("pinned");
+    move_workspace(&mut record, &dest, None).unwrap();
+    assert!(is_worktree_locked(&dest).unwrap());
+    assert_eq!(
+        current_branch(&dest).unwrap().as_deref(),
+        Some("bold_turing")
+    );
+}
+
+#[test]
+fn test_move_workspace_refuses_taken_destination_or_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut record = workspace(dir.path());
+    let taken = dir.path().join("taken");
+    std::fs::create_dir(&taken).unwrap();
+    assert!(move_workspace(&mut record, &taken, None).is_err());
+    let dest = dir.path().join("free");
+    assert!(move_workspace(&mut record, &dest, Some("main")).is_err());
+    assert!(!dest.exists());
+    assert_eq!(record.path, dir.path().join("wt"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_copy_tree_keeps_symlinks() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = dir.path().join("from");
+    std::fs::create_dir_all(from.join("sub")).unwrap();
+    std::fs::write(from.join("sub").join("f"), "x").unwrap();
+    std::os::unix::fs::symlink("sub/f", from.join("link")).unwrap();
+    let to = dir.path().join("to");
+    copy_tree(&from, &to).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(to.join("sub").join("f")).unwrap(),
+        "x"
+    );
+    assert_eq!(
+        std::fs::read_link(to.join("link")).unwrap(),
+        Path::new("sub/f")
+    );
+}
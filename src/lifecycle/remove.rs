@@ -0,0 +1,42 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::git::{common_dir, current_branch, delete_branch, remove_worktree, unlock_worktree};
+
+use super::members;
+
+/// Remove the workspace at `path` without checking for unsaved work.
+///
+/// Each member worktree is unlocked and removed with `git worktree remove
+/// --force` so its bare clone forgets it; when `drop_branch` is set the
+/// checked-out branch is then deleted from the owning repository. Whatever
+/// remains (a multi-repo root, or a directory that is not a worktree at all)
+/// is deleted from disk. Callers are expected to have consulted
+/// [`super::unsaved_work`] first.
+///
+/// # Errors
+///
+/// Returns an error if a worktree or the remaining directory cannot be
+/// removed. A branch that cannot be deleted only prints a warning.
+pub fn remove_workspace(path: &Path, drop_branch: bool) -> Result<()> {
+    for wt in members(path) {
+        let repo = common_dir(&wt)?;
+        let branch = current_branch(&wt).ok().flatten();
+        unlock_worktree(&wt)?;
+        remove_worktree(&repo, &wt, true)?;
+        if let (true, Some(b)) = (drop_branch, branch) {
+            if let Err(e) = delete_branch(&repo, &b) {
+                eprintln!("Warning: could not delete branch {b}: {e}");
+            }
+        }
+    }
+    if path.exists() {
+        std::fs::remove_dir_all(path)
+            .with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "remove_tests.rs"]
+mod tests;
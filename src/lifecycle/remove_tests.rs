@@ -0,0 +1,39 @@
+use super::*;
+use crate::test_support::{git_in, git_out, repo_with_worktree};
+
+#[test]
+fn test_remove_workspace_keeps_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    std::fs::write(wt.join("untracked"), "u").unwrap();
+    remove_workspace(&wt, false).unwrap();
+    assert!(!wt.exists());
+    git_in(&repo, &["rev-parse", "--verify", "refs/heads/issue-1"]);
+    // git no longer knows the worktree, so the branch can be checked out again.
+    git_in(&repo, &["worktree", "add", wt.to_str().unwrap(), "issue-1"]);
+}
+
+#[test]
+fn test_remove_workspace_deletes_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    remove_workspace(&wt, true).unwrap();
+    assert!(git_out(&repo, &["branch", "--list", "issue-1"]).is_empty());
+}
+
+#[test]
+fn test_remove_workspace_plain_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let plain = dir.path().join("plain");
+    std::fs::create_dir_all(plain.join("sub")).unwrap();
+    remove_workspace(&plain, false).unwrap();
+    assert!(!plain.exists());
+}
+
+#[test]
+fn test_remove_workspace_not_a_dir_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("file");
+    std::fs::write(&file, "x").unwrap();
+    assert!(remove_workspace(&file, false).is_err());
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::git::{dirty_paths, unpushed_count};
+use crate::repo_hooks_scaffold::SCAFFOLD;
+use crate::workspace_env::is_generated_envrc;
+
+/// Local work in one worktree that would be lost if it were removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsavedWork {
+    /// The worktree holding the work.
+    pub path: PathBuf,
+    /// Number of modified, staged, or untracked paths.
+    pub dirty: usize,
+    /// Number of commits not present on any remote.
+    pub unpushed: usize,
+}
+
+impl std::fmt::Display for UnsavedWork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} uncommitted change(s), {} unpushed commit(s)",
+            self.path.display(),
+            self.dirty,
+            self.unpushed
+        )
+    }
+}
+
+/// The git worktrees that make up the workspace at `path`.
+///
+/// A single workspace is its own worktree. A multi-repo workspace root holds
+/// one worktree per sub-directory. Directories that are not git worktrees
+/// yield an empty list.
+#[must_use]
+pub fn members(path: &Path) -> Vec<PathBuf> {
+    if path.join(".git").exists() {
+        return vec![path.to_path_buf()];
+    }
+    let Ok(rd) = std::fs::read_dir(path) else {
+        return Vec::new();
+    };
+    let mut found: Vec<PathBuf> = rd
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.join(".git").exists())
+        .collect();
+    found.sort();
+    found
+}
+
+/// Inspect every member worktree of the workspace at `path` and report those
+/// with uncommitted changes or unpushed commits.
+///
+/// # Errors
+///
+/// Returns an error if git cannot inspect one of the member worktrees.
+pub fn unsaved_work(path: &Path) -> Result<Vec<UnsavedWork>> {
+    let mut out = Vec::new();
+    for wt in members(path) {
+        let dirty = dirty_paths(&wt)?
+            .iter()
+            .filter(|line| !is_untouched_scaffold(&wt, line))
+            .count();
+        let unpushed = unpushed_count(&wt)?;
+        if dirty > 0 || unpushed > 0 {
+            out.push(UnsavedWork {
+                path: wt,
+                dirty,
+                unpushed,
+            });
+        }
+    }
+    Ok(out)
+}
+
+/// The `.worktree.toml` scaffold and the generated `.envrc` written by `open`
+/// are not user work.
+fn is_untouched_scaffold(wt: &Path, status_line: &str) -> bool {
+    match status_line {
+        "?? .worktree.toml" => {
+            std::fs::read_to_string(wt.join(".worktree.toml")).is_ok_and(|s| s == SCAFFOLD)
+        }
+        "?? .envrc" => is_generated_envrc(wt),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "safety_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_support::{git_in, repo_with_worktree};
+
+#[test]
+fn test_members_non_git_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(members(dir.path()).is_empty());
+    assert!(members(&dir.path().join("missing")).is_empty());
+}
+
+#[test]
+fn test_members_single_and_multi() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    assert_eq!(members(&wt), vec![wt.clone()]);
+    // The temp dir itself acts as a multi-repo root holding `repo` and `wt`.
+    assert_eq!(members(dir.path()), vec![dir.path().join("repo"), wt]);
+}
+
+#[test]
+fn test_unsaved_work_clean() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    assert!(unsaved_work(&wt).unwrap().is_empty());
+}
+
+#[test]
+fn test_unsaved_work_dirty_and_unpushed() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    std::fs::write(wt.join("g"), "y").unwrap();
+    git_in(&wt, &["add", "g"]);
+    git_in(&wt, &["commit", "-qm", "work"]);
+    std::fs::write(wt.join("h"), "z").unwrap();
+    let unsaved = unsaved_work(&wt).unwrap();
+    assert_eq!(
+        unsaved,
+        vec![UnsavedWork {
+            path: wt,
+            dirty: 1,
+            unpushed: 1,
+        }]
+    );
+    let msg = unsaved[0].to_string();
+    assert!(msg.contains("1 uncommitted change(s), 1 unpushed commit(s)"));
+}
+
+#[test]
+fn test_unsaved_work_ignores_untouched_scaffold() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    std::fs::write(wt.join(".worktree.toml"), SCAFFOLD).unwrap();
+    assert!(unsaved_work(&wt).unwrap().is_empty());
+    std::fs::write(wt.join(".worktree.toml"), "\"pre:open\" = \"make\"\n").unwrap();
+    assert_eq!(unsaved_work(&wt).unwrap()[0].dirty, 1);
+}
@@ -0,0 +1,66 @@
+use std::time::SystemTime;
+
+use crate::ttl::{is_expired, PrunePolicy, Ttl, TtlMode, WorkspaceRecord};
+
+use super::MergeCheck;
+
+/// Why a workspace was selected for pruning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneReason {
+    /// Older than the configured TTL.
+    Expired,
+    /// Its branch was merged into the default branch or deleted upstream.
+    Merged,
+    /// Evicted to bring total disk usage under `workspace.max_total_size`.
+    OverBudget,
+}
+
+impl PruneReason {
+    /// Lower-case name used in reports, e.g. `"merged"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Expired => "expired",
+            Self::Merged => "merged",
+            Self::OverBudget => "over-budget",
+        }
+    }
+}
+
+/// Pick the registered workspaces that a prune should remove.
+///
+/// Under `policy.ttl`, a workspace is selected when it exceeds its own
+/// [`WorkspaceRecord::ttl`], falling back to `ttl`, measured as `mode`
+/// selects. Under `policy.merged`, it is selected when
+/// [`MergeCheck::is_merged`] reports its branches done.
+/// The TTL is checked first so expired workspaces never trigger a fetch.
+/// Pinned entries and entries whose directory no longer exists are skipped.
+#[must_use]
+pub fn select_prunable(
+    records: &[WorkspaceRecord],
+    ttl: Option<&Ttl>,
+    mode: TtlMode,
+    policy: PrunePolicy,
+    now: SystemTime,
+) -> Vec<(WorkspaceRecord, PruneReason)> {
+    let mut check = MergeCheck::default();
+    records
+        .iter()
+        .filter(|r| !r.pinned && r.path.exists())
+        .filter_map(|r| {
+            let ttl = r.ttl.as_ref().or(ttl).filter(|_| policy.ttl);
+            let reason = if ttl.is_some_and(|t| is_expired(r, t, mode, now)) {
+                PruneReason::Expired
+            } else if policy.merged && check.is_merged(&r.path, r.created_at) {
+                PruneReason::Merged
+            } else {
+                return None;
+            };
+            Some((r.clone(), reason))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "select_tests.rs"]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+use std::time::Duration;
+
+const TTL_ONLY: PrunePolicy = PrunePolicy {
+    ttl: true,
+    merged: false,
+};
+const MERGED_ONLY: PrunePolicy = PrunePolicy {
+    ttl: false,
+    merged: true,
+};
+
+#[test]
+fn test_select_prunable_by_ttl() {
+    let dir = tempfile::tempdir().unwrap();
+    let now = SystemTime::now();
+    let old = WorkspaceRecord::new(dir.path().to_path_buf(), now - Duration::from_hours(2));
+    let fresh = WorkspaceRecord::new(dir.path().to_path_buf(), now);
+    let gone = WorkspaceRecord::new(dir.path().join("gone"), now - Duration::from_hours(2));
+    let ttl = Ttl::new(Duration::from_hours(1));
+    let picked = select_prunable(
+        &[old, fresh, gone],
+        Some(&ttl),
+        TtlMode::Created,
+        TTL_ONLY,
+        now,
+    );
+    assert_eq!(picked.len(), 1);
+    assert_eq!(picked[0].1, PruneReason::Expired);
+    assert_eq!(picked[0].1.as_str(), "expired");
+}
+
+#[test]
+fn test_select_prunable_merged_skips_non_git_dirs() {
+    let dir = tempfile::tempdir().unwrap();
+    let now = SystemTime::now();
+    let rec = WorkspaceRecord::new(dir.path().to_path_buf(), now - Duration::from_hours(2));
+    assert!(select_prunable(
+        std::slice::from_ref(&rec),
+        None,
+        TtlMode::Idle,
+        MERGED_ONLY,
+        now
+    )
+    .is_empty());
+    assert_eq!(PruneReason::Merged.as_str(), "merged");
+}
+
+#[test]
+fn test_select_prunable_idle_keeps_recently_opened() {
+    let dir = tempfile::tempdir().unwrap();
+    let now = SystemTime::now();
+    let mut rec = WorkspaceRecord::new(dir.path().to_path_buf(), now - Duration::from_hours(2));
+    rec.last_opened_at = Some(now);
+    let ttl = Ttl::new(Duration::from_hours(1));
+    let records = std::slice::from_ref(&rec);
+    assert!(select_prunable(records, Some(&ttl), TtlMode::Idle, TTL_ONLY, now).is_empty());
+    assert_eq!(
+        select_prunable(records, Some(&ttl), TtlMode::Created, TTL_ONLY, now).len(),
+        1
+    );
+}
+
+#[test]
+fn test_select_prunable_record_ttl_overrides_global() {
+    let dir = tempfile::tempdir().unwrap();
+    let now = SystemTime::now();
+    let mut rec = WorkspaceRecord::new(dir.path().to_path_buf(), now - Duration::from_hours(2));
+    rec.ttl = Some(Ttl::new(Duration::from_hours(1)));
+    let records = std::slice::from_ref(&rec);
+    assert_eq!(
+        select_prunable(records, None, TtlMode::Created, TTL_ONLY, now).len(),
+        1
+    );
+    assert!(select_prunable(records, None, TtlMode::Created, MERGED_ONLY, now).is_empty());
+    rec.ttl = Some(Ttl::new(Duration::from_hours(3)));
+    let global = Ttl::new(Duration::from_hours(1));
+    let records = std::slice::from_ref(&rec);
+    assert!(select_prunable(records, Some(&global), TtlMode::Created, TTL_ONLY, now).is_empty());
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::git::{ahead_behind, current_branch, has_tracked_changes, merge_from, rebase_onto};
+
+/// How `sync` brings a workspace's branch up to date with its base branch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncStrategy {
+    /// Rebase the branch onto the base branch.
+    #[default]
+    Rebase,
+    /// Merge the base branch into the branch.
+    Merge,
+}
+
+impl SyncStrategy {
+    /// The name used for this strategy in the config, e.g. `"merge"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Rebase => "rebase",
+            Self::Merge => "merge",
+        }
+    }
+}
+
+impl std::fmt::Display for SyncStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for SyncStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rebase" => Ok(Self::Rebase),
+            "merge" => Ok(Self::Merge),
+            other => Err(format!(
+                "unknown sync strategy {other:?} (expected \"rebase\" or \"merge\")"
+            )),
+        }
+    }
+}
+
+/// What syncing one worktree did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Synced {
+    /// The branch took in this many new commits from the base branch.
+    Updated(usize),
+    /// The base branch had nothing new.
+    UpToDate,
+    /// Left alone because of uncommitted changes to tracked files.
+    Dirty,
+    /// Left alone because `HEAD` is detached.
+    Detached,
+    /// The rebase or merge conflicted and was aborted.
+    Conflict,
+}
+
+/// Bring the branch checked out in `wt` up to date with `base` using
+/// `strategy`. Worktrees with uncommitted changes are skipped, and conflicts
+/// are aborted so the branch is left as it was.
+///
+/// # Errors
+///
+/// Returns an error if `base` cannot be resolved or git fails for a reason
+/// other than a conflict.
+pub fn sync_worktree(wt: &Path, base: &str, strategy: SyncStrategy) -> Result<Synced> {
+    if current_branch(wt)?.is_none() {
+        return Ok(Synced::Detached);
+    }
+    if has_tracked_changes(wt)? {
+        return Ok(Synced::Dirty);
+    }
+    let (_, behind) = ahead_behind(wt, base)?;
+    if behind == 0 {
+        return Ok(Synced::UpToDate);
+    }
+    let clean = match strategy {
+        SyncStrategy::Rebase => rebase_onto(wt, base)?,
+        SyncStrategy::Merge => merge_from(wt, base)?,
+    };
+    Ok(if clean {
+        Synced::Updated(behind)
+    } else {
+        Synced::Conflict
+    })
+}
+
+#[cfg(test)]
+#[path = "sync_tests.rs"]
+mod tests;
@@ -0,0 +1,40 @@
+use super::*;
+use crate::test_support::{commit_file, git_in};
+
+/// A repository on `topic` with one commit of its own.
+fn repo(dir: &Path) {
+    git_in(dir, &["init", "-q", "-b", "main"]);
+    git_in(dir, &["config", "user.name", "T"]);
+    git_in(dir, &["config", "user.email", "t@t.com"]);
+    commit_file(dir, "base");
+    git_in(dir, &["switch", "-q", "-c", "topic"]);
+    commit_file(dir, "topic");
+}
+
+#[test]
+fn test_sync_worktree_outcomes() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dir.path();
+    repo(wt);
+    let sync = |s| sync_worktree(wt, "main", s).unwrap();
+    assert_eq!(sync(SyncStrategy::Rebase), Synced::UpToDate);
+    git_in(wt, &["switch", "-q", "main"]);
+    commit_file(wt, "one");
+    commit_file(wt, "two");
+    git_in(wt, &["switch", "-q", "topic"]);
+    std::fs::write(wt.join("topic"), "edited").unwrap();
+    assert_eq!(sync(SyncStrategy::Merge), Synced::Dirty);
+    git_in(wt, &["checkout", "--", "topic"]);
+    assert_eq!(sync(SyncStrategy::Merge), Synced::Updated(2));
+    assert!(sync_worktree(wt, "missing", SyncStrategy::Rebase).is_err());
+    git_in(wt, &["switch", "-q", "--detach"]);
+    assert_eq!(sync(SyncStrategy::Rebase), Synced::Detached);
+}
+
+#[test]
+fn test_strategy_parse_and_display_round_trip() {
+    for s in [SyncStrategy::Rebase, SyncStrategy::Merge] {
+        assert_eq!(s.to_string().parse::<SyncStrategy>(), Ok(s));
+    }
+    assert!("squash".parse::<SyncStrategy>().is_err());
+}
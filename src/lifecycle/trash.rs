@@ -0,0 +1,83 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::ttl::WorkspaceRecord;
+
+/// A workspace moved to the trash, as described by its `trash.toml`.
+///
+/// The directory itself sits under `tree/` next to that file, and the git
+/// administrative directory of each member worktree under `admin/<index>/`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// Name of the trash directory, passed to `worktree trash restore`.
+    pub id: String,
+    /// When the workspace was moved to the trash.
+    #[serde(with = "humantime_serde")]
+    pub trashed_at: SystemTime,
+    /// The workspace's registry entry at the time.
+    pub workspace: WorkspaceRecord,
+    /// One entry per linked git worktree, in `admin/<index>` order.
+    #[serde(default, rename = "member")]
+    pub members: Vec<TrashedMember>,
+}
+
+/// How to re-link one member worktree to its repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedMember {
+    /// Where the worktree was checked out.
+    pub path: PathBuf,
+    /// The bare clone or repository that owned the worktree.
+    pub repo: PathBuf,
+    /// Name of its administrative directory under `<repo>/worktrees/`.
+    pub admin: String,
+    /// The branch that was checked out, `None` when detached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// The commit that was checked out, used to recreate a deleted branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+}
+
+impl TrashEntry {
+    /// Directory holding the trash (`~/.local/share/worktree/trash`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the home directory cannot be determined.
+    pub fn root() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Could not determine home directory")?;
+        Ok(home.join(".local/share/worktree/trash"))
+    }
+
+    /// Read the entry `id` from the trash at `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist or cannot be parsed.
+    pub fn load(root: &Path, id: &str) -> Result<Self> {
+        let path = root.join(id).join("trash.toml");
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("No trashed workspace {id:?} at {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Every readable entry in the trash at `root`, oldest first.
+    #[must_use]
+    pub fn list(root: &Path) -> Vec<Self> {
+        let Ok(rd) = std::fs::read_dir(root) else {
+            return Vec::new();
+        };
+        let names = rd
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().into_owned());
+        let mut entries: Vec<Self> = names.filter_map(|id| Self::load(root, &id).ok()).collect();
+        entries.sort_by_key(|e| e.trashed_at);
+        entries
+    }
+}
+
+#[cfg(test)]
+#[path = "trash_tests.rs"]
+mod tests;
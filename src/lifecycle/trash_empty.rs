@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::ttl::Ttl;
+
+use super::trash::TrashEntry;
+
+/// How long a trashed workspace is kept when `workspace.trash_retention` is
+/// not set.
+pub const DEFAULT_TRASH_RETENTION: Ttl = Ttl::new(Duration::from_hours(7 * 24));
+
+/// Delete the entries of the trash at `root` older than `retention`.
+///
+/// Every entry trashed at least `retention` before `now` goes, or all of them
+/// when `retention` is `None`. Returns the deleted entries.
+///
+/// # Errors
+///
+/// Returns an error if an entry's directory cannot be deleted.
+pub fn empty_trash(
+    root: &Path,
+    retention: Option<Ttl>,
+    now: SystemTime,
+) -> Result<Vec<TrashEntry>> {
+    let age = |e: &TrashEntry| now.duration_since(e.trashed_at).unwrap_or_default();
+    let expired = |e: &TrashEntry| retention.is_none_or(|t| age(e) >= t.duration());
+    let purged: Vec<TrashEntry> = TrashEntry::list(root).into_iter().filter(expired).collect();
+    for e in &purged {
+        let dir = root.join(&e.id);
+        std::fs::remove_dir_all(&dir)
+            .with_context(|| format!("Failed to delete {} from the trash", dir.display()))?;
+    }
+    Ok(purged)
+}
@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::git::{admin_dir, common_dir, current_branch, delete_branch, head_commit};
+use crate::ttl::WorkspaceRecord;
+
+use super::entry_dir::new_entry_dir;
+use super::members;
+use super::relocate_copy::move_dir;
+use super::trash::{TrashEntry, TrashedMember};
+
+/// Move the workspace described by `record` into a new entry of the trash at
+/// `root` instead of deleting it, without checking for unsaved work.
+///
+/// The git administrative directory of each member worktree moves along with
+/// it, so its repository forgets the worktree and frees the branch until
+/// [`super::restore_trashed`] puts both back. When `drop_branch` is set the
+/// checked-out branch is deleted; restoring recreates it at the same commit.
+///
+/// # Errors
+///
+/// Returns an error if git cannot inspect a member worktree or the workspace
+/// cannot be moved. A trash on another file system is filled by copying.
+pub fn trash_workspace(
+    record: &WorkspaceRecord,
+    drop_branch: bool,
+    root: &Path,
+    now: SystemTime,
+) -> Result<TrashEntry> {
+    let linked = members(&record.path)
+        .into_iter()
+        .filter(|wt| wt.join(".git").is_file());
+    let inspected = linked.map(|wt| inspect(&wt)).collect::<Result<Vec<_>>>()?;
+    let (id, dir) = new_entry_dir(root, &record.path, now)?;
+    std::fs::create_dir(dir.join("admin"))
+        .with_context(|| format!("Failed to create {}", dir.display()))?;
+    if let Err(e) = move_dir(&record.path, &dir.join("tree")) {
+        std::fs::remove_dir_all(&dir).ok();
+        let path = record.path.display();
+        return Err(e).with_context(|| format!("Failed to move {path} to the trash"));
+    }
+    let mut members = Vec::new();
+    for (i, (member, admin)) in inspected.into_iter().enumerate() {
+        let dest = dir.join("admin").join(i.to_string());
+        move_dir(&admin, &dest)
+            .with_context(|| format!("Failed to move {} to the trash", admin.display()))?;
+        if let (true, Some(b)) = (drop_branch, &member.branch) {
+            if let Err(e) = delete_branch(&member.repo, b) {
+                eprintln!("Warning: could not delete branch {b}: {e}");
+            }
+        }
+        members.push(member);
+    }
+    let workspace = record.clone();
+    let entry = TrashEntry {
+        id,
+        trashed_at: now,
+        workspace,
+        members,
+    };
+    let content = toml::to_string(&entry).context("Failed to serialize trash metadata")?;
+    std::fs::write(dir.join("trash.toml"), content)
+        .with_context(|| format!("Failed to write trash metadata in {}", dir.display()))?;
+    Ok(entry)
+}
+
+fn inspect(wt: &Path) -> Result<(TrashedMember, PathBuf)> {
+    let admin = admin_dir(wt)?;
+    let member = TrashedMember {
+        path: wt.to_path_buf(),
+        repo: common_dir(wt)?,
+        admin: admin
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into(),
+        branch: current_branch(wt).ok().flatten(),
+        head: head_commit(wt).ok(),
+    };
+    Ok((member, admin))
+}
@@ -0,0 +1,84 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::git::{branch_exists_local, create_branch, list_worktrees, relink_worktree};
+
+use super::relocate_copy::move_dir;
+use super::trash::{TrashEntry, TrashedMember};
+
+/// Move the workspace `id` out of the trash at `root` back to where it was.
+///
+/// Each member's administrative directory returns to its repository and the
+/// worktree is re-linked to it; a branch deleted in the meantime is
+/// recreated at the commit that was checked out. The entry is removed from
+/// the trash.
+///
+/// Returns the entry so the caller can re-register its workspace.
+///
+/// # Errors
+///
+/// Returns an error if the entry is missing, its path is taken, a repository
+/// is gone, its branch is checked out elsewhere, or git cannot re-link it.
+pub fn restore_trashed(root: &Path, id: &str) -> Result<TrashEntry> {
+    let entry = TrashEntry::load(root, id)?;
+    let (dir, path) = (root.join(id), &entry.workspace.path);
+    if path.exists() {
+        bail!(
+            "{} already exists; move it out of the way first",
+            path.display()
+        );
+    }
+    for m in &entry.members {
+        check_member(m)?;
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    move_dir(&dir.join("tree"), path)
+        .with_context(|| format!("Failed to move {} out of the trash", path.display()))?;
+    for (i, m) in entry.members.iter().enumerate() {
+        let admin = free_admin_dir(&m.repo, &m.admin)?;
+        move_dir(&dir.join("admin").join(i.to_string()), &admin)
+            .with_context(|| format!("Failed to restore {}", admin.display()))?;
+        if let (Some(b), Some(head)) = (&m.branch, &m.head) {
+            if !branch_exists_local(&m.repo, b) {
+                create_branch(&m.repo, b, head)?;
+            }
+        }
+        relink_worktree(&m.repo, &m.path, &admin)?;
+    }
+    std::fs::remove_dir_all(&dir)
+        .with_context(|| format!("Failed to remove {} from the trash", dir.display()))?;
+    Ok(entry)
+}
+
+fn check_member(m: &TrashedMember) -> Result<()> {
+    if !m.repo.exists() {
+        bail!("repository {} no longer exists", m.repo.display());
+    }
+    let Some(branch) = &m.branch else {
+        return Ok(());
+    };
+    let worktrees = list_worktrees(&m.repo)?;
+    if let Some(other) = worktrees.iter().find(|w| w.branch.as_ref() == Some(branch)) {
+        bail!(
+            "branch {branch} is checked out at {}; remove that worktree first",
+            other.path.display()
+        );
+    }
+    Ok(())
+}
+
+/// `<repo>/worktrees/<name>`, numbered like git does when that is taken.
+fn free_admin_dir(repo: &Path, name: &str) -> Result<PathBuf> {
+    let parent = repo.join("worktrees");
+    std::fs::create_dir_all(&parent)
+        .with_context(|| format!("Failed to create {}", parent.display()))?;
+    let taken = |n: &String| parent.join(n).exists();
+    let name = std::iter::once(name.to_owned())
+        .chain((1..u32::MAX).map(|n| format!("{name}{n}")))
+        .find(|n| !taken(n))
+        .unwrap_or_default();
+    Ok(parent.join(name))
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::lifecycle::{empty_trash, restore_trashed, trash_workspace, DEFAULT_TRASH_RETENTION};
+use crate::test_support::{git_in, git_out, repo_with_worktree};
+use std::time::Duration;
+
+#[test]
+fn test_trash_and_restore_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    std::fs::write(wt.join("f"), "staged").unwrap();
+    git_in(&wt, &["add", "f"]);
+    std::fs::write(wt.join("new"), "untracked").unwrap();
+    let root = dir.path().join("trash");
+    let record = WorkspaceRecord::new(wt.clone(), SystemTime::now());
+
+    let entry = trash_workspace(&record, true, &root, SystemTime::now()).unwrap();
+    assert!(!wt.exists());
+    assert_eq!(entry.members[0].branch.as_deref(), Some("issue-1"));
+    assert_eq!(git_out(&repo, &["worktree", "list"]).lines().count(), 1);
+    assert!(git_out(&repo, &["branch", "--list", "issue-1"]).is_empty());
+
+    let restored = restore_trashed(&root, &entry.id).unwrap();
+    assert_eq!(restored.workspace.path, wt);
+    assert_eq!(
+        git_out(&wt, &["rev-parse", "--abbrev-ref", "HEAD"]),
+        "issue-1"
+    );
+    let status = git_out(&wt, &["status", "--porcelain"]);
+    assert!(status.contains("M  f") && status.contains("?? new"));
+    assert_eq!(git_out(&repo, &["worktree", "list"]).lines().count(), 2);
+    assert!(!root.join(&entry.id).exists());
+}
+
+#[test]
+fn test_restore_refuses_occupied_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, wt) = repo_with_worktree(dir.path());
+    let root = dir.path().join("trash");
+    let record = WorkspaceRecord::new(wt.clone(), SystemTime::now());
+    let entry = trash_workspace(&record, false, &root, SystemTime::now()).unwrap();
+    std::fs::create_dir(&wt).unwrap();
+    let err = restore_trashed(&root, &entry.id).unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{err}");
+    assert!(root.join(&entry.id).join("tree").exists());
+}
+
+#[test]
+fn test_empty_trash_deletes_only_expired_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("trash");
+    let now = SystemTime::now();
+    let day = Duration::from_hours(24);
+    for (name, age) in [("old", day * 10), ("new", day)] {
+        let path = dir.path().join(name);
+        std::fs::create_dir(&path).unwrap();
+        let record = WorkspaceRecord::new(path, now);
+        trash_workspace(&record, false, &root, now - age).unwrap();
+    }
+    let purged = empty_trash(&root, Some(DEFAULT_TRASH_RETENTION), now).unwrap();
+    assert_eq!(purged.len(), 1);
+    assert!(purged[0].id.starts_with("old-"));
+    assert_eq!(TrashEntry::list(&root).len(), 1);
+    assert_eq!(empty_trash(&root, None, now).unwrap().len(), 1);
+    assert!(TrashEntry::list(&root).is_empty());
+}
@@ -0,0 +1,49 @@
+use anyhow::{bail, Context, Result};
+
+use crate::git::{apply_patch, fetch_bundle, restore_worktree};
+
+use super::archive::Archive;
+
+/// Recreate the worktrees saved in the archive `id`.
+///
+/// Each bundle is fetched into its repository, the branch is checked out
+/// where it was, and the patch of uncommitted changes is reapplied. The
+/// archive is deleted once every member is back.
+///
+/// Returns the archive so the caller can re-register its workspace.
+///
+/// # Errors
+///
+/// Returns an error if the archive is missing, a worktree path is taken, a
+/// repository is gone, or git cannot restore a member.
+pub fn unarchive(id: &str) -> Result<Archive> {
+    let archive = Archive::load(id)?;
+    let dir = Archive::root()?.join(id);
+    for m in &archive.members {
+        if m.path.exists() {
+            bail!(
+                "{} already exists; move it out of the way first",
+                m.path.display()
+            );
+        }
+        if !m.repo.exists() {
+            bail!("repository {} no longer exists", m.repo.display());
+        }
+    }
+    for m in &archive.members {
+        if let Some(bundle) = &m.bundle {
+            fetch_bundle(&m.repo, &dir.join(bundle), &m.branch)?;
+        }
+        if let Some(parent) = m.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        restore_worktree(&m.repo, &m.path, &m.branch, Some(&m.head))?;
+        if let Some(patch) = &m.patch {
+            apply_patch(&m.path, &dir.join(patch))?;
+        }
+    }
+    std::fs::remove_dir_all(&dir)
+        .with_context(|| format!("Failed to remove archive {}", dir.display()))?;
+    Ok(archive)
+}
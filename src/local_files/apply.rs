@@ -0,0 +1,37 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::{copy::seed, resolve::resolve, Seeded};
+use crate::config::Config;
+use crate::issue::IssueRef;
+use crate::repo_hooks::RepoConfig;
+
+/// Seed the new worktree `wt` of `issue`, printing what was done. Failures
+/// are reported as warnings: the worktree itself is fine without the files.
+pub fn seed_new_worktree(issue: &IssueRef, wt: &Path) {
+    match seed_worktree(issue, wt) {
+        Ok(Some(seeded)) => eprintln!("{seeded}"),
+        Ok(None) => {}
+        Err(e) => eprintln!("warning: could not copy local files: {e:#}"),
+    }
+}
+
+/// Copy and symlink the local files configured for `issue`, in the global
+/// config and the worktree's `.worktree.toml`, into the worktree `wt`.
+///
+/// Returns `None` when no files are configured.
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be loaded, the source cannot be
+/// resolved or does not exist, or a file cannot be copied.
+pub fn seed_worktree(issue: &IssueRef, wt: &Path) -> Result<Option<Seeded>> {
+    let config = Config::load()?;
+    let repo = RepoConfig::load_from(wt).unwrap_or_default();
+    let files = config.files.local.merged(&repo.files);
+    if files.copy.is_empty() && files.symlink.is_empty() {
+        return Ok(None);
+    }
+    let from = resolve(files.source.as_ref(), issue, &config.files.primary)?;
+    seed(&files, &from, wt).map(Some)
+}
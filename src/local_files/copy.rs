@@ -0,0 +1,85 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Component, Path, PathBuf};
+
+use super::{LocalFiles, Seeded};
+use crate::lifecycle::copy_tree;
+
+/// Copy `files.copy` and symlink `files.symlink` from the directory `from`
+/// into `wt`, keeping their relative paths. Paths `wt` already has are left
+/// alone.
+///
+/// # Errors
+///
+/// Returns an error if `from` is not a directory, a pattern leaves it, or a
+/// file cannot be copied or linked.
+pub fn seed(files: &LocalFiles, from: &Path, wt: &Path) -> Result<Seeded> {
+    if !from.is_dir() {
+        bail!("Local files source {} does not exist", from.display());
+    }
+    let mut seeded = Seeded {
+        source: from.to_path_buf(),
+        ..Seeded::default()
+    };
+    for (patterns, link) in [(&files.copy, false), (&files.symlink, true)] {
+        for src in matches(from, patterns)? {
+            let dest = wt.join(src.strip_prefix(from)?);
+            if dest.symlink_metadata().is_ok() {
+                seeded.existing += 1;
+                continue;
+            }
+            if let Some(parent) = dest.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            place(&src, &dest, link)
+                .with_context(|| format!("Failed to add {}", dest.display()))?;
+            *if link {
+                &mut seeded.linked
+            } else {
+                &mut seeded.copied
+            } += 1;
+        }
+    }
+    Ok(seeded)
+}
+
+/// Every path under `root` matching one of `patterns`.
+fn matches(root: &Path, patterns: &[String]) -> Result<Vec<PathBuf>> {
+    let escaped = glob::Pattern::escape(&root.to_string_lossy());
+    let mut found = Vec::new();
+    for pattern in patterns {
+        if !Path::new(pattern)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+        {
+            bail!("Local files pattern `{pattern}` must stay inside the source");
+        }
+        let paths = glob::glob(&format!("{escaped}/{pattern}"))
+            .with_context(|| format!("Invalid local files pattern `{pattern}`"))?;
+        found.extend(paths.flatten());
+    }
+    Ok(found)
+}
+
+fn place(src: &Path, dest: &Path, link: bool) -> std::io::Result<()> {
+    #[cfg(unix)]
+    if link {
+        return std::os::unix::fs::symlink(src, dest);
+    }
+    #[cfg(windows)]
+    if link {
+        return if src.is_dir() {
+            std::os::windows::fs::symlink_dir(src, dest)
+        } else {
+            std::os::windows::fs::symlink_file(src, dest)
+        };
+    }
+    if src.is_dir() {
+        copy_tree(src, dest)
+    } else {
+        std::fs::copy(src, dest).map(drop)
+    }
+}
+
+#[cfg(test)]
+#[path = "copy_tests.rs"]
+mod tests;
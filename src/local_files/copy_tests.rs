@@ -0,0 +1,65 @@
+use super::*;
+
+fn files(copy: &[&str], symlink: &[&str]) -> LocalFiles {
+    LocalFiles {
+        source: None,
+        copy: copy.iter().map(|s| (*s).to_owned()).collect(),
+        symlink: symlink.iter().map(|s| (*s).to_owned()).collect(),
+    }
+}
+
+/// A source directory with `.env`, `config/local.yml`, `config/app.yml`, and
+/// `.idea/workspace.xml`, plus an empty worktree.
+fn fixture() -> (tempfile::TempDir, PathBuf, PathBuf) {
+    let tmp = tempfile::tempdir().unwrap();
+    let (src, wt) = (tmp.path().join("src"), tmp.path().join("wt"));
+    std::fs::create_dir_all(src.join("config")).unwrap();
+    std::fs::create_dir_all(src.join(".idea")).unwrap();
+    std::fs::create_dir(&wt).unwrap();
+    std::fs::write(src.join(".env"), "KEY=1").unwrap();
+    std::fs::write(src.join("config/local.yml"), "local").unwrap();
+    std::fs::write(src.join("config/app.yml"), "app").unwrap();
+    std::fs::write(src.join(".idea/workspace.xml"), "<x/>").unwrap();
+    (tmp, src, wt)
+}
+
+#[test]
+fn test_seed_copies_files_globs_and_directories() {
+    let (_tmp, src, wt) = fixture();
+    let seeded = seed(&files(&[".env", "config/*.yml", ".idea"], &[]), &src, &wt).unwrap();
+    assert_eq!((seeded.copied, seeded.linked, seeded.existing), (4, 0, 0));
+    assert_eq!(std::fs::read_to_string(wt.join(".env")).unwrap(), "KEY=1");
+    assert_eq!(
+        std::fs::read_to_string(wt.join("config/app.yml")).unwrap(),
+        "app"
+    );
+    assert!(wt.join(".idea/workspace.xml").is_file());
+    assert!(!wt.join(".env").is_symlink());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_seed_symlinks_and_keeps_existing_files() {
+    let (_tmp, src, wt) = fixture();
+    std::fs::write(wt.join(".env"), "mine").unwrap();
+    let seeded = seed(&files(&[".env"], &[".idea", "missing"]), &src, &wt).unwrap();
+    assert_eq!((seeded.copied, seeded.linked, seeded.existing), (0, 1, 1));
+    assert_eq!(std::fs::read_to_string(wt.join(".env")).unwrap(), "mine");
+    assert_eq!(
+        std::fs::read_link(wt.join(".idea")).unwrap(),
+        src.join(".idea")
+    );
+    assert!(
+        seeded.to_string().contains("(1 already present)"),
+        "{seeded}"
+    );
+}
+
+#[test]
+fn test_seed_rejects_missing_source_and_escaping_patterns() {
+    let (tmp, src, wt) = fixture();
+    let err = seed(&files(&[".env"], &[]), &tmp.path().join("nope"), &wt).unwrap_err();
+    assert!(err.to_string().contains("does not exist"), "{err}");
+    let err = seed(&files(&["../src/.env"], &[]), &src, &wt).unwrap_err();
+    assert!(err.to_string().contains("must stay inside"), "{err}");
+}
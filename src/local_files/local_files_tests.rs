@@ -0,0 +1,33 @@
+use super::*;
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| (*s).to_owned()).collect()
+}
+
+#[test]
+fn test_merged_combines_lists_and_prefers_repo_source() {
+    let global = LocalFiles {
+        source: Some(FileSource::Template),
+        copy: strings(&[".env", ".vscode"]),
+        symlink: strings(&["data"]),
+    };
+    let repo = LocalFiles {
+        source: Some(FileSource::Primary),
+        copy: strings(&[".env", "config/local.yml"]),
+        symlink: Vec::new(),
+    };
+    let merged = global.merged(&repo);
+    assert_eq!(merged.source, Some(FileSource::Primary));
+    assert_eq!(merged.copy, [".env", ".vscode", "config/local.yml"]);
+    assert_eq!(merged.symlink, ["data"]);
+}
+
+#[test]
+fn test_merged_keeps_global_source_when_repo_has_none() {
+    let global = LocalFiles {
+        source: Some(FileSource::Template),
+        ..LocalFiles::default()
+    };
+    let merged = global.merged(&LocalFiles::default());
+    assert_eq!(merged.source, Some(FileSource::Template));
+}
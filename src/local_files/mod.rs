@@ -0,0 +1,93 @@
+//! Local-only files copied or symlinked into new worktrees.
+//!
+//! `.env`, IDE settings, and other gitignored config are added right after a
+//! worktree is created, before `pre:open`.
+//!
+//! The lists come from the `[files]` table of the global config and of the
+//! repo's `.worktree.toml`; the two are combined, and the repo's `source`
+//! wins.
+//!
+//! ```toml
+//! [files]
+//! source = "primary"   # or "template", or "workspace:<REF|PATH>"
+//! copy = [".env", "config/local.yml"]
+//! symlink = [".idea"]
+//! ```
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+mod apply;
+mod copy;
+mod resolve;
+mod source;
+
+pub use apply::{seed_new_worktree, seed_worktree};
+pub use copy::seed;
+pub use resolve::template_dir;
+pub use source::FileSource;
+
+/// Files to copy or symlink into new worktrees.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocalFiles {
+    /// Where the files come from. When unset, the primary worktree if one is
+    /// known, otherwise the template directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<FileSource>,
+    /// Files or globs, relative to the source, copied into the worktree.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub copy: Vec<String>,
+    /// Files or globs, relative to the source, symlinked into the worktree.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub symlink: Vec<String>,
+}
+
+impl LocalFiles {
+    /// These settings with the repo's `repo` settings layered on top: the
+    /// lists are combined and the repo's `source` wins.
+    #[must_use]
+    pub fn merged(&self, repo: &Self) -> Self {
+        let join = |a: &[String], b: &[String]| {
+            let mut all = a.to_vec();
+            all.extend(b.iter().filter(|p| !a.contains(p)).cloned());
+            all
+        };
+        Self {
+            source: repo.source.clone().or_else(|| self.source.clone()),
+            copy: join(&self.copy, &repo.copy),
+            symlink: join(&self.symlink, &repo.symlink),
+        }
+    }
+}
+
+/// What seeding a worktree with local files did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Seeded {
+    /// The directory the files were taken from.
+    pub source: PathBuf,
+    /// Number of files and directories copied.
+    pub copied: usize,
+    /// Number of files and directories symlinked.
+    pub linked: usize,
+    /// Matches left alone because the worktree already has them.
+    pub existing: usize,
+}
+
+impl std::fmt::Display for Seeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (copied, linked) = (self.copied, self.linked);
+        write!(
+            f,
+            "Copied {copied} and symlinked {linked} local file(s) from {}",
+            self.source.display()
+        )?;
+        if self.existing > 0 {
+            write!(f, " ({} already present)", self.existing)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "local_files_tests.rs"]
+mod tests;
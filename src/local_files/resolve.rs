@@ -0,0 +1,74 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::FileSource;
+use crate::issue::IssueRef;
+
+/// The template directory for the repository `slug` (see
+/// [`IssueRef::repo_slug`]): `~/.config/worktree/templates/<owner>/<repo>/`.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn template_dir(slug: &str) -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    Ok(home
+        .join(".config")
+        .join("worktree")
+        .join("templates")
+        .join(slug))
+}
+
+/// The directory local files for `issue` are taken from, given `source` and
+/// the configured primary worktrees.
+///
+/// An unset `source` means the primary worktree if one is known, otherwise
+/// the template directory.
+pub(super) fn resolve(
+    source: Option<&FileSource>,
+    issue: &IssueRef,
+    primary: &BTreeMap<String, PathBuf>,
+) -> Result<PathBuf> {
+    let slug = issue.repo_slug();
+    let primary_dir = || {
+        primary
+            .get(&slug)
+            .map(|p| expand_home(p))
+            .or_else(|| match issue {
+                IssueRef::Local { project_path, .. } => Some(project_path.clone()),
+                _ => None,
+            })
+    };
+    match source {
+        None => primary_dir().map_or_else(|| template_dir(&slug), Ok),
+        Some(FileSource::Primary) => primary_dir().with_context(|| {
+            format!("No primary worktree for {slug}; set files.primary.\"{slug}\" in the config")
+        }),
+        Some(FileSource::Template) => template_dir(&slug),
+        Some(FileSource::Workspace(target)) => {
+            let path = expand_home(Path::new(target));
+            if path.is_dir() {
+                return Ok(path);
+            }
+            match IssueRef::parse(target) {
+                Ok(IssueRef::Adhoc { .. }) | Err(_) => {
+                    anyhow::bail!("Not a workspace path or issue reference: {target}")
+                }
+                Ok(other) => Ok(other.temp_path()),
+            }
+        }
+    }
+}
+
+/// `path` with a leading `~` replaced by the home directory.
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+#[path = "resolve_tests.rs"]
+mod tests;
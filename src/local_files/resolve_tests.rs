@@ -0,0 +1,60 @@
+use super::*;
+
+fn github() -> IssueRef {
+    IssueRef::GitHub {
+        owner: "acme".into(),
+        repo: "api".into(),
+        number: 7,
+    }
+}
+
+#[test]
+fn test_template_dir_is_keyed_by_slug() {
+    let dir = template_dir("acme/api").unwrap();
+    assert!(
+        dir.ends_with(".config/worktree/templates/acme/api"),
+        "{dir:?}"
+    );
+}
+
+#[test]
+fn test_primary_comes_from_config() {
+    let primary = BTreeMap::from([("acme/api".to_owned(), PathBuf::from("/src/api"))]);
+    let dir = resolve(Some(&FileSource::Primary), &github(), &primary).unwrap();
+    assert_eq!(dir, PathBuf::from("/src/api"));
+    assert_eq!(resolve(None, &github(), &primary).unwrap(), dir);
+}
+
+#[test]
+fn test_primary_of_local_issue_is_the_project() {
+    let issue = IssueRef::Local {
+        project_path: PathBuf::from("/src/proj"),
+        display_number: 1,
+    };
+    let dir = resolve(Some(&FileSource::Primary), &issue, &BTreeMap::new()).unwrap();
+    assert_eq!(dir, PathBuf::from("/src/proj"));
+}
+
+#[test]
+fn test_missing_primary_errors_or_falls_back_to_template() {
+    let err = resolve(Some(&FileSource::Primary), &github(), &BTreeMap::new()).unwrap_err();
+    assert!(err.to_string().contains("files.primary"), "{err}");
+    let dir = resolve(None, &github(), &BTreeMap::new()).unwrap();
+    assert_eq!(dir, template_dir("acme/api").unwrap());
+}
+
+#[test]
+fn test_workspace_source_accepts_path_or_reference() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().to_string_lossy().into_owned();
+    let source = FileSource::Workspace(path);
+    let dir = resolve(Some(&source), &github(), &BTreeMap::new()).unwrap();
+    assert_eq!(dir, tmp.path());
+
+    let source = FileSource::Workspace("acme/api#3".into());
+    let dir = resolve(Some(&source), &github(), &BTreeMap::new()).unwrap();
+    assert!(dir.ends_with("acme/api/issue-3"), "{dir:?}");
+
+    let source = FileSource::Workspace("acme/api".into());
+    assert!(resolve(Some(&source), &github(), &BTreeMap::new()).is_err());
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Where local files are taken from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum FileSource {
+    /// The repository's designated primary worktree (`files.primary` in the
+    /// config), or the project checkout for local issues.
+    Primary,
+    /// `~/.config/worktree/templates/<owner>/<repo>/`.
+    Template,
+    /// Another workspace, given as an issue reference or a path.
+    Workspace(String),
+}
+
+impl std::fmt::Display for FileSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Primary => f.write_str("primary"),
+            Self::Template => f.write_str("template"),
+            Self::Workspace(target) => write!(f, "workspace:{target}"),
+        }
+    }
+}
+
+impl std::str::FromStr for FileSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "primary" => Ok(Self::Primary),
+            "template" => Ok(Self::Template),
+            _ => match s.strip_prefix("workspace:").map(str::trim) {
+                Some(target) if !target.is_empty() => Ok(Self::Workspace(target.to_owned())),
+                _ => Err(format!(
+                    "unknown files source {s:?} (expected \"primary\", \"template\", or \"workspace:<REF|PATH>\")"
+                )),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for FileSource {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<FileSource> for String {
+    fn from(s: FileSource) -> Self {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "source_tests.rs"]
+mod tests;
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn test_parse_round_trips() {
+    for s in [
+        "primary",
+        "template",
+        "workspace:acme/api#12",
+        "workspace:~/wt/x",
+    ] {
+        let source: FileSource = s.parse().unwrap();
+        assert_eq!(source.to_string(), s);
+    }
+    assert_eq!(
+        "workspace: ../x".parse::<FileSource>().unwrap(),
+        FileSource::Workspace("../x".into())
+    );
+}
+
+#[test]
+fn test_parse_rejects_unknown() {
+    for bad in ["", "main", "workspace:", "workspace:  "] {
+        let err = bad.parse::<FileSource>().unwrap_err();
+        assert!(err.contains("unknown files source"), "{bad}: {err}");
+    }
+}
+
+#[test]
+fn test_deserializes_from_string() {
+    #[derive(serde::Deserialize)]
+    struct Wrap {
+        source: FileSource,
+    }
+    let w: Wrap = toml::from_str("source = \"template\"").unwrap();
+    assert_eq!(w.source, FileSource::Template);
+    assert!(toml::from_str::<Wrap>("source = \"elsewhere\"").is_err());
+}
@@ -8,52 +8,53 @@ use clap::Parser;
 mod cli;
 mod commands;
 use cli::{Cli, Commands};
+use commands::clean::cmd_clean;
 use commands::config::cmd_config;
+use commands::doctor::cmd_doctor;
+use commands::gc::cmd_gc;
+use commands::hibernate::cmd_hibernate;
+use commands::import::cmd_import;
 use commands::list::cmd_list;
+use commands::mv::cmd_mv;
 use commands::open::cmd_open;
 use commands::open_multi::cmd_open_multi;
+use commands::pin::cmd_pin;
 use commands::prune::cmd_prune;
+use commands::remove::cmd_remove;
 use commands::restore::cmd_restore;
+use commands::run::cmd_run;
 use commands::scheme::cmd_scheme;
 use commands::setup::cmd_setup;
+use commands::status::cmd_status;
+use commands::sync::cmd_sync;
+use commands::trash::{cmd_trash, cmd_undo};
+use commands::unarchive::cmd_unarchive;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
     match cli.command {
-        Commands::Open {
-            issue_ref,
-            editor,
-            no_hooks,
-            headless,
-            script,
-            env,
-            json,
-        } => {
-            let extra_env: Vec<(String, String)> = env
-                .iter()
-                .filter_map(|kv| {
-                    let mut parts = kv.splitn(2, '=');
-                    let k = parts.next()?.to_string();
-                    let v = parts.next()?.to_string();
-                    Some((k, v))
-                })
-                .collect();
-            cmd_open(
-                issue_ref.as_deref(),
-                editor,
-                no_hooks,
-                headless,
-                script.as_deref(),
-                extra_env,
-                json,
-            )?;
-        }
+        Commands::Open(args) => cmd_open(&args)?,
         Commands::OpenMulti { refs, no_hooks } => cmd_open_multi(&refs, no_hooks)?,
         Commands::Config { action } => cmd_config(action)?,
-        Commands::List { json } => cmd_list(json)?,
-        Commands::Prune { json } => cmd_prune(json)?,
-        Commands::Restore => cmd_restore()?,
+        Commands::List(args) => cmd_list(&args)?,
+        Commands::Status(args) => cmd_status(&args)?,
+        Commands::Sync(args) => cmd_sync(&args)?,
+        Commands::Prune(args) => cmd_prune(&args)?,
+        Commands::Remove(args) => cmd_remove(&args)?,
+        Commands::Run(args) => cmd_run(&args)?,
+        Commands::Mv(args) => cmd_mv(&args)?,
+        Commands::Pin(args) => cmd_pin(&args, true)?,
+        Commands::Unpin(args) => cmd_pin(&args, false)?,
+        Commands::Clean(args) => cmd_clean(&args)?,
+        Commands::Hibernate(args) => cmd_hibernate(&args)?,
+        Commands::Restore(args) => cmd_restore(&args)?,
+        Commands::Trash { action } => cmd_trash(action)?,
+        Commands::Undo => cmd_undo()?,
+        Commands::Unarchive(args) => cmd_unarchive(&args)?,
+        Commands::Import(args) => cmd_import(&args)?,
+        Commands::Gc(args) => cmd_gc(&args)?,
         Commands::Scheme { action } => cmd_scheme(action)?,
+        Commands::Doctor(args) => cmd_doctor(&args)?,
         Commands::Setup => cmd_setup()?,
         Commands::Version => println!("{}", env!("CARGO_PKG_VERSION")),
     }
@@ -0,0 +1,54 @@
+//! Multi-repo workspace creation: one unified folder across several repos.
+use anyhow::{Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    issue::IssueRef,
+    name_gen,
+    ttl::{WorkspaceKind, WorkspaceRecord, WorkspaceRegistry},
+};
+
+mod open;
+
+/// A spec for one repo in a multi-workspace.
+pub enum MultiSpec {
+    /// Repo paired with an issue — creates a branch, folder is `<repo>-<id>`.
+    WithIssue(IssueRef),
+    /// Bare repo slug — checked out on its default branch, folder is `<repo>`.
+    BareRepo {
+        /// GitHub owner (org or user).
+        owner: String,
+        /// Repository name.
+        repo: String,
+    },
+}
+
+/// Create a unified workspace under `workspaces_root`, one sub-dir per spec.
+/// Registers the root and its member repos with `WorkspaceRegistry`.
+/// # Errors
+/// Returns an error if any directory, clone, fetch, or worktree step fails.
+// LLVM_COV_EXCL_START
+pub fn create_multi_workspace(specs: &[MultiSpec], workspaces_root: &Path) -> Result<PathBuf> {
+    let root = workspaces_root.join(name_gen::generate_name());
+    fs::create_dir_all(&root)
+        .with_context(|| format!("failed to create workspace root {}", root.display()))?;
+    let members = specs
+        .iter()
+        .map(|spec| open::open_one(spec, &root))
+        .collect::<Result<Vec<_>>>()?;
+    if let Ok(mut registry) = WorkspaceRegistry::load() {
+        let now = SystemTime::now();
+        let mut record = WorkspaceRecord::new(root.clone(), now);
+        record.kind = WorkspaceKind::Multi;
+        record.last_opened_at = Some(now);
+        record.members = members;
+        registry.insert(record);
+        let _ = registry.save();
+    }
+    Ok(root)
+}
+// LLVM_COV_EXCL_STOP
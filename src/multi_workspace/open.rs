@@ -1,52 +1,15 @@
-//! Multi-repo workspace creation: one unified folder across several repos.
 use anyhow::{Context, Result};
-use std::{
-    fs,
-    path::{Path, PathBuf},
-};
+use std::path::{Path, PathBuf};
 
-use crate::{
-    git::{
-        bare_clone, branch_exists_remote, create_worktree, detect_default_branch, git_fetch,
-        git_worktree_prune,
-    },
-    issue::IssueRef,
-    name_gen,
-    ttl::WorkspaceRegistry,
+use super::MultiSpec;
+use crate::git::{
+    bare_clone, branch_exists_remote, create_worktree, detect_default_branch, git_fetch,
+    git_worktree_prune,
 };
+use crate::issue::IssueRef;
+use crate::ttl::MemberRecord;
 
-/// A spec for one repo in a multi-workspace.
-pub enum MultiSpec {
-    /// Repo paired with an issue — creates a branch, folder is `<repo>-<id>`.
-    WithIssue(IssueRef),
-    /// Bare repo slug — checked out on its default branch, folder is `<repo>`.
-    BareRepo {
-        /// GitHub owner (org or user).
-        owner: String,
-        /// Repository name.
-        repo: String,
-    },
-}
-
-/// Create a unified workspace under `workspaces_root`, one sub-dir per spec.
-/// Registers the root with `WorkspaceRegistry` for TTL-based pruning.
-/// # Errors
-/// Returns an error if any directory, clone, fetch, or worktree step fails.
 // LLVM_COV_EXCL_START
-pub fn create_multi_workspace(specs: &[MultiSpec], workspaces_root: &Path) -> Result<PathBuf> {
-    let root = workspaces_root.join(name_gen::generate_name());
-    fs::create_dir_all(&root)
-        .with_context(|| format!("failed to create workspace root {}", root.display()))?;
-    for spec in specs {
-        open_one(spec, &root)?;
-    }
-    if let Ok(mut registry) = WorkspaceRegistry::load() {
-        registry.register(root.clone());
-        let _ = registry.save();
-    }
-    Ok(root)
-}
-
 fn github_bare_path(owner: &str, repo: &str) -> Result<PathBuf> {
     Ok(dirs::home_dir()
         .context("could not determine home directory")?
@@ -56,14 +19,15 @@ fn github_bare_path(owner: &str, repo: &str) -> Result<PathBuf> {
         .join(repo))
 }
 
-fn open_one(spec: &MultiSpec, root: &Path) -> Result<()> {
+/// Check out one spec under `root` and describe the resulting member.
+pub(super) fn open_one(spec: &MultiSpec, root: &Path) -> Result<MemberRecord> {
     match spec {
         MultiSpec::WithIssue(issue) => open_one_issue(issue, root),
         MultiSpec::BareRepo { owner, repo } => open_one_bare(owner, repo, root),
     }
 }
 
-fn open_one_issue(issue: &IssueRef, root: &Path) -> Result<()> {
+fn open_one_issue(issue: &IssueRef, root: &Path) -> Result<MemberRecord> {
     let bare_path = issue.bare_clone_path();
     if bare_path.exists() {
         eprintln!("Fetching origin for {}…", bare_path.display());
@@ -78,10 +42,20 @@ fn open_one_issue(issue: &IssueRef, root: &Path) -> Result<()> {
     let _ = git_worktree_prune(&bare_path);
     let dest = root.join(issue.multi_dir_name());
     create_worktree(&bare_path, &dest, &branch, &base_branch, branch_exists)
-        .with_context(|| format!("failed to create worktree at {}", dest.display()))
+        .with_context(|| format!("failed to create worktree at {}", dest.display()))?;
+    crate::local_files::seed_new_worktree(issue, &dest);
+    let base_ref = (!branch_exists).then(|| format!("origin/{base_branch}"));
+    crate::cache::prepare_new_worktree(issue, &dest, base_ref.as_deref());
+    Ok(MemberRecord {
+        path: dest,
+        branch: Some(branch),
+        base_ref,
+        bare_path: Some(bare_path),
+        issue: Some(issue.clone()),
+    })
 }
 
-fn open_one_bare(owner: &str, repo: &str, root: &Path) -> Result<()> {
+fn open_one_bare(owner: &str, repo: &str, root: &Path) -> Result<MemberRecord> {
     let bare_path = github_bare_path(owner, repo)?;
     let url = format!("https://github.com/{owner}/{repo}.git");
     if bare_path.exists() {
@@ -95,6 +69,13 @@ fn open_one_bare(owner: &str, repo: &str, root: &Path) -> Result<()> {
     let _ = git_worktree_prune(&bare_path);
     let dest = root.join(repo);
     create_worktree(&bare_path, &dest, &branch, &branch, true)
-        .with_context(|| format!("failed to create worktree at {}", dest.display()))
+        .with_context(|| format!("failed to create worktree at {}", dest.display()))?;
+    Ok(MemberRecord {
+        path: dest,
+        branch: Some(branch),
+        base_ref: None,
+        bare_path: Some(bare_path),
+        issue: None,
+    })
 }
 // LLVM_COV_EXCL_STOP
@@ -18,6 +18,7 @@ fn macos_app_exists(app_name: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(target_os = "macos")]
     use super::*;
 
     #[test]
@@ -84,12 +84,12 @@ pub fn open_in_editor(path: &Path, command: &str, background: bool) -> Result<()
         .with_context(|| format!("Failed to open editor with command: {cmd_str}"))
 }
 
-/// Open `path` with `cmd`; uses terminal-specific logic if `cmd` is a known terminal,
-/// otherwise opens as an editor.
+/// Open `path` with `cmd`, running `init` inside when `cmd` is a known
+/// terminal; otherwise opens it as an editor.
 /// # Errors
 /// Returns an error if the spawn or editor command fails.
-pub fn open_editor_or_terminal(path: &Path, cmd: &str, background: bool) -> Result<()> {
-    if !terminal::try_terminal_with_init(path, cmd, "")? {
+pub fn open_editor_or_terminal(path: &Path, cmd: &str, init: &str, background: bool) -> Result<()> {
+    if !terminal::try_terminal_with_init(path, cmd, init)? {
         open_in_editor(path, cmd, background)?;
     }
     Ok(())
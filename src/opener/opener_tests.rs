@@ -28,5 +28,5 @@ fn test_open_in_editor_background() {
 #[test]
 fn test_open_editor_or_terminal_ide() {
     let p = std::path::Path::new("/tmp/myproject");
-    open_editor_or_terminal(p, "echo .", false).unwrap();
+    open_editor_or_terminal(p, "echo .", "", false).unwrap();
 }
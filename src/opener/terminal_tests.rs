@@ -17,5 +17,5 @@ fn test_tmux_returns_true() {
 #[test]
 fn test_open_editor_or_terminal_tmux() {
     let p = std::path::Path::new("/tmp");
-    super::super::open_editor_or_terminal(p, "tmux", false).unwrap();
+    super::super::open_editor_or_terminal(p, "tmux", "", false).unwrap();
 }
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::PortsConfig;
+use crate::ttl::WorkspaceRegistry;
+
+/// The lowest block start of the form `cfg.base + k * cfg.block` whose
+/// block overlaps none of the blocks starting at `taken`.
+/// `None` when no block fits below port 65536.
+#[must_use]
+pub fn allocate(taken: &[u16], cfg: &PortsConfig) -> Option<u16> {
+    let block = u32::from(cfg.block.max(1));
+    let free = |start: u32| {
+        taken
+            .iter()
+            .all(|&t| u32::from(t) + block <= start || start + block <= u32::from(t))
+    };
+    (u32::from(cfg.base)..)
+        .step_by(block as usize)
+        .take_while(|start| start + block <= 1 << 16)
+        .find(|&start| free(start))
+        .and_then(|start| u16::try_from(start).ok())
+}
+
+/// The port block of the workspace registered at `wt`, allocating one when it
+/// has none or its block now overlaps another workspace's. `None` when `wt`
+/// is not registered.
+///
+/// # Errors
+///
+/// Returns an error if the registry cannot be loaded or saved, or no free
+/// block is left.
+pub fn assign_port(wt: &Path, cfg: &PortsConfig) -> Result<Option<u16>> {
+    let mut registry = WorkspaceRegistry::load()?;
+    // Match `wt` the way the registry does, so a symlinked path is not
+    // mistaken for another workspace.
+    let Some(own) = registry.get(wt).map(|r| r.path.clone()) else {
+        return Ok(None);
+    };
+    let taken: Vec<u16> = registry
+        .workspace
+        .iter()
+        .filter(|r| r.path != own && r.trashed_at.is_none())
+        .filter_map(|r| r.port)
+        .collect();
+    let Some(record) = registry.get_mut(&own) else {
+        return Ok(None);
+    };
+    if let Some(port) = record.port {
+        if allocate(&taken, &PortsConfig { base: port, ..*cfg }) == Some(port) {
+            return Ok(Some(port));
+        }
+    }
+    let Some(port) = allocate(&taken, cfg) else {
+        anyhow::bail!(
+            "No free block of {} port(s) left from port {}",
+            cfg.block,
+            cfg.base
+        );
+    };
+    record.port = Some(port);
+    registry.save()?;
+    Ok(Some(port))
+}
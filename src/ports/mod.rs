@@ -0,0 +1,54 @@
+//! Port blocks allocated to workspaces.
+//!
+//! Every workspace gets its own block of `block` ports starting at `base` or
+//! above, so dev servers of several worktrees of the same app don't collide.
+//! The first port of the block is kept on the workspace's registry entry,
+//! stays the same across opens, and is freed when the workspace is removed or
+//! moved to the trash. Hooks see it as `WORKTREE_PORT` and `{{port}}`.
+//!
+//! ```toml
+//! [ports]
+//! base = 4000   # first port handed out
+//! block = 10    # ports per workspace
+//! ```
+use serde::{Deserialize, Serialize};
+
+mod assign;
+
+pub use assign::{allocate, assign_port};
+
+/// Default first port handed out, clear of the usual dev server ports.
+pub const DEFAULT_BASE: u16 = 4000;
+/// Default number of ports in each workspace's block.
+pub const DEFAULT_BLOCK: u16 = 10;
+
+/// Where port blocks start and how large they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PortsConfig {
+    /// First port handed out.
+    pub base: u16,
+    /// Number of ports in each workspace's block.
+    pub block: u16,
+}
+
+impl Default for PortsConfig {
+    fn default() -> Self {
+        Self {
+            base: DEFAULT_BASE,
+            block: DEFAULT_BLOCK,
+        }
+    }
+}
+
+impl PortsConfig {
+    /// `true` when these are the default settings.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[cfg(test)]
+#[path = "ports_tests.rs"]
+mod tests;
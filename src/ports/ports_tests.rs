@@ -0,0 +1,40 @@
+use super::*;
+
+const CFG: PortsConfig = PortsConfig {
+    base: 4000,
+    block: 10,
+};
+
+#[test]
+fn allocates_the_lowest_free_block() {
+    assert_eq!(allocate(&[], &CFG), Some(4000));
+    assert_eq!(allocate(&[4000, 4010], &CFG), Some(4020));
+    assert_eq!(allocate(&[4010], &CFG), Some(4000));
+}
+
+#[test]
+fn skips_blocks_overlapping_other_sizes() {
+    assert_eq!(allocate(&[4005], &CFG), Some(4020));
+    assert_eq!(allocate(&[3995], &CFG), Some(4010));
+}
+
+#[test]
+fn stops_at_the_top_of_the_port_range() {
+    let cfg = PortsConfig {
+        base: 65_520,
+        block: 10,
+    };
+    assert_eq!(allocate(&[], &cfg), Some(65_520));
+    assert_eq!(allocate(&[65_520], &cfg), None);
+    let zero = PortsConfig { base: 80, block: 0 };
+    assert_eq!(allocate(&[80], &zero), Some(81));
+}
+
+#[test]
+fn missing_settings_fall_back_to_defaults() {
+    assert!(PortsConfig::default().is_default());
+    let cfg: PortsConfig = toml::from_str("block = 20").unwrap();
+    assert_eq!(cfg.base, DEFAULT_BASE);
+    assert!(!cfg.is_default());
+    assert!(toml::from_str::<PortsConfig>("size = 1").is_err());
+}
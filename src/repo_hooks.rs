@@ -1,5 +1,11 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
+#[path = "repo_hooks_combine.rs"]
+mod combine;
+
+pub use combine::combined_script;
+
 /// Declares how a per-repo hook relates to the matching global hook.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum HookOrder {
@@ -12,8 +18,7 @@ pub enum HookOrder {
     Replace,
 }
 
-/// A single per-repo hook with its script and ordering relationship to the
-/// global hook.
+/// A per-repo hook script and how it is ordered against the global hook.
 #[derive(Debug, Clone)]
 pub struct RepoHookEntry {
     /// The hook script. Supports the same Mustache-style placeholders as
@@ -33,23 +38,30 @@ pub struct RepoHooksConfig {
     pub post_open: Option<RepoHookEntry>,
 }
 
-/// Per-repository configuration loaded from `.worktree.toml` in the worktree
-/// root.
-///
-/// The file is version-controlled alongside the repo so that every developer
-/// who uses `worktree-io` gets the same lifecycle hooks automatically.
+/// Per-repository configuration loaded from a worktree's `.worktree.toml`,
+/// which is committed so every developer gets the same settings.
 #[derive(Debug, Clone, Default)]
 pub struct RepoConfig {
     /// Lifecycle hooks scoped to this repository.
     pub hooks: RepoHooksConfig,
+    /// Time-to-live for workspaces of this repository (`ttl = "30days"`).
+    pub ttl: Option<crate::ttl::Ttl>,
+    /// Extra build artifact globs for `worktree clean` (`clean = ["dist"]`).
+    pub clean: Vec<String>,
+    /// Local files copied or symlinked into new worktrees (`[files]`).
+    pub files: crate::local_files::LocalFiles,
+    /// Dependency and build caches shared across worktrees (`[cache]`).
+    pub cache: crate::cache::CacheConfig,
+    /// Variables set in every workspace of the repo (`[env]`); values may
+    /// use the hook placeholders, e.g. `{{issue}}`.
+    pub env: BTreeMap<String, String>,
 }
 
 impl RepoConfig {
     /// Load `.worktree.toml` from `worktree_path`.
     ///
-    /// Returns `None` when the file is missing. When the file exists but
-    /// cannot be parsed, prints a warning to stderr and also returns `None`
-    /// so the caller falls back to global-only behavior.
+    /// Returns `None` when the file is missing, or when it cannot be parsed
+    /// after printing a warning, so the caller falls back to global settings.
     #[must_use]
     pub fn load_from(worktree_path: &Path) -> Option<Self> {
         let path = worktree_path.join(".worktree.toml");
@@ -64,37 +76,6 @@ impl RepoConfig {
     }
 }
 
-/// Combine a global hook script with an optional per-repo hook entry into a
-/// single effective script.
-///
-/// | global  | repo entry | result                              |
-/// |---------|------------|-------------------------------------|
-/// | `None`  | `None`     | `None`                              |
-/// | `Some`  | `None`     | global only                         |
-/// | `None`  | `Some`     | repo script only                    |
-/// | `Some`  | `Some`     | ordered per `entry.order`           |
-///
-/// When both sides are present the ordering rules apply:
-/// * `before` — repo script, newline, global script
-/// * `after`  — global script, newline, repo script
-/// * `replace` — repo script only (global is suppressed)
-#[must_use]
-pub fn combined_script(global: Option<&str>, repo_entry: Option<&RepoHookEntry>) -> Option<String> {
-    match (global, repo_entry) {
-        (None, None) => None,
-        (Some(g), None) => Some(g.to_owned()),
-        (None, Some(r)) => Some(r.script.clone()),
-        (Some(g), Some(r)) => Some(match r.order {
-            HookOrder::Before => format!("{}\n{}", r.script, g),
-            HookOrder::After => format!("{}\n{}", g, r.script),
-            HookOrder::Replace => r.script.clone(),
-        }),
-    }
-}
-
 #[cfg(test)]
 #[path = "repo_hooks_load_tests.rs"]
 mod load_tests;
-#[cfg(test)]
-#[path = "repo_hooks_tests.rs"]
-mod tests;
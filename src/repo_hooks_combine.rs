@@ -0,0 +1,30 @@
+use super::{HookOrder, RepoHookEntry};
+
+/// Combine a global hook script with an optional per-repo hook entry into a
+/// single effective script.
+///
+/// | global  | repo entry | result                              |
+/// |---------|------------|-------------------------------------|
+/// | `None`  | `None`     | `None`                              |
+/// | `Some`  | `None`     | global only                         |
+/// | `None`  | `Some`     | repo script only                    |
+/// | `Some`  | `Some`     | ordered per `entry.order`           |
+///
+/// See [`HookOrder`] for how each order joins the two scripts.
+#[must_use]
+pub fn combined_script(global: Option<&str>, repo_entry: Option<&RepoHookEntry>) -> Option<String> {
+    match (global, repo_entry) {
+        (None, None) => None,
+        (Some(g), None) => Some(g.to_owned()),
+        (None, Some(r)) => Some(r.script.clone()),
+        (Some(g), Some(r)) => Some(match r.order {
+            HookOrder::Before => format!("{}\n{}", r.script, g),
+            HookOrder::After => format!("{}\n{}", g, r.script),
+            HookOrder::Replace => r.script.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+#[path = "repo_hooks_tests.rs"]
+mod tests;
@@ -73,3 +73,14 @@ fn test_load_from_replace_order() {
     let cfg = RepoConfig::load_from(dir.path()).unwrap();
     assert_eq!(cfg.hooks.pre_open.unwrap().order, HookOrder::Replace);
 }
+
+#[test]
+fn test_load_from_ttl() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join(".worktree.toml");
+    std::fs::write(&file, b"ttl = \"30days\"\n").unwrap();
+    let cfg = RepoConfig::load_from(dir.path()).unwrap();
+    assert_eq!(cfg.ttl.unwrap().to_string(), "30days");
+    std::fs::write(&file, b"ttl = \"soon\"\n").unwrap();
+    assert!(RepoConfig::load_from(dir.path()).is_none());
+}
@@ -3,9 +3,14 @@
 //! The user-facing schema uses flat keys named after each hook. Hook scripts
 //! and their optional `order` siblings may sit either at the document root or
 //! inside an explicit `[hooks]` table. Both layouts produce the same
-//! [`RepoConfig`].
+//! [`RepoConfig`]. A root-level `ttl` sets the workspace TTL for the repo,
+//! `clean` lists extra build artifacts for `worktree clean`, `[files]` lists
+//! local files to copy into new worktrees, `[cache]` shared caches, and
+//! `[env]` variables for every workspace.
 //!
 //! ```toml
+//! ttl = "30days"
+//! clean = ["dist", "**/.cache"]
 //! "pre:open" = "cargo build"
 //! "pre:open:order" = "before"   # optional; defaults to "before"
 //!
@@ -15,12 +20,20 @@
 
 use crate::repo_hooks::{HookOrder, RepoConfig, RepoHookEntry, RepoHooksConfig};
 
+#[path = "repo_hooks_tables.rs"]
+mod tables;
+#[path = "repo_hooks_values.rs"]
+mod values;
+
 /// Parse a `.worktree.toml` body into a [`RepoConfig`].
 ///
 /// # Errors
 ///
 /// Returns an error string when the document is not valid TOML, when a hook
-/// script is not a string, or when an `order` value is missing or unknown.
+/// script is not a string, when an `order` value is missing or unknown, when
+/// `ttl` is not a valid duration, when `clean` or a `[files]` list is not a
+/// list of relative glob patterns, or when `files.source`, `[cache]`, or
+/// `[env]` is invalid.
 pub fn parse(contents: &str) -> Result<RepoConfig, String> {
     let table: toml::Table = toml::from_str(contents).map_err(|e| e.to_string())?;
     let mut hooks = RepoHooksConfig::default();
@@ -31,7 +44,14 @@ pub fn parse(contents: &str) -> Result<RepoConfig, String> {
             .ok_or_else(|| "`hooks` must be a TOML table".to_owned())?;
         take_hooks(h, &mut hooks)?;
     }
-    Ok(RepoConfig { hooks })
+    Ok(RepoConfig {
+        hooks,
+        ttl: table.get("ttl").map(values::parse_ttl).transpose()?,
+        clean: values::optional(&table, "clean", values::parse_clean)?,
+        files: values::optional(&table, "files", values::parse_files)?,
+        cache: values::optional(&table, "cache", tables::parse_cache)?,
+        env: values::optional(&table, "env", tables::parse_env)?,
+    })
 }
 
 fn take_hooks(table: &toml::Table, out: &mut RepoHooksConfig) -> Result<(), String> {
@@ -58,7 +78,7 @@ fn extract_hook(table: &toml::Table, name: &str) -> Result<Option<RepoHookEntry>
             let s = v
                 .as_str()
                 .ok_or_else(|| format!("`{order_key}` must be a string"))?;
-            parse_order(s).ok_or_else(|| {
+            values::parse_order(s).ok_or_else(|| {
                 format!("invalid `{order_key}` value `{s}` (expected before/after/replace)")
             })?
         }
@@ -69,15 +89,6 @@ fn extract_hook(table: &toml::Table, name: &str) -> Result<Option<RepoHookEntry>
     }))
 }
 
-fn parse_order(s: &str) -> Option<HookOrder> {
-    match s {
-        "before" => Some(HookOrder::Before),
-        "after" => Some(HookOrder::After),
-        "replace" => Some(HookOrder::Replace),
-        _ => None,
-    }
-}
-
 #[cfg(test)]
 #[path = "repo_hooks_parse_tests.rs"]
 mod tests;
@@ -0,0 +1,26 @@
+use serde::Deserialize as _;
+use std::collections::BTreeMap;
+
+use crate::cache::CacheConfig;
+use crate::workspace_env::is_var_name;
+
+/// Parse the `[cache]` table; see [`CacheConfig`].
+pub(super) fn parse_cache(v: &toml::Value) -> Result<CacheConfig, String> {
+    let cache = CacheConfig::deserialize(v.clone()).map_err(|e| format!("invalid `cache`: {e}"))?;
+    cache.validate()?;
+    Ok(cache)
+}
+
+/// Parse the `[env]` table of variable names and templated string values.
+pub(super) fn parse_env(v: &toml::Value) -> Result<BTreeMap<String, String>, String> {
+    let env = BTreeMap::<String, String>::deserialize(v.clone())
+        .map_err(|e| format!("invalid `env`: {e}"))?;
+    if let Some(k) = env.keys().find(|k| !is_var_name(k)) {
+        return Err(format!("`env` key `{k}` is not a valid variable name"));
+    }
+    Ok(env)
+}
+
+#[cfg(test)]
+#[path = "repo_hooks_tables_tests.rs"]
+mod tests;
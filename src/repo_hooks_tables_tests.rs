@@ -0,0 +1,32 @@
+use crate::repo_hooks_parse::parse;
+
+#[test]
+fn parse_cache_table() {
+    let cfg = parse(
+        "[cache]\nsibling = [\"node_modules\"]\n[cache.env]\nCARGO_TARGET_DIR = \"target/{{base}}\"\n",
+    )
+    .unwrap();
+    assert_eq!(cfg.cache.sibling, ["node_modules"]);
+    assert_eq!(cfg.cache.env["CARGO_TARGET_DIR"], "target/{{base}}");
+    for (body, needle) in [
+        ("[cache]\nshare = []\n", "unknown field"),
+        ("[cache.link]\n\"../x\" = \"x\"\n", "must stay inside"),
+    ] {
+        let err = parse(body).unwrap_err();
+        assert!(err.contains(needle), "{body}: {err}");
+    }
+}
+
+#[test]
+fn parse_env_table() {
+    let cfg = parse("[env]\nDATABASE_URL = \"postgres://localhost/{{branch}}\"\n").unwrap();
+    assert_eq!(cfg.env["DATABASE_URL"], "postgres://localhost/{{branch}}");
+    for (body, needle) in [
+        ("[env]\n\"1X\" = \"a\"\n", "not a valid variable name"),
+        ("[env]\nX = 1\n", "invalid `env`"),
+        ("env = \"X\"\n", "invalid `env`"),
+    ] {
+        let err = parse(body).unwrap_err();
+        assert!(err.contains(needle), "{body}: {err}");
+    }
+}
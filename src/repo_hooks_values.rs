@@ -0,0 +1,94 @@
+use crate::local_files::LocalFiles;
+use crate::repo_hooks::HookOrder;
+use crate::ttl::Ttl;
+
+/// Parse `key` of `table` with `parse`, or the default when it is absent.
+pub(super) fn optional<T: Default>(
+    table: &toml::Table,
+    key: &str,
+    parse: fn(&toml::Value) -> Result<T, String>,
+) -> Result<T, String> {
+    table
+        .get(key)
+        .map(parse)
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Parse the root-level `ttl` value.
+pub(super) fn parse_ttl(v: &toml::Value) -> Result<Ttl, String> {
+    let s = v
+        .as_str()
+        .ok_or_else(|| "`ttl` must be a string".to_owned())?;
+    s.parse()
+        .map_err(|e| format!("invalid `ttl` value `{s}`: {e}"))
+}
+
+/// Parse the root-level `clean` list.
+pub(super) fn parse_clean(v: &toml::Value) -> Result<Vec<String>, String> {
+    parse_globs("clean", v)
+}
+
+/// Parse the `[files]` table: an optional `source` plus `copy` and `symlink`
+/// glob lists.
+pub(super) fn parse_files(v: &toml::Value) -> Result<LocalFiles, String> {
+    let table = v
+        .as_table()
+        .ok_or_else(|| "`files` must be a TOML table".to_owned())?;
+    let source = table
+        .get("source")
+        .map(|s| {
+            s.as_str()
+                .ok_or_else(|| "`files.source` must be a string".to_owned())?
+                .parse()
+        })
+        .transpose()?;
+    let list = |key: &str| {
+        table.get(key).map_or_else(
+            || Ok(Vec::new()),
+            |v| parse_globs(&format!("files.{key}"), v),
+        )
+    };
+    Ok(LocalFiles {
+        source,
+        copy: list("copy")?,
+        symlink: list("symlink")?,
+    })
+}
+
+/// Parse the glob list under `key`. Each entry must be a valid glob that
+/// stays inside the worktree: neither absolute nor climbing out with `..`.
+fn parse_globs(key: &str, v: &toml::Value) -> Result<Vec<String>, String> {
+    let list = v
+        .as_array()
+        .ok_or_else(|| format!("`{key}` must be a list of glob patterns"))?;
+    list.iter()
+        .map(|p| {
+            let p = p
+                .as_str()
+                .ok_or_else(|| format!("`{key}` entries must be strings"))?;
+            let path = std::path::Path::new(p);
+            if path.is_absolute() || path.components().any(|c| c.as_os_str() == "..") {
+                return Err(format!(
+                    "`{key}` pattern `{p}` must stay inside the worktree"
+                ));
+            }
+            glob::Pattern::new(p).map_err(|e| format!("invalid `{key}` pattern `{p}`: {e}"))?;
+            Ok(p.to_owned())
+        })
+        .collect()
+}
+
+/// Parse a hook `order` value.
+pub(super) fn parse_order(s: &str) -> Option<HookOrder> {
+    match s {
+        "before" => Some(HookOrder::Before),
+        "after" => Some(HookOrder::After),
+        "replace" => Some(HookOrder::Replace),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "repo_hooks_values_tests.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+use crate::repo_hooks_parse::parse;
+
+#[test]
+fn parse_clean_patterns() {
+    let cfg = parse("clean = [\"dist\", \"**/.cache\"]\n").unwrap();
+    assert_eq!(cfg.clean, ["dist", "**/.cache"]);
+    assert!(parse("").unwrap().clean.is_empty());
+}
+
+#[test]
+fn parse_clean_rejects_non_list() {
+    let err = parse("clean = \"dist\"\n").unwrap_err();
+    assert!(err.contains("list"), "{err}");
+}
+
+#[test]
+fn parse_clean_rejects_escaping_patterns() {
+    for bad in ["/tmp", "../sibling", "a/../../b"] {
+        let err = parse(&format!("clean = [\"{bad}\"]\n")).unwrap_err();
+        assert!(err.contains("inside the worktree"), "{bad}: {err}");
+    }
+}
+
+#[test]
+fn parse_clean_rejects_invalid_glob() {
+    let err = parse("clean = [\"[a\"]\n").unwrap_err();
+    assert!(err.contains("invalid `clean` pattern"), "{err}");
+}
+
+#[test]
+fn parse_files_table() {
+    let cfg = parse(
+        "[files]\nsource = \"template\"\ncopy = [\".env\", \"config/*.yml\"]\nsymlink = [\".idea\"]\n",
+    )
+    .unwrap();
+    assert_eq!(
+        cfg.files.source,
+        Some(crate::local_files::FileSource::Template)
+    );
+    assert_eq!(cfg.files.copy, [".env", "config/*.yml"]);
+    assert_eq!(cfg.files.symlink, [".idea"]);
+    assert_eq!(
+        parse("").unwrap().files,
+        crate::local_files::LocalFiles::default()
+    );
+}
+
+#[test]
+fn parse_files_rejects_bad_values() {
+    for (body, needle) in [
+        ("files = [\".env\"]\n", "`files` must be a TOML table"),
+        ("[files]\nsource = \"elsewhere\"\n", "unknown files source"),
+        ("[files]\ncopy = \".env\"\n", "`files.copy` must be a list"),
+        (
+            "[files]\nsymlink = [\"../up\"]\n",
+            "`files.symlink` pattern",
+        ),
+    ] {
+        let err = parse(body).unwrap_err();
+        assert!(err.contains(needle), "{body}: {err}");
+    }
+}
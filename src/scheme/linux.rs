@@ -70,6 +70,7 @@ pub fn uninstall() -> Result<()> {
     Ok(())
 }
 
+#[allow(clippy::unnecessary_wraps, reason = "same signature on every OS")]
 pub fn status() -> Result<SchemeStatus> {
     let path = desktop_file();
     if path.exists() {
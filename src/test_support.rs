@@ -0,0 +1,82 @@
+//! Git fixtures shared by the unit tests, mirroring `tests/common`.
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Run git in `dir` and assert that it succeeds.
+pub fn git_in(dir: &Path, args: &[&str]) {
+    git_out(dir, args);
+}
+
+/// Run git in `dir`, assert that it succeeds, and return its trimmed stdout.
+pub fn git_out(dir: &Path, args: &[&str]) -> String {
+    let out = git_cmd(dir, args).output().unwrap();
+    assert!(
+        out.status.success(),
+        "git {args:?}: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_owned()
+}
+
+fn git_cmd(dir: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C")
+        .arg(dir)
+        .args(args)
+        // Unset inherited git env vars so `-C dir` is honoured even inside
+        // a git worktree hook, where GIT_DIR would otherwise override it.
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .env_remove("GIT_INDEX_FILE")
+        .env("GIT_AUTHOR_NAME", "T")
+        .env("GIT_AUTHOR_EMAIL", "t@t.com")
+        .env("GIT_COMMITTER_NAME", "T")
+        .env("GIT_COMMITTER_EMAIL", "t@t.com");
+    cmd
+}
+
+/// Create `<dir>/repo` with one commit on `main` that adds the file `f`.
+pub fn init_repo(dir: &Path) -> PathBuf {
+    let repo = dir.join("repo");
+    std::fs::create_dir_all(&repo).unwrap();
+    git_in(&repo, &["init", "-q", "-b", "main"]);
+    commit_file_as(&repo, "f", "x", "init");
+    repo
+}
+
+/// Create `<dir>/src` as [`init_repo`] does and bare-clone it to
+/// `<dir>/bare`. Returns the bare clone path.
+pub fn setup_bare_clone(dir: &Path) -> PathBuf {
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).unwrap();
+    git_in(&src, &["init", "-q", "-b", "main"]);
+    commit_file_as(&src, "f", "x", "init");
+    git_in(dir, &["clone", "-q", "--bare", "src", "bare"]);
+    dir.join("bare")
+}
+
+/// Add a worktree of `repo` on a new `branch` at `wt`.
+pub fn add_worktree(repo: &Path, wt: &Path, branch: &str) {
+    let path = wt.to_str().unwrap();
+    git_in(repo, &["worktree", "add", "-q", path, "-b", branch]);
+}
+
+/// [`init_repo`] plus a worktree on `issue-1` at `<dir>/wt`. Returns the
+/// repository and worktree paths.
+pub fn repo_with_worktree(dir: &Path) -> (PathBuf, PathBuf) {
+    let repo = init_repo(dir);
+    let wt = dir.join("wt");
+    add_worktree(&repo, &wt, "issue-1");
+    (repo, wt)
+}
+
+/// Commit a new file `name`, whose content and message are also `name`.
+pub fn commit_file(dir: &Path, name: &str) {
+    commit_file_as(dir, name, name, name);
+}
+
+fn commit_file_as(dir: &Path, name: &str, body: &str, message: &str) {
+    std::fs::write(dir.join(name), body).unwrap();
+    git_in(dir, &["add", name]);
+    git_in(dir, &["commit", "-q", "-m", message]);
+}
@@ -0,0 +1,32 @@
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+/// How long a measured workspace size is trusted before it is re-measured.
+pub const SIZE_CACHE_TTL: Duration = Duration::from_hours(1);
+
+/// A workspace's disk usage as last measured, cached on its registry entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedSize {
+    /// Total size of the files under the workspace, in bytes.
+    pub bytes: u64,
+    /// When the size was measured.
+    #[serde(with = "humantime_serde")]
+    pub measured_at: SystemTime,
+}
+
+impl CachedSize {
+    /// Whether the cached size can still be used at `now`: it is younger than
+    /// [`SIZE_CACHE_TTL`] and the workspace has not been opened since.
+    #[must_use]
+    pub fn is_fresh(&self, last_opened_at: Option<SystemTime>, now: SystemTime) -> bool {
+        last_opened_at.is_none_or(|t| t <= self.measured_at)
+            && now
+                .duration_since(self.measured_at)
+                .is_ok_and(|age| age < SIZE_CACHE_TTL)
+    }
+}
+
+#[cfg(test)]
+#[path = "cached_size_tests.rs"]
+mod tests;
@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn test_cached_size_freshness() {
+    let now = SystemTime::now();
+    let cached = CachedSize {
+        bytes: 1,
+        measured_at: now - Duration::from_mins(5),
+    };
+    assert!(cached.is_fresh(None, now));
+    assert!(cached.is_fresh(Some(now - Duration::from_mins(10)), now));
+    assert!(!cached.is_fresh(Some(now), now));
+    assert!(!cached.is_fresh(None, now + SIZE_CACHE_TTL));
+}
@@ -0,0 +1,19 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+/// The saved local state of a hibernated workspace, whose working tree was
+/// deleted until the next `open` wakes it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hibernation {
+    /// When the workspace was hibernated.
+    #[serde(with = "humantime_serde")]
+    pub at: SystemTime,
+    /// Ref in the repository, under `refs/hibernate/`, of the stash holding
+    /// its uncommitted and untracked changes; `None` when it was clean.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stash: Option<String>,
+    /// Untracked files saved in the stash, relative to the worktree.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untracked: Vec<String>,
+}
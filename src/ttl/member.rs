@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::issue::IssueRef;
+
+/// What a registered workspace directory holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceKind {
+    /// One worktree of a managed bare clone.
+    #[default]
+    Single,
+    /// A unified folder holding one worktree per repository (`open-multi`).
+    Multi,
+    /// A worktree of a local project repository; no bare clone involved.
+    Local,
+}
+
+impl WorkspaceKind {
+    /// The name used for this kind in `workspaces.toml` and JSON output.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Single => "single",
+            Self::Multi => "multi",
+            Self::Local => "local",
+        }
+    }
+}
+
+/// One repository inside a multi-repo workspace.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberRecord {
+    /// Absolute path to the member worktree.
+    pub path: PathBuf,
+    /// Branch checked out in the member worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Ref the branch was created from, e.g. `origin/main`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+    /// Bare clone backing the member worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bare_path: Option<PathBuf>,
+    /// The issue the member was opened for; `None` for a repository opened
+    /// on its default branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueRef>,
+}
@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+
+use super::{MemberRecord, WorkspaceKind, WorkspaceRecord, WorkspaceRegistry, REGISTRY_VERSION};
+use crate::git::{common_dir, current_branch};
+use crate::issue::{provider_host, worktrees_root, IssueRef};
+use crate::lifecycle::members;
+
+impl WorkspaceRegistry {
+    /// Upgrade a registry read from an older file format in place.
+    ///
+    /// Version 1 files only stored `path` and `created_at`; the remaining
+    /// fields are inferred from where the workspace lives and, when it still
+    /// exists on disk, from git.
+    pub(super) fn migrate(&mut self) {
+        if self.version >= REGISTRY_VERSION {
+            return;
+        }
+        let root = worktrees_root();
+        let multi_root = dirs::home_dir().map(|h| h.join("workspaces"));
+        for record in &mut self.workspace {
+            record.upgrade(&root, multi_root.as_deref());
+        }
+        self.version = REGISTRY_VERSION;
+    }
+}
+
+impl WorkspaceRecord {
+    fn upgrade(&mut self, root: &Path, multi_root: Option<&Path>) {
+        if multi_root.is_some_and(|m| self.path.starts_with(m)) {
+            self.kind = WorkspaceKind::Multi;
+            self.members = members(&self.path).into_iter().map(member).collect();
+            return;
+        }
+        let branch = current_branch(&self.path)
+            .ok()
+            .flatten()
+            .or_else(|| Some(self.path.file_name()?.to_string_lossy().into_owned()));
+        if self.path.starts_with(root.join("local")) {
+            self.kind = WorkspaceKind::Local;
+            self.provider = Some("local".to_owned());
+            self.bare_path = repo_of(&self.path);
+        } else {
+            let rel = self.path.strip_prefix(root).ok();
+            let provider = rel.and_then(|r| r.iter().next());
+            self.provider = provider.map(|p| p.to_string_lossy().into_owned());
+            self.host = self
+                .provider
+                .as_deref()
+                .and_then(provider_host)
+                .map(Into::into);
+            self.bare_path = self.path.parent().map(Path::to_path_buf);
+        }
+        if let (None, Some(repo), Some(b)) = (&self.issue, &self.bare_path, &branch) {
+            // Version 1 records carry no issue, so no Jira host is known.
+            self.issue = IssueRef::infer(root, repo, b, None);
+        }
+        self.branch = branch;
+    }
+}
+
+/// The repository owning worktree `wt`: the bare clone, or the project
+/// directory of a regular checkout.
+fn repo_of(wt: &Path) -> Option<PathBuf> {
+    let dir = common_dir(wt).ok()?;
+    Some(match dir.file_name() {
+        Some(name) if name == ".git" => dir.parent()?.to_path_buf(),
+        _ => dir,
+    })
+}
+
+fn member(path: PathBuf) -> MemberRecord {
+    MemberRecord {
+        branch: current_branch(&path).ok().flatten(),
+        bare_path: repo_of(&path),
+        path,
+        ..MemberRecord::default()
+    }
+}
+
+#[cfg(test)]
+#[path = "migrate_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+use crate::test_support::git_in;
+use std::time::SystemTime;
+
+fn record(path: PathBuf) -> WorkspaceRecord {
+    WorkspaceRecord::new(path, SystemTime::UNIX_EPOCH)
+}
+
+#[test]
+fn test_upgrade_single_workspace_from_path() {
+    let root = PathBuf::from("/nonexistent/worktrees");
+    let mut r = record(root.join("github/acme/api/issue-7"));
+    r.upgrade(&root, None);
+    assert_eq!(r.kind, WorkspaceKind::Single);
+    assert_eq!(r.provider.as_deref(), Some("github"));
+    assert_eq!(r.host.as_deref(), Some("github.com"));
+    assert_eq!(r.branch.as_deref(), Some("issue-7"));
+    assert_eq!(r.bare_path, Some(root.join("github/acme/api")));
+    assert_eq!(r.issue, Some(IssueRef::parse("acme/api#7").unwrap()));
+}
+
+#[test]
+fn test_upgrade_keeps_known_issue_and_handles_local() {
+    let root = PathBuf::from("/nonexistent/worktrees");
+    let mut r = record(root.join("local/proj/issue-3"));
+    let issue = IssueRef::Local {
+        project_path: "/src/proj".into(),
+        display_number: 3,
+    };
+    r.issue = Some(issue.clone());
+    r.upgrade(&root, None);
+    assert_eq!(r.kind, WorkspaceKind::Local);
+    assert_eq!(r.provider.as_deref(), Some("local"));
+    assert_eq!(r.branch.as_deref(), Some("issue-3"));
+    assert_eq!(r.issue, Some(issue));
+}
+
+#[test]
+fn test_upgrade_multi_workspace_lists_members() {
+    let dir = tempfile::tempdir().unwrap();
+    let multi_root = dir.path().join("workspaces");
+    let repo = multi_root.join("bold_turing/api");
+    std::fs::create_dir_all(&repo).unwrap();
+    git_in(&repo, &["init", "-q", "-b", "feature"]);
+    let mut r = record(multi_root.join("bold_turing"));
+    r.upgrade(&dir.path().join("worktrees"), Some(&multi_root));
+    assert_eq!(r.kind, WorkspaceKind::Multi);
+    assert_eq!(r.members.len(), 1);
+    assert_eq!(r.members[0].path, repo);
+    assert_eq!(r.members[0].branch.as_deref(), Some("feature"));
+    assert_eq!(r.members[0].bare_path, Some(repo));
+}
+
+#[test]
+fn test_load_v1_file_migrates_and_saves_v2() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("workspaces.toml");
+    let v1 = "[[workspace]]\npath = \"/gone/issue-1\"\ncreated_at = \"2024-01-01T00:00:00Z\"\n";
+    std::fs::write(&path, v1).unwrap();
+    let reg = WorkspaceRegistry::load_from(&path).unwrap();
+    assert_eq!(reg.version, REGISTRY_VERSION);
+    assert_eq!(reg.workspace[0].branch.as_deref(), Some("issue-1"));
+    reg.write_to(&path).unwrap();
+    let saved = std::fs::read_to_string(&path).unwrap();
+    assert!(saved.starts_with("version = 2\n"), "saved: {saved}");
+    assert!(saved.contains("kind = \"single\""), "saved: {saved}");
+}
+
+#[test]
+fn test_load_rejects_newer_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("workspaces.toml");
+    std::fs::write(&path, "version = 3\n").unwrap();
+    let err = WorkspaceRegistry::load_from(&path).unwrap_err();
+    assert!(err.to_string().contains("format version 3"), "{err}");
+}
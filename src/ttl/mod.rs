@@ -2,9 +2,25 @@ use std::time::{Duration, SystemTime};
 
 use serde::{Deserialize, Serialize};
 
+mod cached_size;
+mod hibernation;
+mod member;
+mod migrate;
+mod mode;
+mod policy;
+mod record;
+mod record_ops;
 /// Workspace record and registry persistence.
 pub mod registry;
-pub use registry::{WorkspaceRecord, WorkspaceRegistry};
+mod registry_ops;
+mod size;
+pub use cached_size::{CachedSize, SIZE_CACHE_TTL};
+pub use hibernation::Hibernation;
+pub use member::{MemberRecord, WorkspaceKind};
+pub use mode::TtlMode;
+pub use policy::PrunePolicy;
+pub use registry::{WorkspaceRecord, WorkspaceRegistry, REGISTRY_VERSION};
+pub use size::ByteSize;
 
 /// A time-to-live duration controlling how long a workspace remains active.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -38,16 +54,18 @@ impl std::str::FromStr for Ttl {
     }
 }
 
-/// Returns `true` if the workspace has exceeded its TTL at the given instant.
+/// Returns `true` if the workspace has exceeded its TTL at the given instant,
+/// measuring its age from the point `mode` selects.
 ///
-/// Returns `false` when `created_at` is in the future relative to `now`.
+/// Returns `false` when that point is in the future relative to `now`.
 #[must_use]
-pub fn is_expired(record: &WorkspaceRecord, ttl: &Ttl, now: SystemTime) -> bool {
-    now.duration_since(record.created_at)
+pub fn is_expired(record: &WorkspaceRecord, ttl: &Ttl, mode: TtlMode, now: SystemTime) -> bool {
+    now.duration_since(mode.since(record))
         .is_ok_and(|age| age >= ttl.0)
 }
 
-/// Returns workspaces that are both present on disk and have exceeded the TTL.
+/// Returns unpinned workspaces that are present on disk and have exceeded
+/// their own [`WorkspaceRecord::ttl`], or `ttl` when they have none.
 ///
 /// Entries whose [`WorkspaceRecord::path`] no longer exists are silently
 /// skipped, making the registry self-healing on the next prune call.
@@ -55,11 +73,13 @@ pub fn is_expired(record: &WorkspaceRecord, ttl: &Ttl, now: SystemTime) -> bool
 pub fn prune<'a>(
     records: &'a [WorkspaceRecord],
     ttl: &Ttl,
+    mode: TtlMode,
     now: SystemTime,
 ) -> Vec<&'a WorkspaceRecord> {
     records
         .iter()
-        .filter(|r| r.path.exists() && is_expired(r, ttl, now))
+        .filter(|r| !r.pinned && r.path.exists())
+        .filter(|r| is_expired(r, r.ttl.as_ref().unwrap_or(ttl), mode, now))
         .collect()
 }
 
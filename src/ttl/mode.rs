@@ -0,0 +1,61 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::WorkspaceRecord;
+
+/// What a workspace's age is measured from when checking it against the TTL.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TtlMode {
+    /// Time since the workspace was created.
+    #[default]
+    Created,
+    /// Time since the workspace was last opened.
+    Idle,
+}
+
+impl TtlMode {
+    /// The name used for this mode in the config, e.g. `"idle"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Idle => "idle",
+        }
+    }
+
+    /// The instant `record`'s age is measured from. Workspaces that were
+    /// never opened since they were registered count as idle since creation.
+    #[must_use]
+    pub fn since(self, record: &WorkspaceRecord) -> SystemTime {
+        match self {
+            Self::Created => record.created_at,
+            Self::Idle => record.last_opened_at.unwrap_or(record.created_at),
+        }
+    }
+}
+
+impl std::fmt::Display for TtlMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for TtlMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(Self::Created),
+            "idle" => Ok(Self::Idle),
+            other => Err(format!(
+                "unknown TTL mode {other:?} (expected \"created\" or \"idle\")"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "mode_tests.rs"]
+mod tests;
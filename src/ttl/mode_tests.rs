@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use super::*;
+
+fn record(last_opened_at: Option<SystemTime>) -> WorkspaceRecord {
+    let mut r = WorkspaceRecord::new(PathBuf::from("/w"), SystemTime::UNIX_EPOCH);
+    r.last_opened_at = last_opened_at;
+    r
+}
+
+#[test]
+fn test_since_created_ignores_last_opened() {
+    let opened = SystemTime::UNIX_EPOCH + Duration::from_mins(1);
+    let r = record(Some(opened));
+    assert_eq!(TtlMode::Created.since(&r), SystemTime::UNIX_EPOCH);
+    assert_eq!(TtlMode::Idle.since(&r), opened);
+}
+
+#[test]
+fn test_since_idle_falls_back_to_created() {
+    assert_eq!(TtlMode::Idle.since(&record(None)), SystemTime::UNIX_EPOCH);
+}
+
+#[test]
+fn test_parse_and_display_round_trip() {
+    for mode in [TtlMode::Created, TtlMode::Idle] {
+        assert_eq!(mode.to_string().parse::<TtlMode>(), Ok(mode));
+    }
+    assert!("opened"
+        .parse::<TtlMode>()
+        .unwrap_err()
+        .contains("\"opened\""));
+}
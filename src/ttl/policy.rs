@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// Which criteria `prune` uses to decide that a workspace is done.
+///
+/// Written as a comma-separated list in the config: `"ttl"`, `"merged"`, or
+/// `"ttl,merged"`. A workspace is pruned when any enabled criterion matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PrunePolicy {
+    /// Prune workspaces older than `workspace.ttl`.
+    pub ttl: bool,
+    /// Prune workspaces whose branch is merged into the default branch or
+    /// whose remote branch was deleted upstream.
+    pub merged: bool,
+}
+
+impl Default for PrunePolicy {
+    fn default() -> Self {
+        Self {
+            ttl: true,
+            merged: false,
+        }
+    }
+}
+
+impl std::fmt::Display for PrunePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<&str> = [(self.ttl, "ttl"), (self.merged, "merged")]
+            .into_iter()
+            .filter_map(|(on, name)| on.then_some(name))
+            .collect();
+        f.write_str(&parts.join(","))
+    }
+}
+
+impl std::str::FromStr for PrunePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut policy = Self {
+            ttl: false,
+            merged: false,
+        };
+        for part in s.split(',').map(str::trim) {
+            match part {
+                "ttl" => policy.ttl = true,
+                "merged" => policy.merged = true,
+                other => {
+                    return Err(format!(
+                        "unknown prune policy {other:?} (expected \"ttl\", \"merged\", or \"ttl,merged\")"
+                    ))
+                }
+            }
+        }
+        Ok(policy)
+    }
+}
+
+impl TryFrom<String> for PrunePolicy {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<PrunePolicy> for String {
+    fn from(p: PrunePolicy) -> Self {
+        p.to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "policy_tests.rs"]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_prune_policy_default_is_ttl() {
+    assert_eq!(PrunePolicy::default().to_string(), "ttl");
+}
+
+#[test]
+fn test_prune_policy_parse_combined() {
+    let p: PrunePolicy = "ttl, merged".parse().unwrap();
+    assert!(p.ttl && p.merged);
+    assert_eq!(p.to_string(), "ttl,merged");
+    let p: PrunePolicy = "merged".parse().unwrap();
+    assert!(!p.ttl && p.merged);
+}
+
+#[test]
+fn test_prune_policy_parse_rejects_unknown_and_empty() {
+    assert!("age"
+        .parse::<PrunePolicy>()
+        .unwrap_err()
+        .contains("\"age\""));
+    assert!("".parse::<PrunePolicy>().is_err());
+}
+
+#[test]
+fn test_prune_policy_serde_round_trip() {
+    #[derive(Serialize, Deserialize)]
+    struct W {
+        p: PrunePolicy,
+    }
+    let w: W = toml::from_str("p = \"merged,ttl\"").unwrap();
+    assert!(w.p.ttl && w.p.merged);
+    assert_eq!(toml::to_string(&w).unwrap().trim(), "p = \"ttl,merged\"");
+    assert!(toml::from_str::<W>("p = \"nope\"").is_err());
+}
@@ -10,7 +10,7 @@ fn past(secs: u64) -> SystemTime {
 }
 
 fn record(path: PathBuf, created_at: SystemTime) -> WorkspaceRecord {
-    WorkspaceRecord { path, created_at }
+    WorkspaceRecord::new(path, created_at)
 }
 
 #[test]
@@ -18,7 +18,7 @@ fn test_prune_returns_expired_with_existing_path() {
     let dir = tempfile::tempdir().unwrap();
     let ttl = Ttl::new(Duration::from_mins(1));
     let records = vec![record(dir.path().to_path_buf(), past(120))];
-    let expired = prune(&records, &ttl, SystemTime::now());
+    let expired = prune(&records, &ttl, TtlMode::Created, SystemTime::now());
     assert_eq!(expired.len(), 1);
     assert_eq!(expired[0].path, dir.path());
 }
@@ -27,7 +27,7 @@ fn test_prune_returns_expired_with_existing_path() {
 fn test_prune_skips_missing_paths() {
     let ttl = Ttl::new(Duration::from_mins(1));
     let records = vec![record(PathBuf::from("/nonexistent/path/xyz"), past(120))];
-    assert!(prune(&records, &ttl, SystemTime::now()).is_empty());
+    assert!(prune(&records, &ttl, TtlMode::Created, SystemTime::now()).is_empty());
 }
 
 #[test]
@@ -35,7 +35,7 @@ fn test_prune_skips_unexpired() {
     let dir = tempfile::tempdir().unwrap();
     let ttl = Ttl::new(Duration::from_hours(1));
     let records = vec![record(dir.path().to_path_buf(), past(60))];
-    assert!(prune(&records, &ttl, SystemTime::now()).is_empty());
+    assert!(prune(&records, &ttl, TtlMode::Created, SystemTime::now()).is_empty());
 }
 
 #[test]
@@ -48,7 +48,17 @@ fn test_prune_mixed_records() {
         record(fresh_dir.path().to_path_buf(), past(60)),
         record(PathBuf::from("/no/such/path"), past(1200)),
     ];
-    let expired = prune(&records, &ttl, SystemTime::now());
+    let expired = prune(&records, &ttl, TtlMode::Created, SystemTime::now());
     assert_eq!(expired.len(), 1);
     assert_eq!(expired[0].path, expired_dir.path());
 }
+
+#[test]
+fn test_prune_skips_pinned() {
+    let dir = tempfile::tempdir().unwrap();
+    let ttl = Ttl::new(Duration::from_mins(1));
+    let mut pinned = record(dir.path().to_path_buf(), past(120));
+    pinned.pinned = true;
+    let records = vec![pinned];
+    assert!(prune(&records, &ttl, TtlMode::Created, SystemTime::now()).is_empty());
+}
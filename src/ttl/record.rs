@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::member::{MemberRecord, WorkspaceKind};
+use crate::issue::IssueRef;
+
+/// A workspace entry stored in the registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceRecord {
+    /// Absolute path to the worktree directory.
+    pub path: PathBuf,
+    /// When this workspace was first created.
+    #[serde(with = "humantime_serde")]
+    pub created_at: SystemTime,
+    /// When this workspace was last opened, if known.
+    #[serde(
+        default,
+        with = "humantime_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_opened_at: Option<SystemTime>,
+    /// Whether this is a single, multi-repo, or local workspace.
+    #[serde(default)]
+    pub kind: WorkspaceKind,
+    /// Hosting provider: `github`, `gitlab`, `azuredevops`, or `local`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Host the repository is cloned from, e.g. `github.com`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Branch checked out in the worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Ref the branch was created from, e.g. `origin/main`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+    /// Bare clone backing the worktree; the project repository itself for
+    /// local workspaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bare_path: Option<PathBuf>,
+    /// The issue this workspace was opened for, when known. Worktrees
+    /// imported from plain `git worktree add` carry a best guess.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueRef>,
+    /// TTL overriding `workspace.ttl` for this workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<super::Ttl>,
+    /// Disk usage as last measured by `prune`, reused while fresh.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<super::CachedSize>,
+    /// Pinned workspaces are never pruned and their worktrees stay locked.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+    /// When the workspace was moved to the trash; it can be restored until
+    /// the trash retention runs out.
+    #[serde(
+        default,
+        with = "humantime_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub trashed_at: Option<SystemTime>,
+    /// Set while the workspace is hibernated: its working tree is deleted and
+    /// its local changes are kept in the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hibernated: Option<super::Hibernation>,
+    /// First port of the block allocated to this workspace; freed when it is
+    /// removed or moved to the trash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Variables given with `open --env`, exported to every later hook, `run`,
+    /// and terminal of this workspace.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// The repositories of a multi-repo workspace.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<MemberRecord>,
+}
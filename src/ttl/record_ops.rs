@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::member::WorkspaceKind;
+use super::WorkspaceRecord;
+use crate::issue::IssueRef;
+
+impl WorkspaceRecord {
+    /// Create a record for `path` with no associated issue.
+    #[must_use]
+    pub const fn new(path: PathBuf, created_at: SystemTime) -> Self {
+        Self {
+            path,
+            created_at,
+            last_opened_at: None,
+            kind: WorkspaceKind::Single,
+            provider: None,
+            host: None,
+            branch: None,
+            base_ref: None,
+            bare_path: None,
+            issue: None,
+            ttl: None,
+            size: None,
+            pinned: false,
+            trashed_at: None,
+            hibernated: None,
+            port: None,
+            env: BTreeMap::new(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Create a record for a worktree opened for `issue` at `now`, filling in
+    /// everything derivable from the issue itself. `base_ref` is left for the
+    /// caller, which knows the branch the worktree was created from.
+    #[must_use]
+    pub fn for_issue(issue: &IssueRef, path: PathBuf, now: SystemTime) -> Self {
+        let mut record = Self::new(path, now);
+        record.last_opened_at = Some(now);
+        record.provider = Some(issue.provider().to_owned());
+        record.host = issue.host().map(str::to_owned);
+        record.branch = Some(issue.branch_name());
+        record.bare_path = Some(match issue {
+            IssueRef::Local { project_path, .. } => project_path.clone(),
+            _ => issue.bare_clone_path(),
+        });
+        if matches!(issue, IssueRef::Local { .. }) {
+            record.kind = WorkspaceKind::Local;
+        }
+        record.issue = Some(issue.clone());
+        record
+    }
+
+    /// The repositories the workspace was created from: its bare clone or
+    /// project repository, then those of its members.
+    pub fn repos(&self) -> impl Iterator<Item = &Path> {
+        let members = self.members.iter().filter_map(|m| m.bare_path.as_deref());
+        self.bare_path.as_deref().into_iter().chain(members)
+    }
+}
@@ -1,28 +1,37 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use std::time::SystemTime;
 
-use anyhow::{Context, Result};
-use serde::{Deserialize, Serialize};
+pub use super::record::WorkspaceRecord;
 
-/// A workspace entry stored in the registry.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WorkspaceRecord {
-    /// Absolute path to the worktree directory.
-    pub path: PathBuf,
-    /// When this workspace was first created.
-    #[serde(with = "humantime_serde")]
-    pub created_at: SystemTime,
-}
+/// Current format version of `workspaces.toml`.
+pub const REGISTRY_VERSION: u32 = 2;
 
 /// Persistent registry of all known workspaces and their creation timestamps.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct WorkspaceRegistry {
+    /// File format version; files written before versioning are version 1.
+    #[serde(default = "legacy_version")]
+    pub version: u32,
     /// Registered workspaces.
     #[serde(rename = "workspace")]
     pub workspace: Vec<WorkspaceRecord>,
 }
 
+const fn legacy_version() -> u32 {
+    1
+}
+
+impl Default for WorkspaceRegistry {
+    fn default() -> Self {
+        Self {
+            version: REGISTRY_VERSION,
+            workspace: Vec::new(),
+        }
+    }
+}
+
 impl WorkspaceRegistry {
     /// Return the path to the workspace registry file
     /// (`~/.config/worktree/workspaces.toml`).
@@ -38,16 +47,26 @@ impl WorkspaceRegistry {
             .join("workspaces.toml"))
     }
 
-    fn load_from(path: &Path) -> Result<Self> {
+    pub(super) fn load_from(path: &Path) -> Result<Self> {
         if !path.exists() {
             return Ok(Self::default());
         }
         let content = std::fs::read_to_string(path)
             .context(format!("Failed to read registry from {}", path.display()))?;
-        toml::from_str(&content).context(format!("Failed to parse registry at {}", path.display()))
+        let mut registry: Self = toml::from_str(&content)
+            .context(format!("Failed to parse registry at {}", path.display()))?;
+        if registry.version > REGISTRY_VERSION {
+            bail!(
+                "Registry at {} uses format version {}, newer than this build supports ({REGISTRY_VERSION}); upgrade worktree",
+                path.display(),
+                registry.version
+            );
+        }
+        registry.migrate();
+        Ok(registry)
     }
 
-    fn write_to(&self, path: &Path) -> Result<()> {
+    pub(super) fn write_to(&self, path: &Path) -> Result<()> {
         let parent = path.parent().context("registry path has no parent")?;
         std::fs::create_dir_all(parent)
             .context(format!("Failed to create config dir {}", parent.display()))?;
@@ -74,19 +93,6 @@ impl WorkspaceRegistry {
     pub fn save(&self) -> Result<()> {
         self.write_to(&Self::path()?)
     }
-
-    /// Register a workspace path with the current timestamp.
-    ///
-    /// Idempotent: if `path` is already present, the existing entry is left
-    /// unchanged.
-    pub fn register(&mut self, path: PathBuf) {
-        if !self.workspace.iter().any(|r| r.path == path) {
-            self.workspace.push(WorkspaceRecord {
-                path,
-                created_at: SystemTime::now(),
-            });
-        }
-    }
 }
 
 #[cfg(test)]
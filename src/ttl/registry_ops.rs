@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::{WorkspaceRecord, WorkspaceRegistry};
+use crate::issue::IssueRef;
+
+impl WorkspaceRegistry {
+    /// Register a workspace path with the current timestamp.
+    ///
+    /// Idempotent: if `path` is already present, the existing entry is left
+    /// unchanged.
+    pub fn register(&mut self, path: PathBuf) {
+        self.insert(WorkspaceRecord::new(path, SystemTime::now()));
+    }
+
+    /// Add `record` unless a workspace at the same path is already present.
+    /// A trashed workspace at that path is replaced, since its directory is
+    /// free again.
+    ///
+    /// Returns `true` when the record was added.
+    pub fn insert(&mut self, record: WorkspaceRecord) -> bool {
+        match self.get(&record.path) {
+            Some(r) if r.trashed_at.is_some() => drop(self.remove(&record.path)),
+            Some(_) => return false,
+            None => {}
+        }
+        self.workspace.push(record);
+        true
+    }
+
+    /// Record that the workspace at `path` was opened at `now`.
+    ///
+    /// Returns `true` when `path` is registered and the record was updated.
+    pub fn touch(&mut self, path: &Path, now: SystemTime) -> bool {
+        self.get_mut(path)
+            .map(|r| r.last_opened_at = Some(now))
+            .is_some()
+    }
+
+    /// The record for `path`, matched like [`Self::contains`].
+    #[must_use]
+    pub fn get(&self, path: &Path) -> Option<&WorkspaceRecord> {
+        self.position(path).map(|i| &self.workspace[i])
+    }
+
+    /// Mutable access to the record for `path`, matched like [`Self::contains`].
+    pub fn get_mut(&mut self, path: &Path) -> Option<&mut WorkspaceRecord> {
+        self.position(path).map(|i| &mut self.workspace[i])
+    }
+
+    /// Return `true` if a workspace at `path` is registered. Paths are
+    /// compared after resolving symlinks when both sides exist on disk.
+    #[must_use]
+    pub fn contains(&self, path: &Path) -> bool {
+        self.position(path).is_some()
+    }
+
+    /// Remove and return the record for `path`, matched like [`Self::contains`].
+    pub fn remove(&mut self, path: &Path) -> Option<WorkspaceRecord> {
+        self.position(path).map(|i| self.workspace.remove(i))
+    }
+
+    /// The Jira host of a workspace created from `repo` for a Jira issue, if
+    /// any.
+    #[must_use]
+    pub fn jira_host(&self, repo: &Path) -> Option<&str> {
+        self.workspace.iter().find_map(|r| match &r.issue {
+            Some(IssueRef::Jira { host, .. }) if r.bare_path.as_deref() == Some(repo) => {
+                Some(host.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    fn position(&self, path: &Path) -> Option<usize> {
+        let canonical = std::fs::canonicalize(path).ok();
+        self.workspace.iter().position(|r| {
+            r.path == path
+                || canonical.is_some() && std::fs::canonicalize(&r.path).ok() == canonical
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "registry_ops_tests.rs"]
+mod tests;
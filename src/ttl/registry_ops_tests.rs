@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::*;
+use crate::issue::IssueRef;
+
+#[test]
+fn test_insert_adds_new_record() {
+    let mut r = WorkspaceRegistry::default();
+    let mut rec = WorkspaceRecord::new(PathBuf::from("/a"), SystemTime::UNIX_EPOCH);
+    rec.issue = Some(IssueRef::GitHub {
+        owner: "o".into(),
+        repo: "r".into(),
+        number: 1,
+    });
+    assert!(r.insert(rec));
+    assert_eq!(r.workspace[0].created_at, SystemTime::UNIX_EPOCH);
+    assert!(r.workspace[0].issue.is_some());
+}
+
+#[test]
+fn test_insert_skips_duplicate_path() {
+    let mut r = WorkspaceRegistry::default();
+    r.register(PathBuf::from("/a"));
+    let rec = WorkspaceRecord::new(PathBuf::from("/a"), SystemTime::UNIX_EPOCH);
+    assert!(!r.insert(rec));
+    assert_eq!(r.workspace.len(), 1);
+}
+
+#[test]
+fn test_insert_replaces_trashed_record() {
+    let mut r = WorkspaceRegistry::default();
+    let mut trashed = WorkspaceRecord::new(PathBuf::from("/a"), SystemTime::UNIX_EPOCH);
+    trashed.trashed_at = Some(SystemTime::UNIX_EPOCH);
+    r.insert(trashed);
+    assert!(r.insert(WorkspaceRecord::new(PathBuf::from("/a"), SystemTime::now())));
+    assert_eq!(r.workspace.len(), 1);
+    assert!(r.workspace[0].trashed_at.is_none());
+}
+
+#[test]
+fn test_contains_resolves_symlinks() {
+    let dir = tempfile::tempdir().unwrap();
+    let real = dir.path().join("real");
+    std::fs::create_dir(&real).unwrap();
+    let mut r = WorkspaceRegistry::default();
+    r.register(real.clone());
+    assert!(r.contains(&real));
+    assert!(r.contains(&real.join("..").join("real")));
+    assert!(!r.contains(&dir.path().join("other")));
+}
+
+#[test]
+fn test_remove_returns_record() {
+    let mut r = WorkspaceRegistry::default();
+    r.register(PathBuf::from("/a"));
+    r.register(PathBuf::from("/b"));
+    assert_eq!(r.remove(Path::new("/a")).unwrap().path, PathBuf::from("/a"));
+    assert!(r.remove(Path::new("/a")).is_none());
+    assert_eq!(r.workspace.len(), 1);
+}
+
+#[test]
+fn test_touch_sets_last_opened_only_for_known_paths() {
+    let mut r = WorkspaceRegistry::default();
+    r.register(PathBuf::from("/a"));
+    assert!(r.touch(Path::new("/a"), SystemTime::UNIX_EPOCH));
+    assert_eq!(r.workspace[0].last_opened_at, Some(SystemTime::UNIX_EPOCH));
+    assert!(!r.touch(Path::new("/b"), SystemTime::UNIX_EPOCH));
+}
+
+#[test]
+fn test_jira_host_from_workspace_of_same_repo() {
+    let mut r = WorkspaceRegistry::default();
+    let issue = IssueRef::Jira {
+        host: "acme.atlassian.net".into(),
+        issue_key: "PROJ-1".into(),
+        owner: "acme".into(),
+        repo: "api".into(),
+    };
+    let rec = WorkspaceRecord::for_issue(&issue, PathBuf::from("/w"), SystemTime::UNIX_EPOCH);
+    let bare = rec.bare_path.clone().unwrap();
+    r.insert(rec);
+    assert_eq!(r.jira_host(&bare), Some("acme.atlassian.net"));
+    assert_eq!(r.jira_host(Path::new("/elsewhere")), None);
+}
@@ -57,10 +57,7 @@ fn test_registry_clone_is_independent() {
 
 #[test]
 fn test_record_debug_and_clone() {
-    let r = WorkspaceRecord {
-        path: PathBuf::from("/tmp"),
-        created_at: SystemTime::now(),
-    };
+    let r = WorkspaceRecord::new(PathBuf::from("/tmp"), SystemTime::now());
     let _ = format!("{r:?}");
     let r2 = r.clone();
     assert_eq!(r.path, r2.path);
@@ -47,10 +47,10 @@ fn test_workspace_record_serde_round_trip() {
         .checked_sub(Duration::from_hours(1))
         .unwrap();
     let h = RecordHelper {
-        workspace: vec![WorkspaceRecord {
-            path: PathBuf::from("/tmp/my-workspace"),
+        workspace: vec![WorkspaceRecord::new(
+            PathBuf::from("/tmp/my-workspace"),
             created_at,
-        }],
+        )],
     };
     let s = toml::to_string(&h).unwrap();
     let parsed: RecordHelper = toml::from_str(&s).unwrap();
@@ -62,3 +62,28 @@ fn test_workspace_record_serde_round_trip() {
         to_secs(parsed.workspace[0].created_at),
     );
 }
+
+#[test]
+fn test_workspace_record_issue_round_trip() {
+    let mut rec = WorkspaceRecord::new(PathBuf::from("/tmp/ws"), SystemTime::now());
+    rec.issue = Some(crate::issue::IssueRef::Jira {
+        host: "acme.atlassian.net".into(),
+        issue_key: "PROJ-1".into(),
+        owner: "acme".into(),
+        repo: "api".into(),
+    });
+    let s = toml::to_string(&RecordHelper {
+        workspace: vec![rec.clone()],
+    })
+    .unwrap();
+    assert!(s.contains("type = \"jira\""));
+    let parsed: RecordHelper = toml::from_str(&s).unwrap();
+    assert_eq!(parsed.workspace[0].issue, rec.issue);
+}
+
+#[test]
+fn test_workspace_record_without_issue_parses() {
+    let s = "[[workspace]]\npath = \"/x\"\ncreated_at = \"2025-01-01T00:00:00Z\"\n";
+    let parsed: RecordHelper = toml::from_str(s).unwrap();
+    assert!(parsed.workspace[0].issue.is_none());
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+const UNITS: [(&str, u64); 5] = [
+    ("TB", 1 << 40),
+    ("GB", 1 << 30),
+    ("MB", 1 << 20),
+    ("KB", 1 << 10),
+    ("B", 1),
+];
+
+/// Bytes per `unit`, e.g. `1024` for `KB`, `KiB`, or `k`.
+fn scale(unit: &str) -> Option<u64> {
+    let unit = unit.to_ascii_uppercase();
+    let prefix = unit
+        .strip_suffix("IB")
+        .or_else(|| unit.strip_suffix('B'))
+        .unwrap_or(&unit);
+    UNITS
+        .iter()
+        .find(|(u, _)| u.strip_suffix('B') == Some(prefix))
+        .map(|&(_, scale)| scale)
+}
+
+/// A disk-space budget such as `50GB`, in binary (1024-based) units.
+///
+/// Accepts `B` up to `TB`, case-insensitively, after a number like `1.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// The size in bytes.
+    #[must_use]
+    pub const fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (unit, scale) = UNITS
+            .iter()
+            .find(|(_, scale)| self.0.is_multiple_of(*scale) && self.0 >= *scale)
+            .unwrap_or(&("B", 1));
+        write!(f, "{}{unit}", self.0 / scale)
+    }
+}
+
+impl std::str::FromStr for ByteSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid size {s:?} (expected e.g. \"50GB\" or \"500MB\")");
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(s.len());
+        let (num, unit) = s.split_at(split);
+        let scale = scale(unit.trim()).ok_or_else(err)?;
+        let (whole, frac) = num.split_once('.').unwrap_or((num, ""));
+        let digits = |d: &str| -> Result<u64, String> {
+            if d.is_empty() {
+                Ok(0)
+            } else {
+                d.parse().map_err(|_| err())
+            }
+        };
+        if whole.is_empty() && frac.is_empty() {
+            return Err(err());
+        }
+        let places = u32::try_from(frac.len()).map_err(|_| err())?;
+        let frac_bytes = 10u64
+            .checked_pow(places)
+            .and_then(|p| digits(frac).ok()?.checked_mul(scale).map(|n| n / p))
+            .ok_or_else(err)?;
+        digits(whole)?
+            .checked_mul(scale)
+            .and_then(|n| n.checked_add(frac_bytes))
+            .map(Self)
+            .ok_or_else(err)
+    }
+}
+
+impl TryFrom<String> for ByteSize {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<ByteSize> for String {
+    fn from(b: ByteSize) -> Self {
+        b.to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "size_tests.rs"]
+mod tests;
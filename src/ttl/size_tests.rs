@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_byte_size_parse_units() {
+    let gb = |s: &str| s.parse::<ByteSize>().unwrap().bytes();
+    assert_eq!(gb("50GB"), 50 << 30);
+    assert_eq!(gb("50 gb"), 50 << 30);
+    assert_eq!(gb("500MiB"), 500 << 20);
+    assert_eq!(gb("2k"), 2 << 10);
+    assert_eq!(gb("1.5GB"), 3 << 29);
+    assert_eq!(gb("123"), 123);
+    assert_eq!(gb("1TB"), 1 << 40);
+}
+
+#[test]
+fn test_byte_size_parse_rejects_garbage() {
+    for bad in ["", "GB", "fifty GB", "10XB", "1.2.3GB", "99999999999TB"] {
+        let err = bad.parse::<ByteSize>().unwrap_err();
+        assert!(err.contains("invalid size"), "{bad}: {err}");
+    }
+}
+
+#[test]
+fn test_byte_size_display_round_trips() {
+    for s in ["50GB", "1536MB", "123B", "1TB"] {
+        assert_eq!(s.parse::<ByteSize>().unwrap().to_string(), s);
+    }
+    assert_eq!("0".parse::<ByteSize>().unwrap().to_string(), "0B");
+}
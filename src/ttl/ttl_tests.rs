@@ -46,40 +46,41 @@ fn test_ttl_parse_invalid() {
 #[test]
 fn test_is_expired_when_age_exceeds_ttl() {
     let ttl = Ttl::new(Duration::from_mins(1));
-    let r = WorkspaceRecord {
-        path: PathBuf::new(),
-        created_at: past(120),
-    };
-    assert!(is_expired(&r, &ttl, SystemTime::now()));
+    let r = WorkspaceRecord::new(PathBuf::new(), past(120));
+    assert!(is_expired(&r, &ttl, TtlMode::Created, SystemTime::now()));
 }
 
 #[test]
 fn test_is_not_expired_when_young() {
     let ttl = Ttl::new(Duration::from_hours(1));
-    let r = WorkspaceRecord {
-        path: PathBuf::new(),
-        created_at: past(60),
-    };
-    assert!(!is_expired(&r, &ttl, SystemTime::now()));
+    let r = WorkspaceRecord::new(PathBuf::new(), past(60));
+    assert!(!is_expired(&r, &ttl, TtlMode::Created, SystemTime::now()));
 }
 
 #[test]
 fn test_is_not_expired_when_created_in_future() {
     let ttl = Ttl::new(Duration::from_mins(1));
-    let r = WorkspaceRecord {
-        path: PathBuf::new(),
-        created_at: future(30),
-    };
-    assert!(!is_expired(&r, &ttl, SystemTime::now()));
+    let r = WorkspaceRecord::new(PathBuf::new(), future(30));
+    assert!(!is_expired(&r, &ttl, TtlMode::Created, SystemTime::now()));
 }
 
 #[test]
 fn test_is_expired_at_exact_boundary() {
     let ttl = Ttl::new(Duration::from_secs(100));
     let now = SystemTime::now();
-    let r = WorkspaceRecord {
-        path: PathBuf::new(),
-        created_at: now.checked_sub(Duration::from_secs(100)).unwrap(),
-    };
-    assert!(is_expired(&r, &ttl, now));
+    let r = WorkspaceRecord::new(
+        PathBuf::new(),
+        now.checked_sub(Duration::from_secs(100)).unwrap(),
+    );
+    assert!(is_expired(&r, &ttl, TtlMode::Created, now));
+}
+
+#[test]
+fn test_idle_mode_measures_from_last_open() {
+    let ttl = Ttl::new(Duration::from_hours(1));
+    let mut r = WorkspaceRecord::new(PathBuf::new(), past(7200));
+    r.last_opened_at = Some(past(60));
+    let now = SystemTime::now();
+    assert!(is_expired(&r, &ttl, TtlMode::Created, now));
+    assert!(!is_expired(&r, &ttl, TtlMode::Idle, now));
 }
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::git::{
+    bare_clone, branch_exists_local, branch_exists_remote, create_local_worktree, create_worktree,
+    current_branch, detect_default_branch, git_fetch, git_worktree_prune,
+};
+use crate::issue::IssueRef;
+
+/// Create the worktree for `issue` at `dest`, from the project repository
+/// for local issues and from a fresh or fetched bare clone otherwise.
+///
+/// Returns the ref a new worktree branch was started from, if any.
+// LLVM_COV_EXCL_START
+pub(super) fn create(issue: &IssueRef, dest: &Path) -> Result<Option<String>> {
+    match issue {
+        IssueRef::Local { project_path, .. } => create_local(issue, project_path, dest),
+        _ => create_remote(issue, &issue.bare_clone_path(), dest),
+    }
+}
+// LLVM_COV_EXCL_STOP
+
+/// Create a worktree for a local issue directly from the project repository.
+///
+/// Returns the branch a new worktree branch was started from, if any.
+// LLVM_COV_EXCL_START
+fn create_local(issue: &IssueRef, project: &Path, dest: &Path) -> Result<Option<String>> {
+    eprintln!("Creating local worktree at {}…", dest.display());
+    let branch = issue.branch_name();
+    let branch_exists = branch_exists_local(project, &branch);
+    let base_ref = if branch_exists {
+        None
+    } else {
+        current_branch(project)?
+    };
+    std::fs::create_dir_all(dest.parent().unwrap_or(dest))?;
+    let _ = git_worktree_prune(project);
+    create_local_worktree(project, dest, &branch, branch_exists)?;
+    Ok(base_ref)
+}
+
+/// Clone or fetch the bare repository for `issue` and add a worktree to it.
+///
+/// Returns the remote ref a new worktree branch was started from, if any.
+fn create_remote(issue: &IssueRef, bare_path: &Path, dest: &Path) -> Result<Option<String>> {
+    if bare_path.exists() {
+        eprintln!("Fetching origin…");
+        git_fetch(bare_path)?;
+    } else {
+        eprintln!(
+            "Cloning {} (bare) into {}…",
+            issue.clone_url(),
+            bare_path.display()
+        );
+        bare_clone(&issue.clone_url(), bare_path)?;
+    }
+
+    let base_branch = detect_default_branch(bare_path)?;
+    eprintln!("Default branch: {base_branch}");
+
+    let branch = issue.branch_name();
+    // A local branch survives its worktree; reuse it so recreating keeps its commits.
+    let branch_exists =
+        branch_exists_remote(bare_path, &branch) || branch_exists_local(bare_path, &branch);
+
+    eprintln!("Creating worktree {} at {}…", branch, dest.display());
+    let _ = git_worktree_prune(bare_path);
+    create_worktree(bare_path, dest, &branch, &base_branch, branch_exists)?;
+    Ok((!branch_exists).then(|| format!("origin/{base_branch}")))
+}
+// LLVM_COV_EXCL_STOP
@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use crate::git::{admin_dir, common_dir, current_branch, relink_worktree, repair_worktree};
+
+/// What `open` found wrong with an existing worktree, and what it did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Integrity {
+    /// `git worktree repair` fixed the links between the worktree and its
+    /// repository.
+    Repaired,
+    /// The worktree's `.git` file was pointed back at its entry in the
+    /// repository.
+    Relinked,
+    /// The worktree was recreated, and the files found in its place were
+    /// copied back over the fresh checkout.
+    Recreated,
+    /// A live worktree checked out on another branch, named here; left as is.
+    OtherBranch(String),
+}
+
+impl Integrity {
+    /// The name used in `open --json`, e.g. `"relinked"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Repaired => "repaired",
+            Self::Relinked => "relinked",
+            Self::Recreated => "recreated",
+            Self::OtherBranch(_) => "other-branch",
+        }
+    }
+}
+
+/// The state of an existing worktree directory.
+pub(super) enum Health {
+    Live,
+    OtherBranch(String),
+    /// Not a usable worktree of the expected repository, and why.
+    Broken(String),
+}
+
+/// Whether `path` is a live worktree of `repo` on `branch`: it has its own
+/// `.git`, git resolves it to `repo`, and `repo` links back to it.
+pub(super) fn check(path: &Path, repo: &Path, branch: &str) -> Health {
+    if !path.join(".git").exists() {
+        return Health::Broken("it has no .git".to_owned());
+    }
+    let same = |a: &Path, b: &Path| a.canonicalize().ok() == b.canonicalize().ok();
+    match common_dir(path) {
+        Ok(dir) if same(&dir, repo) || same(&dir, &repo.join(".git")) => {}
+        Ok(dir) => return Health::Broken(format!("it belongs to {}", dir.display())),
+        Err(_) => return Health::Broken("git cannot find its repository".to_owned()),
+    }
+    let linked_back = (admin_dir(path).ok())
+        .and_then(|admin| std::fs::read_to_string(admin.join("gitdir")).ok())
+        .is_some_and(|gitdir| same(Path::new(gitdir.trim()), &path.join(".git")));
+    if !linked_back && path.join(".git").is_file() {
+        return Health::Broken("its repository has lost track of it".to_owned());
+    }
+    match current_branch(path) {
+        Ok(Some(b)) if b == branch => Health::Live,
+        Ok(Some(b)) => Health::OtherBranch(b),
+        Ok(None) => Health::OtherBranch("a detached HEAD".to_owned()),
+        Err(e) => Health::Broken(format!("{e:#}")),
+    }
+}
+
+/// Fix the links between `path` and `repo` without touching any files:
+/// first with `git worktree repair`, then by pointing `.git` at the entry
+/// of `repo` that still records `path`. A directory without a `.git` file
+/// has lost its checkout too, so it is left for recreation.
+pub(super) fn repair_links(path: &Path, repo: &Path, branch: &str) -> Option<Integrity> {
+    let gitfile = path.join(".git");
+    if !gitfile.is_file() {
+        return None;
+    }
+    let healthy = || !matches!(check(path, repo, branch), Health::Broken(_));
+    if repair_worktree(repo, path).is_ok() && healthy() {
+        return Some(Integrity::Repaired);
+    }
+    let common = Some(repo.join(".git")).filter(|d| d.is_dir());
+    let admin = std::fs::read_dir(common.as_deref().unwrap_or(repo).join("worktrees"))
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .find(|admin| {
+            std::fs::read_to_string(admin.join("gitdir"))
+                .is_ok_and(|g| Path::new(g.trim()) == gitfile)
+        })?;
+    (relink_worktree(repo, path, &admin).is_ok() && healthy()).then_some(Integrity::Relinked)
+}
+
+#[cfg(test)]
+#[path = "integrity_tests.rs"]
+mod tests;
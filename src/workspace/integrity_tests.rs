@@ -0,0 +1,55 @@
+use super::*;
+use crate::test_support::{git_in, repo_with_worktree};
+
+fn is_broken(h: &Health) -> bool {
+    matches!(h, Health::Broken(_))
+}
+
+#[test]
+fn test_check_live_other_branch_and_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    assert!(matches!(check(&wt, &repo, "issue-1"), Health::Live));
+    git_in(&wt, &["switch", "-c", "other"]);
+    assert!(matches!(check(&wt, &repo, "issue-1"), Health::OtherBranch(b) if b == "other"));
+    let empty = dir.path().join("empty");
+    std::fs::create_dir(&empty).unwrap();
+    assert!(is_broken(&check(&empty, &repo, "issue-1")));
+}
+
+#[test]
+fn test_repair_links_relinks_a_corrupt_git_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    std::fs::write(wt.join(".git"), "gitdir: /nowhere\n").unwrap();
+    assert!(is_broken(&check(&wt, &repo, "issue-1")));
+    assert_eq!(
+        repair_links(&wt, &repo, "issue-1"),
+        Some(Integrity::Relinked)
+    );
+    assert!(matches!(check(&wt, &repo, "issue-1"), Health::Live));
+}
+
+#[test]
+fn test_repair_links_repairs_a_moved_worktree() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    let moved = dir.path().join("moved");
+    std::fs::rename(&wt, &moved).unwrap();
+    assert!(is_broken(&check(&moved, &repo, "issue-1")));
+    assert_eq!(
+        repair_links(&moved, &repo, "issue-1"),
+        Some(Integrity::Repaired)
+    );
+    assert!(matches!(check(&moved, &repo, "issue-1"), Health::Live));
+}
+
+#[test]
+fn test_repair_links_leaves_a_dir_without_git_to_recreate() {
+    let dir = tempfile::tempdir().unwrap();
+    let (repo, wt) = repo_with_worktree(dir.path());
+    std::fs::remove_dir_all(&wt).unwrap();
+    std::fs::create_dir(&wt).unwrap();
+    assert_eq!(repair_links(&wt, &repo, "issue-1"), None);
+    assert!(!wt.join(".git").exists());
+}
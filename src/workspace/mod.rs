@@ -0,0 +1,88 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::issue::IssueRef;
+use crate::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+mod create;
+mod integrity;
+mod recreate;
+mod wake;
+
+pub use integrity::Integrity;
+
+/// An open (or newly created) git worktree for a given issue.
+pub struct Workspace {
+    /// Absolute path to the worktree directory.
+    pub path: PathBuf,
+    /// The issue this workspace was opened for.
+    pub issue: IssueRef,
+    /// `true` if this call created the worktree; `false` if it already existed.
+    pub created: bool,
+    /// `true` if this call rehydrated a hibernated worktree.
+    pub woke: bool,
+    /// What was wrong with an existing worktree, and what was done about it.
+    pub integrity: Option<Integrity>,
+}
+
+impl Workspace {
+    /// Open an existing worktree, wake a hibernated one, or create a fresh one.
+    ///
+    /// An existing worktree is checked first and repaired when it is broken;
+    /// see [`Integrity`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be cloned/fetched, the branch
+    /// cannot be detected, or the worktree cannot be created.
+    pub fn open_or_create(issue: IssueRef) -> Result<Self> {
+        let worktree_path = issue.temp_path();
+
+        // Fast path: worktree already exists
+        if worktree_path.exists() {
+            let integrity = Self::verify(&issue, &worktree_path)?;
+            if let Ok(mut registry) = WorkspaceRegistry::load() {
+                if registry.touch(&worktree_path, SystemTime::now()) {
+                    let _ = registry.save();
+                }
+            }
+            return Ok(Self {
+                path: worktree_path,
+                issue,
+                created: false,
+                woke: false,
+                integrity,
+            });
+        }
+        if let Some(woken) = Self::wake(&issue, &worktree_path)? {
+            return Ok(woken);
+        }
+
+        // LLVM_COV_EXCL_START
+        let base_ref = create::create(&issue, &worktree_path)?;
+        crate::local_files::seed_new_worktree(&issue, &worktree_path);
+        crate::cache::prepare_new_worktree(&issue, &worktree_path, base_ref.as_deref());
+
+        if let Ok(mut registry) = WorkspaceRegistry::load() {
+            let mut record =
+                WorkspaceRecord::for_issue(&issue, worktree_path.clone(), SystemTime::now());
+            record.base_ref = base_ref;
+            registry.insert(record);
+            let _ = registry.save();
+        }
+
+        Ok(Self {
+            path: worktree_path,
+            issue,
+            created: true,
+            woke: false,
+            integrity: None,
+        })
+        // LLVM_COV_EXCL_STOP
+    }
+}
+
+#[cfg(test)]
+#[path = "workspace_tests.rs"]
+mod workspace_tests;
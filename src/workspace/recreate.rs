@@ -0,0 +1,95 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::integrity::{check, repair_links, Health, Integrity};
+use super::{create, Workspace};
+use crate::issue::IssueRef;
+use crate::ttl::{WorkspaceRecord, WorkspaceRegistry};
+
+impl Workspace {
+    /// Check the existing worktree at `path` and repair it if it is broken.
+    /// `None` means it is a live worktree of the issue's branch.
+    ///
+    /// Links are repaired in place when possible. Otherwise the directory is
+    /// set aside, the worktree recreated, and the files set aside copied back
+    /// over the fresh checkout, so local edits survive.
+    pub(super) fn verify(issue: &IssueRef, path: &Path) -> Result<Option<Integrity>> {
+        let repo = match issue {
+            IssueRef::Local { project_path, .. } => project_path.clone(),
+            _ => issue.bare_clone_path(),
+        };
+        let branch = issue.branch_name();
+        let why = match check(path, &repo, &branch) {
+            Health::Live => return Ok(None),
+            Health::OtherBranch(b) => return Ok(Some(Integrity::OtherBranch(b))),
+            Health::Broken(why) => why,
+        };
+        eprintln!("The worktree at {} is broken: {why}.", path.display());
+        let fixed = repo.exists().then(|| repair_links(path, &repo, &branch));
+        if let Some(fixed) = fixed.flatten() {
+            return Ok(Some(fixed));
+        }
+        eprintln!("Recreating it and keeping its files…");
+        let aside = set_aside(path)?;
+        let base_ref = match create::create(issue, path) {
+            Ok(base_ref) => base_ref,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(path);
+                let _ = std::fs::rename(&aside, path);
+                return Err(e.context(format!("Failed to recreate {}", path.display())));
+            }
+        };
+        copy_back(&aside, path, true)
+            .with_context(|| format!("Failed to copy files back from {}", aside.display()))?;
+        std::fs::remove_dir_all(&aside)
+            .with_context(|| format!("Failed to remove {}", aside.display()))?;
+        if let Ok(mut registry) = WorkspaceRegistry::load() {
+            let mut record =
+                WorkspaceRecord::for_issue(issue, path.to_path_buf(), SystemTime::now());
+            record.base_ref = base_ref;
+            if registry.insert(record) {
+                let _ = registry.save();
+            }
+        }
+        Ok(Some(Integrity::Recreated))
+    }
+}
+
+/// Rename `path` to a hidden sibling so a fresh worktree can take its place.
+fn set_aside(path: &Path) -> Result<PathBuf> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let aside = (1..u32::MAX)
+        .map(|n| path.with_file_name(format!(".{name}.broken-{n}")))
+        .find(|p| !p.exists())
+        .context("No free name to set the broken worktree aside")?;
+    std::fs::rename(path, &aside)
+        .with_context(|| format!("Failed to move {} aside", path.display()))?;
+    Ok(aside)
+}
+
+/// Move every file under `from` into `to`, replacing what is there. The old
+/// `.git` at the top is left behind.
+fn copy_back(from: &Path, to: &Path, top: bool) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let (src, dst) = (entry.path(), to.join(entry.file_name()));
+        if top && entry.file_name() == ".git" {
+            continue;
+        }
+        let dst_is_dir = std::fs::symlink_metadata(&dst).is_ok_and(|m| m.is_dir());
+        if entry.file_type()?.is_dir() && dst_is_dir {
+            copy_back(&src, &dst, false)?;
+            continue;
+        }
+        if dst_is_dir {
+            std::fs::remove_dir_all(&dst)?;
+        }
+        std::fs::rename(&src, &dst)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "recreate_tests.rs"]
+mod tests;
@@ -0,0 +1,42 @@
+use super::*;
+
+fn write(path: &Path, body: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, body).unwrap();
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_set_aside_picks_a_free_hidden_sibling() {
+    let dir = tempfile::tempdir().unwrap();
+    let wt = dir.path().join("issue-1");
+    std::fs::create_dir(dir.path().join(".issue-1.broken-1")).unwrap();
+    write(&wt.join("f"), "x");
+    let aside = set_aside(&wt).unwrap();
+    assert_eq!(aside, dir.path().join(".issue-1.broken-2"));
+    assert!(!wt.exists() && aside.join("f").exists());
+}
+
+#[test]
+fn test_copy_back_keeps_local_files_over_the_checkout() {
+    let dir = tempfile::tempdir().unwrap();
+    let (from, to) = (dir.path().join("from"), dir.path().join("to"));
+    write(&from.join(".git"), "stale");
+    write(&from.join("a.txt"), "mine");
+    write(&from.join("dir/b.txt"), "mine");
+    write(&from.join("new/c.txt"), "mine");
+    write(&to.join(".git"), "gitdir: live");
+    write(&to.join("a.txt"), "checkout");
+    write(&to.join("dir/b.txt"), "checkout");
+    write(&to.join("dir/d.txt"), "checkout");
+
+    copy_back(&from, &to, true).unwrap();
+    assert_eq!(read(&to.join(".git")), "gitdir: live");
+    assert_eq!(read(&to.join("a.txt")), "mine");
+    assert_eq!(read(&to.join("dir/b.txt")), "mine");
+    assert_eq!(read(&to.join("dir/d.txt")), "checkout");
+    assert_eq!(read(&to.join("new/c.txt")), "mine");
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use std::path::Path;
+use std::time::SystemTime;
+
+use super::Workspace;
+use crate::issue::IssueRef;
+use crate::lifecycle::wake_workspace;
+use crate::ttl::WorkspaceRegistry;
+
+impl Workspace {
+    /// Rehydrate the workspace at `path` when the registry has it marked
+    /// hibernated; `None` when there is nothing to wake.
+    pub(super) fn wake(issue: &IssueRef, path: &Path) -> Result<Option<Self>> {
+        let Ok(mut registry) = WorkspaceRegistry::load() else {
+            return Ok(None);
+        };
+        let Some(record) = registry.get_mut(path).filter(|r| r.hibernated.is_some()) else {
+            return Ok(None);
+        };
+        eprintln!("Waking hibernated workspace at {}…", path.display());
+        wake_workspace(record)?;
+        record.last_opened_at = Some(SystemTime::now());
+        registry.save()?;
+        Ok(Some(Self {
+            path: path.to_path_buf(),
+            issue: issue.clone(),
+            created: false,
+            woke: true,
+            integrity: None,
+        }))
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+use crate::issue::IssueRef;
+use crate::test_support::git_in;
+
+#[test]
+fn test_open_or_create_existing() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().join("__test_wt__");
+    std::fs::create_dir(&repo).unwrap();
+    git_in(&repo, &["init", "-b", "main"]);
+    git_in(&repo, &["commit", "--allow-empty", "-m", "init"]);
+    let issue = IssueRef::Local {
+        project_path: repo.clone(),
+        display_number: 9999,
+    };
+    let path = issue.temp_path();
+    let branch = issue.branch_name();
+    git_in(
+        &repo,
+        &["worktree", "add", path.to_str().unwrap(), "-b", &branch],
+    );
+    let ws = Workspace::open_or_create(issue).unwrap();
+    assert!(!ws.created);
+    assert!(ws.integrity.is_none());
+    std::fs::remove_dir_all(path.parent().unwrap()).ok();
+}
@@ -0,0 +1,32 @@
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// First line of every `.envrc` this tool writes.
+const HEADER: &str = "# Generated by worktree from the workspace environment; rewritten on open.\n";
+
+/// Write `env` to `<wt>/.envrc` for direnv. An `.envrc` this tool did not
+/// write is left alone; returns whether the file was written.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn write_envrc(wt: &Path, env: &[(String, String)]) -> Result<bool> {
+    let path = wt.join(".envrc");
+    if path.exists() && !is_generated_envrc(wt) {
+        return Ok(false);
+    }
+    let mut body = HEADER.to_owned();
+    for (k, v) in env {
+        writeln!(body, "export {k}='{}'", v.replace('\'', "'\\''")).unwrap();
+    }
+    std::fs::write(&path, body).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(true)
+}
+
+/// `true` when `<wt>/.envrc` was written by [`write_envrc`], so it is not
+/// user work.
+#[must_use]
+pub fn is_generated_envrc(wt: &Path) -> bool {
+    std::fs::read_to_string(wt.join(".envrc")).is_ok_and(|s| s.starts_with(HEADER))
+}
@@ -0,0 +1,40 @@
+/// Script lines setting `env` in the bootstrap a terminal runs, so the shell
+/// it opens inherits them. Names that are not valid identifiers are skipped.
+///
+/// On Windows the bootstrap is a batch file: values are caret-escaped, and
+/// values spanning several lines, which a `set` line cannot hold, are skipped.
+#[must_use]
+pub fn env_exports(env: &[(String, String)]) -> String {
+    env.iter()
+        .filter(|(k, _)| super::is_var_name(k))
+        .filter_map(|(k, v)| {
+            if cfg!(windows) {
+                batch_set(k, v)
+            } else {
+                Some(format!("export {k}='{}'\n", v.replace('\'', "'\\''")))
+            }
+        })
+        .collect()
+}
+
+/// A batch-file line setting `k` to `v`, or `None` when `v` spans lines.
+fn batch_set(k: &str, v: &str) -> Option<String> {
+    if v.contains(['\r', '\n']) {
+        return None;
+    }
+    let mut line = format!("set {k}=");
+    for c in v.chars() {
+        match c {
+            '%' => line.push('%'),
+            '^' | '"' | '&' | '|' | '<' | '>' | '(' | ')' => line.push('^'),
+            _ => {}
+        }
+        line.push(c);
+    }
+    line.push_str("\r\n");
+    Some(line)
+}
+
+#[cfg(test)]
+#[path = "exports_tests.rs"]
+mod tests;
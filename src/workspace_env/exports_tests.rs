@@ -0,0 +1,27 @@
+use super::*;
+
+#[cfg(not(windows))]
+#[test]
+fn test_env_exports_quotes_values_and_skips_bad_names() {
+    let env = [
+        ("CARGO_TARGET_DIR".to_owned(), "/c/it's".to_owned()),
+        ("BAD;NAME".to_owned(), "x".to_owned()),
+        ("1X".to_owned(), "x".to_owned()),
+    ];
+    assert_eq!(env_exports(&env), "export CARGO_TARGET_DIR='/c/it'\\''s'\n");
+    assert_eq!(env_exports(&[]), "");
+}
+
+#[test]
+fn test_batch_set_escapes_special_characters() {
+    assert_eq!(
+        batch_set("A", r#"x" & %PATH% ^ (y)"#).unwrap(),
+        "set A=x^\" ^& %%PATH%% ^^ ^(y^)\r\n"
+    );
+    assert_eq!(batch_set("A", "C:\\dir").unwrap(), "set A=C:\\dir\r\n");
+}
+
+#[test]
+fn test_batch_set_skips_multiline_values() {
+    assert_eq!(batch_set("A", "x\ny"), None);
+}
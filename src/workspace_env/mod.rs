@@ -0,0 +1,75 @@
+//! Per-workspace environment variables.
+//!
+//! Variables given with `open --env` or a deep link's `env=` are saved on the
+//! workspace's registry entry, so later opens keep them. Together with
+//! `WORKTREE_PORT`, the shared cache variables, and the repo's `[env]` table
+//! they are exported to every hook, `worktree run`, and the terminal `open`
+//! starts, and optionally written to an `.envrc` for direnv.
+//!
+//! Names that steer shells, the loader, or git, such as `PATH`, `LD_*`, and
+//! `GIT_*`, are rejected before anything is saved.
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::cache::cache_env;
+use crate::hooks::HookContext;
+use crate::issue::IssueRef;
+use crate::repo_hooks::RepoConfig;
+use crate::ttl::WorkspaceRegistry;
+
+mod envrc;
+mod exports;
+mod names;
+
+pub use envrc::{is_generated_envrc, write_envrc};
+pub use exports::env_exports;
+pub use names::{check_names, is_reserved, is_var_name};
+
+/// Save `pairs` on the registry entry of the workspace at `wt`, replacing
+/// earlier values of the same names.
+///
+/// # Errors
+///
+/// Returns an error if a name is not a valid variable name, is reserved (see
+/// [`is_reserved`]), or the registry cannot be loaded or saved.
+pub fn save_env(wt: &Path, pairs: &[(String, String)]) -> Result<()> {
+    check_names(pairs)?;
+    if pairs.is_empty() {
+        return Ok(());
+    }
+    let mut registry = WorkspaceRegistry::load()?;
+    if let Some(record) = registry.get_mut(wt) {
+        record.env.extend(pairs.iter().cloned());
+        registry.save()?;
+    }
+    Ok(())
+}
+
+/// The environment of the workspace at `wt`.
+///
+/// Later entries win: `WORKTREE_PORT` when `ctx` has a port, the shared cache
+/// variables of `issue`, the repo's `[env]` table rendered with `ctx`, then
+/// the variables saved on its registry entry.
+#[must_use]
+pub fn workspace_env(
+    issue: Option<&IssueRef>,
+    ctx: &HookContext,
+    wt: &Path,
+) -> Vec<(String, String)> {
+    let mut env = BTreeMap::new();
+    if !ctx.port.is_empty() {
+        env.insert("WORKTREE_PORT".to_owned(), ctx.port.clone());
+    }
+    env.extend(issue.map(|i| cache_env(i, wt)).unwrap_or_default());
+    let repo = RepoConfig::load_from(wt).unwrap_or_default();
+    env.extend(repo.env.iter().map(|(k, v)| (k.clone(), ctx.render(v))));
+    if let Ok(registry) = WorkspaceRegistry::load() {
+        env.extend(registry.get(wt).map(|r| r.env.clone()).unwrap_or_default());
+    }
+    env.into_iter().collect()
+}
+
+#[cfg(test)]
+#[path = "workspace_env_tests.rs"]
+mod tests;
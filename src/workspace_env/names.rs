@@ -0,0 +1,51 @@
+use anyhow::Result;
+
+/// Names that change how shells, the dynamic loader, or git behave, so a
+/// deep link must not be able to set them.
+const RESERVED: &[&str] = &[
+    "PATH",
+    "HOME",
+    "SHELL",
+    "IFS",
+    "ENV",
+    "BASH_ENV",
+    "PROMPT_COMMAND",
+    "PS4",
+    "SHELLOPTS",
+    "BASHOPTS",
+];
+
+/// Prefixes reserved for the loader, git, exported shell functions, and
+/// worktree's own `WORKTREE_*` variables.
+const RESERVED_PREFIXES: &[&str] = &["LD_", "DYLD_", "GIT_", "BASH_FUNC_", "WORKTREE_"];
+
+/// `true` when `name` can be exported from a shell: ASCII letters, digits,
+/// and `_`, not starting with a digit.
+#[must_use]
+pub fn is_var_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `true` when `name` is reserved and may not be saved on a workspace.
+#[must_use]
+pub fn is_reserved(name: &str) -> bool {
+    RESERVED.contains(&name) || RESERVED_PREFIXES.iter().any(|p| name.starts_with(p))
+}
+
+/// Check that every name in `pairs` is a valid variable name and not
+/// reserved.
+///
+/// # Errors
+///
+/// Returns an error naming the first invalid or reserved name.
+pub fn check_names(pairs: &[(String, String)]) -> Result<()> {
+    if let Some((bad, _)) = pairs.iter().find(|(k, _)| !is_var_name(k)) {
+        anyhow::bail!("Invalid environment variable name: {bad:?}");
+    }
+    match pairs.iter().find(|(k, _)| is_reserved(k)) {
+        Some((bad, _)) => anyhow::bail!("Reserved environment variable name: {bad:?}"),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+#[test]
+fn var_names() {
+    for ok in ["PATH", "_x", "DATABASE_URL2"] {
+        assert!(is_var_name(ok), "{ok}");
+    }
+    for bad in ["", "1X", "A-B", "A B", "É"] {
+        assert!(!is_var_name(bad), "{bad}");
+    }
+}
+
+#[test]
+fn reserved_names() {
+    for reserved in [
+        "PATH",
+        "BASH_ENV",
+        "PROMPT_COMMAND",
+        "LD_PRELOAD",
+        "DYLD_X",
+        "GIT_SSH_COMMAND",
+        "WORKTREE_PORT",
+    ] {
+        assert!(is_reserved(reserved), "{reserved}");
+    }
+    for ok in ["DATABASE_URL", "PATHS", "GITHUB_TOKEN", "NODE_ENV"] {
+        assert!(!is_reserved(ok), "{ok}");
+    }
+}
+
+#[test]
+fn save_env_rejects_reserved_names() {
+    let pairs = [("LD_PRELOAD".to_owned(), "/tmp/x.so".to_owned())];
+    let err = save_env(Path::new("/nonexistent"), &pairs).unwrap_err();
+    assert!(err.to_string().contains("Reserved"), "{err}");
+    assert!(err.to_string().contains("\"LD_PRELOAD\""), "{err}");
+}
+
+#[test]
+fn save_env_rejects_invalid_names() {
+    let pairs = [("A-B".to_owned(), "1".to_owned())];
+    let err = save_env(Path::new("/nonexistent"), &pairs).unwrap_err();
+    assert!(err.to_string().contains("\"A-B\""), "{err}");
+}
+
+#[test]
+fn envrc_is_written_and_rewritten() {
+    let dir = tempfile::tempdir().unwrap();
+    let env = [("GREETING".to_owned(), "it's here".to_owned())];
+    assert!(write_envrc(dir.path(), &env).unwrap());
+    let body = std::fs::read_to_string(dir.path().join(".envrc")).unwrap();
+    assert!(
+        body.ends_with("export GREETING='it'\\''s here'\n"),
+        "{body}"
+    );
+    assert!(is_generated_envrc(dir.path()));
+    assert!(write_envrc(dir.path(), &[]).unwrap());
+    assert!(!std::fs::read_to_string(dir.path().join(".envrc"))
+        .unwrap()
+        .contains("GREETING"));
+}
+
+#[test]
+fn user_envrc_is_left_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join(".envrc"), "use nix\n").unwrap();
+    assert!(!is_generated_envrc(dir.path()));
+    assert!(!write_envrc(dir.path(), &[("A".into(), "1".into())]).unwrap());
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join(".envrc")).unwrap(),
+        "use nix\n"
+    );
+}
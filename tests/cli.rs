@@ -21,14 +21,23 @@ fn run(home: &Path, args: &[&str]) -> std::process::Output {
         .unwrap()
 }
 
+/// Set up a bare clone with a live worktree for `issue`, as `open` would.
 fn pre_create_workspace(home: &Path, owner: &str, repo: &str, issue: u64) -> PathBuf {
-    let wt = home
-        .join("worktrees")
-        .join("github")
-        .join(owner)
-        .join(repo)
-        .join(format!("issue-{issue}"));
-    std::fs::create_dir_all(&wt).unwrap();
+    setup_bare_clone(home, owner, repo);
+    let bare = home.join("worktrees").join("github").join(owner).join(repo);
+    let wt = bare.join(format!("issue-{issue}"));
+    let branch = format!("issue-{issue}");
+    git_in(
+        &bare,
+        &[
+            "worktree",
+            "add",
+            wt.to_str().unwrap(),
+            "-b",
+            &branch,
+            "main",
+        ],
+    );
     wt
 }
 
@@ -332,14 +341,21 @@ fn test_prune_removes_expired() {
 fn test_prune_warns_on_remove_failure() {
     let h = temp_home("prune_fail");
     write_config(&h, "[workspace]\nttl = \"1s\"\n");
-    let ws = h.join("not-a-dir");
-    std::fs::write(&ws, "file").unwrap();
+    let ws = h.join("untrashable");
+    std::fs::create_dir_all(&ws).unwrap();
+    let trash = h.join(".local/share/worktree/trash");
+    std::fs::create_dir_all(trash.parent().unwrap()).unwrap();
+    std::fs::write(&trash, "not a directory").unwrap();
     write_registry(&h, &[(&ws, "2000-01-01T00:00:00Z")]);
     let out = run(&h, &["prune"]);
     assert!(out.status.success());
     let stderr = String::from_utf8_lossy(&out.stderr);
     assert!(stderr.contains("Warning: failed to remove"));
-    assert!(stderr.contains("Pruned 1 expired workspace(s)"));
+    assert!(stderr.contains("Pruned 0 expired workspace(s)"));
+    assert!(stderr.contains("Failed to prune 1 expired workspace(s)"));
+    assert!(ws.exists());
+    let registry = std::fs::read_to_string(h.join(".config/worktree/workspaces.toml")).unwrap();
+    assert!(registry.contains("untrashable"), "{registry}");
     std::fs::remove_dir_all(&h).ok();
 }
 
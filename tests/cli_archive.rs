@@ -0,0 +1,88 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    add_worktree, commit_file, git_out, read_registry, run, setup_bare_clone, stderr, temp_home,
+    write_config, write_registry,
+};
+
+/// The archive id from an "Archived unsaved work … as <id>;" line.
+fn archive_id(err: &str) -> String {
+    let line = err
+        .lines()
+        .find(|l| l.starts_with("Archived"))
+        .unwrap_or_default();
+    let (_, rest) = line.split_once(" as ").unwrap_or_default();
+    rest.split(';').next().unwrap_or_default().to_owned()
+}
+
+#[test]
+fn test_forced_remove_archives_and_unarchive_restores() {
+    let h = temp_home("archive_remove");
+    let bare = setup_bare_clone(&h, "__ar__", "__ar__");
+    let wt = add_worktree(&bare, "issue-1");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    commit_file(&wt, "local-only");
+    std::fs::write(wt.join("f"), "edited").unwrap();
+    std::fs::write(wt.join("scratch"), "untracked").unwrap();
+    let out = run(
+        &h,
+        &["remove", wt.to_str().unwrap(), "--force", "--delete-branch"],
+    );
+    let err = stderr(&out);
+    assert!(out.status.success(), "remove failed: {err}");
+    assert!(!wt.exists());
+    let id = archive_id(&err);
+    assert!(!id.is_empty(), "{err}");
+    let archive = h.join(".local/share/worktree/archive").join(&id);
+    assert!(archive.join("archive.toml").exists());
+
+    let out = run(&h, &["unarchive"]);
+    assert!(stderr(&out).contains(&id), "{}", stderr(&out));
+    let out = run(&h, &["unarchive", &id]);
+    assert!(out.status.success(), "unarchive failed: {}", stderr(&out));
+    assert_eq!(std::fs::read_to_string(wt.join("f")).unwrap(), "edited");
+    assert!(wt.join("scratch").exists() && wt.join("local-only").exists());
+    assert_eq!(
+        git_out(&wt, &["rev-parse", "--abbrev-ref", "HEAD"]),
+        "issue-1"
+    );
+    assert!(read_registry(&h).contains("issue-1"));
+    assert!(!archive.exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_forced_prune_archives_unsaved_work() {
+    let h = temp_home("archive_prune");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__ap__", "__ap__");
+    let wt = add_worktree(&bare, "issue-2");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    std::fs::write(wt.join("f"), "edited").unwrap();
+    let out = run(&h, &["prune", "--force"]);
+    let err = stderr(&out);
+    assert!(out.status.success(), "prune failed: {err}");
+    assert!(!wt.exists());
+    let id = archive_id(&err);
+    let out = run(&h, &["unarchive", &id]);
+    assert!(out.status.success(), "unarchive failed: {}", stderr(&out));
+    assert_eq!(std::fs::read_to_string(wt.join("f")).unwrap(), "edited");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_unarchive_unknown_id_fails() {
+    let h = temp_home("archive_unknown");
+    let out = run(&h, &["unarchive", "nope-1"]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("No archive \"nope-1\""),
+        "{}",
+        stderr(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,71 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{run, setup_bare_clone, stderr, temp_home, write_config};
+
+#[test]
+fn test_open_shares_caches_and_exports_env_to_hooks() {
+    let h = temp_home("cache_share");
+    let bare = setup_bare_clone(&h, "__ca1__", "__ca1__");
+    write_config(
+        &h,
+        "[hooks]\n\"pre:open\" = \"echo \\\"$CARGO_TARGET_DIR\\\" > '{{worktree_path}}/target.txt'\"\n\
+         [cache]\nsibling = [\"node_modules\"]\n\
+         [cache.env]\nCARGO_TARGET_DIR = \"target/{{base}}\"\n\
+         [cache.link]\n\".turbo\" = \"turbo\"\n",
+    );
+    let out = run(&h, &["open", "--headless", "__ca1__/__ca1__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(
+        err.contains("Shared caches: 1 variable(s), 1 link(s), 0 cloned"),
+        "{err}"
+    );
+
+    let root = h.join(".cache/worktree/__ca1__/__ca1__");
+    let first = bare.join("issue-1");
+    let target = std::fs::read_to_string(first.join("target.txt")).unwrap();
+    assert_eq!(
+        target.trim(),
+        root.join("target/main").display().to_string()
+    );
+    assert!(root.join("target/main").is_dir());
+    #[cfg(unix)]
+    assert_eq!(
+        std::fs::read_link(first.join(".turbo")).unwrap(),
+        root.join("turbo")
+    );
+
+    std::fs::create_dir_all(first.join("node_modules/pkg")).unwrap();
+    std::fs::write(first.join("node_modules/pkg/index.js"), "built").unwrap();
+    let out = run(&h, &["open", "--headless", "__ca1__/__ca1__#2"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    assert!(
+        stderr(&out).contains("1 cloned from sibling worktrees"),
+        "{}",
+        stderr(&out)
+    );
+    let second = bare.join("issue-2");
+    let copied = std::fs::read_to_string(second.join("node_modules/pkg/index.js")).unwrap();
+    assert_eq!(copied, "built");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_warns_on_invalid_cache_config() {
+    let h = temp_home("cache_invalid");
+    setup_bare_clone(&h, "__ca2__", "__ca2__");
+    write_config(&h, "[cache.link]\n\"../outside\" = \"x\"\n");
+    let out = run(&h, &["open", "--headless", "__ca2__/__ca2__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(
+        err.contains("warning: could not set up shared caches"),
+        "{err}"
+    );
+    assert!(err.contains("must stay inside the worktree"), "{err}");
+    std::fs::remove_dir_all(&h).ok();
+}
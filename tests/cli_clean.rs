@@ -0,0 +1,74 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::{run, setup_bare_clone, stderr, stdout, temp_home, write_config};
+
+/// Open `owner/repo#issue` headlessly and give it a Rust build directory
+/// that git ignores. Returns the worktree path.
+fn open_built_issue(h: &Path, slug: &str, issue: u64) -> PathBuf {
+    let bare = setup_bare_clone(h, slug, slug);
+    let out = run(
+        h,
+        &["open", "--headless", &format!("{slug}/{slug}#{issue}")],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let wt = bare.join(format!("issue-{issue}"));
+    std::fs::write(wt.join("Cargo.toml"), "[package]\n").unwrap();
+    std::fs::write(wt.join(".gitignore"), "target/\n").unwrap();
+    std::fs::create_dir_all(wt.join("target/debug")).unwrap();
+    std::fs::write(wt.join("target/debug/app"), vec![0u8; 4096]).unwrap();
+    wt
+}
+
+#[test]
+fn test_clean_deletes_ignored_artifacts_of_idle_workspaces() {
+    let h = temp_home("clean_idle");
+    let wt = open_built_issue(&h, "__cl1__", 1);
+    write_config(&h, "[workspace]\nclean_after = \"1s\"\n");
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+
+    let out = run(&h, &["clean", "--dry-run"]);
+    assert!(out.status.success(), "clean failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("Would clean"), "{}", stderr(&out));
+    assert!(wt.join("target").exists());
+
+    let out = run(&h, &["clean"]);
+    assert!(out.status.success(), "clean failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(err.contains("target (freed: 4.0 KB)"), "{err}");
+    assert!(err.contains("Cleaned 1 idle workspace(s)"), "{err}");
+    assert!(!wt.join("target").exists());
+    assert!(wt.join("Cargo.toml").exists() && wt.join("f").exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_clean_skips_recently_opened_workspaces_and_reports_json() {
+    let h = temp_home("clean_recent");
+    let wt = open_built_issue(&h, "__cl2__", 2);
+    let out = run(&h, &["clean", "--idle", "1day", "--json"]);
+    assert!(out.status.success(), "clean failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"cleaned\":[]"), "{json}");
+    assert!(json.contains("\"idle\":\"1day\""), "{json}");
+    assert!(wt.join("target").exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_clean_requires_idle_time() {
+    let h = temp_home("clean_no_idle");
+    let out = run(&h, &["clean"]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("workspace.clean_after"),
+        "{}",
+        stderr(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,73 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    git_out, read_registry, run, setup_bare_clone, stderr, temp_home, write_config, write_registry,
+};
+
+#[test]
+fn test_doctor_passes_on_a_fresh_home() {
+    let h = temp_home("doctor_fresh");
+    let out = run(&h, &["doctor"]);
+    assert!(out.status.success(), "doctor failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(err.contains("PASS  git: git version"), "{err}");
+    assert!(err.contains("PASS  config"), "{err}");
+    assert!(err.contains("0 failed."), "{err}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_doctor_fails_on_bad_config_and_unknown_editor() {
+    let h = temp_home("doctor_bad_config");
+    write_config(&h, "[workspace\n");
+    let out = run(&h, &["doctor"]);
+    assert!(!out.status.success());
+    assert!(stderr(&out).contains("FAIL  config"), "{}", stderr(&out));
+
+    write_config(&h, "[editor]\ncommand = \"__no_such_editor__ .\"\n");
+    let out = run(&h, &["doctor"]);
+    assert!(!out.status.success());
+    let err = stderr(&out);
+    assert!(err.contains("FAIL  editor: `__no_such_editor__`"), "{err}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_doctor_fix_prunes_stale_worktrees_and_drops_lost_entries() {
+    let h = temp_home("doctor_fix");
+    let bare = setup_bare_clone(&h, "__doc__", "__doc__");
+    assert!(run(&h, &["open", "--headless", "__doc__/__doc__#1"])
+        .status
+        .success());
+    let wt = bare.join("issue-1");
+    std::fs::remove_dir_all(&wt).unwrap();
+    let lost = h.join("lost");
+    let mut reg = read_registry(&h);
+    write_registry(&h, &[(&lost, "2024-01-01T00:00:00Z")]);
+    reg.push_str(&read_registry(&h));
+    std::fs::write(common::registry_path(&h), reg).unwrap();
+
+    let out = run(&h, &["doctor"]);
+    let err = stderr(&out);
+    assert!(err.contains("`worktree restore` rebuilds them"), "{err}");
+    assert!(
+        err.contains("cannot be restored; `--fix` drops them"),
+        "{err}"
+    );
+    assert!(err.contains("1 stale entries"), "{err}");
+    assert!(err.contains("doctor --fix"), "{err}");
+
+    let out = run(&h, &["doctor", "--fix"]);
+    assert!(out.status.success(), "doctor failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(err.contains("pruned 1 stale entries"), "{err}");
+    let reg = read_registry(&h);
+    assert!(!reg.contains("/lost\"") && reg.contains("issue-1"), "{reg}");
+    let worktrees = git_out(&bare, &["worktree", "list", "--porcelain"]);
+    assert!(!worktrees.contains("prunable"), "{worktrees}");
+    std::fs::remove_dir_all(&h).ok();
+}
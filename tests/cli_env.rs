@@ -0,0 +1,121 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{run, setup_bare_clone, stderr, stdout, temp_home, write_config};
+
+#[test]
+fn test_open_env_persists_into_later_hooks_and_run() {
+    let h = temp_home("env_persist");
+    let bare = setup_bare_clone(&h, "__en1__", "__en1__");
+    write_config(
+        &h,
+        "[hooks]\n\"pre:open\" = \"echo \\\"$GREETING $DB\\\" > '{{worktree_path}}/env.txt'\"\n",
+    );
+    let out = run(
+        &h,
+        &[
+            "open",
+            "--headless",
+            "--env",
+            "GREETING=hi",
+            "__en1__/__en1__#1",
+        ],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let wt = bare.join("issue-1");
+    assert_eq!(
+        std::fs::read_to_string(wt.join("env.txt")).unwrap().trim(),
+        "hi"
+    );
+
+    std::fs::write(wt.join(".worktree.toml"), "[env]\nDB = \"db_{{issue}}\"\n").unwrap();
+    let out = run(&h, &["open", "--headless", "__en1__/__en1__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let env = std::fs::read_to_string(wt.join("env.txt")).unwrap();
+    assert_eq!(env.trim(), "hi db_1");
+
+    let script = "echo \"$GREETING $DB $WORKTREE_ISSUE\"; pwd";
+    let out = run(&h, &["run", "__en1__/__en1__#1", "--", "sh", "-c", script]);
+    assert!(out.status.success(), "run failed: {}", stderr(&out));
+    let printed = stdout(&out);
+    assert!(printed.starts_with("hi db_1 1\n"), "{printed}");
+    assert!(printed.contains("issue-1"), "{printed}");
+
+    let out = run(
+        &h,
+        &["run", "__en1__/__en1__#1", "--", "sh", "-c", "exit 3"],
+    );
+    assert_eq!(out.status.code(), Some(3));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_writes_envrc_when_enabled() {
+    let h = temp_home("env_envrc");
+    let bare = setup_bare_clone(&h, "__en2__", "__en2__");
+    write_config(&h, "[open]\neditor = false\nenvrc = true\n");
+    let out = run(
+        &h,
+        &[
+            "open",
+            "--headless",
+            "--env",
+            "API_URL=http://localhost",
+            "__en2__/__en2__#1",
+        ],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let envrc = std::fs::read_to_string(bare.join("issue-1/.envrc")).unwrap();
+    assert!(envrc.starts_with("# Generated by worktree"), "{envrc}");
+    assert!(
+        envrc.contains("export API_URL='http://localhost'\n"),
+        "{envrc}"
+    );
+
+    let out = run(&h, &["remove", "__en2__/__en2__#1"]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_rejects_invalid_env_name() {
+    let h = temp_home("env_invalid");
+    let bare = setup_bare_clone(&h, "__en3__", "__en3__");
+    let out = run(
+        &h,
+        &[
+            "open",
+            "--headless",
+            "--env",
+            "NOT-VALID=1",
+            "__en3__/__en3__#1",
+        ],
+    );
+    assert!(!out.status.success());
+    assert!(!bare.join("issue-1").exists());
+    assert!(
+        stderr(&out).contains("Invalid environment variable name"),
+        "{}",
+        stderr(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_deep_link_rejects_reserved_env_name() {
+    let h = temp_home("env_reserved");
+    let bare = setup_bare_clone(&h, "__en4__", "__en4__");
+    let url = "worktree://open?owner=__en4__&repo=__en4__&issue=1&env=LD_PRELOAD:/tmp/x.so";
+    let out = run(&h, &["open", "--headless", url]);
+    assert!(!out.status.success());
+    assert!(!bare.join("issue-1").exists());
+    assert!(
+        stderr(&out).contains("Reserved environment variable name: \"LD_PRELOAD\""),
+        "{}",
+        stderr(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,64 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{add_worktree, run, setup_bare_clone, stderr, temp_home, write_config};
+
+#[test]
+fn test_gc_removes_unused_bare_clone_after_grace() {
+    let h = temp_home("gc_orphan");
+    let bare = setup_bare_clone(&h, "__gco__", "__gco__");
+    let out = run(&h, &["gc", "--grace", "0s"]);
+    assert!(out.status.success(), "gc failed: {}", stderr(&out));
+    assert!(!bare.exists());
+    // The now-empty owner directory is cleaned up too.
+    assert!(!bare.parent().unwrap().exists());
+    assert!(h.join("worktrees").exists());
+    let err = stderr(&out);
+    assert!(err.contains("Removed unused bare clone"), "stderr: {err}");
+    assert!(err.contains("removed 1, compacted 0"), "stderr: {err}");
+    assert!(err.contains("Total freed:"), "stderr: {err}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_gc_keeps_recent_unused_clone_within_grace() {
+    let h = temp_home("gc_grace");
+    write_config(&h, "[workspace]\ngc_grace = \"30days\"\n");
+    let bare = setup_bare_clone(&h, "__gcg__", "__gcg__");
+    let out = run(&h, &["gc"]);
+    assert!(out.status.success(), "gc failed: {}", stderr(&out));
+    assert!(bare.exists());
+    let err = stderr(&out);
+    assert!(err.contains("Keeping unused bare clone"), "stderr: {err}");
+    assert!(err.contains("grace: 30days"), "stderr: {err}");
+    assert!(err.contains("compacted 1"), "stderr: {err}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_gc_compacts_clone_with_worktrees() {
+    let h = temp_home("gc_in_use");
+    let bare = setup_bare_clone(&h, "__gcu__", "__gcu__");
+    let wt = add_worktree(&bare, "issue-1");
+    let out = run(&h, &["gc", "--grace", "0s"]);
+    assert!(out.status.success(), "gc failed: {}", stderr(&out));
+    assert!(wt.exists());
+    let err = stderr(&out);
+    assert!(err.contains("Compacted"), "stderr: {err}");
+    assert!(
+        err.contains("Checked 1 bare clone(s): removed 0"),
+        "stderr: {err}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_gc_invalid_grace() {
+    let h = temp_home("gc_bad_grace");
+    let out = run(&h, &["gc", "--grace", "soon"]);
+    assert!(!out.status.success());
+    std::fs::remove_dir_all(&h).ok();
+}
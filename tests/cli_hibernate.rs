@@ -0,0 +1,79 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::{read_registry, run, setup_bare_clone, stderr, temp_home, write_config};
+
+/// Open `owner/repo#issue` headlessly and return `(bare, worktree)` paths.
+fn open_issue(h: &Path, slug: &str, issue: u64) -> (PathBuf, PathBuf) {
+    let bare = setup_bare_clone(h, slug, slug);
+    let out = run(
+        h,
+        &["open", "--headless", &format!("{slug}/{slug}#{issue}")],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    (bare.clone(), bare.join(format!("issue-{issue}")))
+}
+
+#[test]
+fn test_hibernate_then_open_wakes_with_local_changes() {
+    let h = temp_home("hibernate_wake");
+    let (_, wt) = open_issue(&h, "__hib1__", 1);
+    std::fs::write(wt.join("f"), "edited").unwrap();
+    std::fs::write(wt.join("notes.txt"), "untracked").unwrap();
+    let out = run(&h, &["hibernate", "__hib1__/__hib1__#1"]);
+    assert!(out.status.success(), "hibernate failed: {}", stderr(&out));
+    assert!(!wt.exists());
+    assert!(read_registry(&h).contains("[workspace.hibernated]"));
+    let out = run(&h, &["list"]);
+    assert!(stderr(&out).contains("HIBERNATED"), "{}", stderr(&out));
+
+    let out = run(&h, &["open", "--headless", "__hib1__/__hib1__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("Woke"), "{}", stderr(&out));
+    assert_eq!(std::fs::read_to_string(wt.join("f")).unwrap(), "edited");
+    assert!(wt.join("notes.txt").exists());
+    assert!(!read_registry(&h).contains("hibernated"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_hibernate_keeps_registry_entry() {
+    let h = temp_home("hibernate_prune");
+    let (_, wt) = open_issue(&h, "__hib2__", 2);
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    let out = run(&h, &["prune", "--hibernate"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("Hibernated"), "{}", stderr(&out));
+    assert!(!wt.exists());
+    let reg = read_registry(&h);
+    assert!(
+        reg.contains("hibernated") && !reg.contains("trashed_at"),
+        "{reg}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_hibernated_with_stash_requires_force() {
+    let h = temp_home("hibernate_remove");
+    let (bare, wt) = open_issue(&h, "__hib3__", 3);
+    std::fs::write(wt.join("f"), "edited").unwrap();
+    assert!(run(&h, &["hibernate", wt.to_str().unwrap()])
+        .status
+        .success());
+    let out = run(&h, &["remove", wt.to_str().unwrap()]);
+    assert!(!out.status.success());
+    assert!(stderr(&out).contains("hibernated"), "{}", stderr(&out));
+    let out = run(&h, &["remove", "--force", wt.to_str().unwrap()]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert!(!read_registry(&h).contains("__hib3__"));
+    let refs = common::git_out(&bare, &["for-each-ref", "refs/hibernate"]);
+    assert!(refs.is_empty(), "{refs}");
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,71 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{add_worktree, read_registry, run, setup_bare_clone, stderr, temp_home};
+
+#[test]
+fn test_import_registers_manual_worktrees() {
+    let h = temp_home("import_manual");
+    let bare = setup_bare_clone(&h, "__im__", "__im__");
+    let issue = add_worktree(&bare, "issue-7");
+    let adhoc = add_worktree(&bare, "bold_turing");
+    let out = run(&h, &["import"]);
+    assert!(out.status.success(), "import failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("imported 2 worktree(s)"));
+    let reg = read_registry(&h);
+    assert!(reg.contains(issue.file_name().unwrap().to_str().unwrap()));
+    assert!(reg.contains(adhoc.file_name().unwrap().to_str().unwrap()));
+    assert!(reg.contains("type = \"github\""), "registry: {reg}");
+    assert!(reg.contains("number = 7"), "registry: {reg}");
+    assert!(reg.contains("type = \"adhoc\""), "registry: {reg}");
+    assert!(reg.contains("branch = \"issue-7\""), "registry: {reg}");
+    assert!(reg.contains("host = \"github.com\""), "registry: {reg}");
+    assert!(
+        reg.contains(&format!("bare_path = \"{}\"", bare.display())),
+        "registry: {reg}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_import_is_idempotent() {
+    let h = temp_home("import_twice");
+    let bare = setup_bare_clone(&h, "__im2__", "__im2__");
+    add_worktree(&bare, "issue-1");
+    assert!(run(&h, &["import"]).status.success());
+    let out = run(&h, &["import"]);
+    assert!(out.status.success());
+    assert!(stderr(&out).contains("imported 0 worktree(s)"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_import_extra_local_repo() {
+    let h = temp_home("import_local");
+    let bare = setup_bare_clone(&h, "__im3__", "__im3__");
+    let src = h.join("_src_");
+    let wt = h.join("elsewhere-issue-3");
+    common::git_in(
+        &src,
+        &["worktree", "add", wt.to_str().unwrap(), "-b", "issue-3"],
+    );
+    std::fs::remove_dir_all(&bare).unwrap();
+    let out = run(&h, &["import", src.to_str().unwrap()]);
+    assert!(out.status.success(), "import failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(reg.contains("elsewhere-issue-3"), "registry: {reg}");
+    assert!(reg.contains("type = \"local\""), "registry: {reg}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_import_missing_repo_fails() {
+    let h = temp_home("import_missing");
+    let out = run(&h, &["import", "/nonexistent/repo/for/import"]);
+    assert!(!out.status.success());
+    assert!(stderr(&out).contains("Repository not found"));
+    std::fs::remove_dir_all(&h).ok();
+}
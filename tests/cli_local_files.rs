@@ -0,0 +1,98 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{run, setup_bare_clone, stderr, temp_home, write_config};
+
+#[test]
+fn test_open_copies_and_links_template_files() {
+    let h = temp_home("local_files_template");
+    let bare = setup_bare_clone(&h, "__lf1__", "__lf1__");
+    let template = h.join(".config/worktree/templates/__lf1__/__lf1__");
+    std::fs::create_dir_all(template.join("config")).unwrap();
+    std::fs::create_dir_all(template.join(".idea")).unwrap();
+    std::fs::write(template.join(".env"), "SECRET=1").unwrap();
+    std::fs::write(template.join("config/local.yml"), "local").unwrap();
+    std::fs::write(template.join("f"), "not mine").unwrap();
+    write_config(
+        &h,
+        "[files]\nsource = \"template\"\ncopy = [\".env\", \"config/*.yml\", \"f\"]\nsymlink = [\".idea\"]\n",
+    );
+
+    let out = run(&h, &["open", "--headless", "__lf1__/__lf1__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    assert!(
+        stderr(&out).contains("Copied 2 and symlinked 1 local file(s)"),
+        "{}",
+        stderr(&out)
+    );
+    let wt = bare.join("issue-1");
+    assert_eq!(
+        std::fs::read_to_string(wt.join(".env")).unwrap(),
+        "SECRET=1"
+    );
+    assert_eq!(
+        std::fs::read_to_string(wt.join("config/local.yml")).unwrap(),
+        "local"
+    );
+    assert_ne!(std::fs::read_to_string(wt.join("f")).unwrap(), "not mine");
+    #[cfg(unix)]
+    assert_eq!(
+        std::fs::read_link(wt.join(".idea")).unwrap(),
+        template.join(".idea")
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_copies_from_primary_and_other_workspace() {
+    let h = temp_home("local_files_primary");
+    let bare = setup_bare_clone(&h, "__lf2__", "__lf2__");
+    let primary = h.join("checkout");
+    std::fs::create_dir(&primary).unwrap();
+    std::fs::write(primary.join(".env"), "FROM=primary").unwrap();
+    let config = format!(
+        "[files]\ncopy = [\".env\"]\n[files.primary]\n\"__lf2__/__lf2__\" = {:?}\n",
+        primary.display().to_string()
+    );
+    write_config(&h, &config);
+    let out = run(&h, &["open", "--headless", "__lf2__/__lf2__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let first = bare.join("issue-1");
+    assert_eq!(
+        std::fs::read_to_string(first.join(".env")).unwrap(),
+        "FROM=primary"
+    );
+
+    std::fs::write(first.join(".env"), "FROM=issue-1").unwrap();
+    let config = config.replace(
+        "[files]\n",
+        "[files]\nsource = \"workspace:__lf2__/__lf2__#1\"\n",
+    );
+    write_config(&h, &config);
+    let out = run(&h, &["open", "--headless", "__lf2__/__lf2__#2"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let second = bare.join("issue-2");
+    assert_eq!(
+        std::fs::read_to_string(second.join(".env")).unwrap(),
+        "FROM=issue-1"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_warns_when_source_is_missing() {
+    let h = temp_home("local_files_missing");
+    setup_bare_clone(&h, "__lf3__", "__lf3__");
+    write_config(&h, "[files]\nsource = \"primary\"\ncopy = [\".env\"]\n");
+    let out = run(&h, &["open", "--headless", "__lf3__/__lf3__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(
+        err.contains("warning: could not copy local files: No primary worktree"),
+        "{err}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
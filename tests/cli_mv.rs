@@ -0,0 +1,82 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    add_worktree, git_out, read_registry, run, setup_bare_clone, stderr, stdout, temp_home,
+    write_config, write_registry,
+};
+
+#[test]
+fn test_mv_adhoc_workspace_to_issue_renames_branch() {
+    let h = temp_home("mv_issue");
+    let bare = setup_bare_clone(&h, "__mv1__", "__mv1__");
+    let wt = add_worktree(&bare, "bold_turing");
+    std::fs::write(wt.join("notes.txt"), "mine").unwrap();
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    let from = wt.to_str().unwrap();
+    let out = run(&h, &["mv", from, "__mv1__/__mv1__#42", "--rename-branch"]);
+    assert!(out.status.success(), "mv failed: {}", stderr(&out));
+
+    let dest = bare.join("issue-42");
+    assert!(!wt.exists());
+    assert!(dest.join("notes.txt").exists());
+    let branch = git_out(&dest, &["rev-parse", "--abbrev-ref", "HEAD"]);
+    assert_eq!(branch.trim(), "issue-42");
+    let reg = read_registry(&h);
+    assert!(
+        reg.contains("issue-42") && !reg.contains("bold_turing"),
+        "{reg}"
+    );
+    assert!(reg.contains("2000-01-01T00:00:00Z"), "{reg}");
+
+    let out = run(&h, &["open", "--headless", "--json", "__mv1__/__mv1__#42"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"created\":false"), "{json}");
+    assert!(json.contains("\"integrity\":null"), "{json}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_mv_to_path_runs_hooks_only_when_asked() {
+    let h = temp_home("mv_path");
+    setup_bare_clone(&h, "__mv2__", "__mv2__");
+    let out = run(&h, &["open", "--headless", "__mv2__/__mv2__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    write_config(
+        &h,
+        "[hooks]\n\"post:open\" = \"touch \\\"$WORKTREE_PATH/hooked\\\"\"\n",
+    );
+    let first = h.join("elsewhere").join("one");
+    let out = run(&h, &["mv", "__mv2__/__mv2__#1", first.to_str().unwrap()]);
+    assert!(out.status.success(), "mv failed: {}", stderr(&out));
+    assert!(first.join("f").exists() && !first.join("hooked").exists());
+    assert!(read_registry(&h).contains("elsewhere"));
+
+    let second = h.join("elsewhere").join("two");
+    let (a, b) = (first.to_str().unwrap(), second.to_str().unwrap());
+    let out = run(&h, &["mv", a, b, "--hooks"]);
+    assert!(out.status.success(), "mv failed: {}", stderr(&out));
+    assert!(second.join("hooked").exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_mv_refuses_issue_of_another_repository() {
+    let h = temp_home("mv_other");
+    setup_bare_clone(&h, "__mv3__", "__mv3__");
+    let out = run(&h, &["open", "--headless", "__mv3__/__mv3__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let out = run(&h, &["mv", "__mv3__/__mv3__#1", "__other__/__other__#1"]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("different repository"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(read_registry(&h).contains("issue-1"));
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,101 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::{git_in, git_out, run, setup_bare_clone, stderr, stdout, temp_home};
+
+/// Open `owner/repo#issue` headlessly and return `(bare, worktree)` paths.
+fn open_issue(h: &Path, slug: &str, issue: u64) -> (PathBuf, PathBuf) {
+    let bare = setup_bare_clone(h, slug, slug);
+    let out = run(
+        h,
+        &["open", "--headless", &format!("{slug}/{slug}#{issue}")],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    (bare.clone(), bare.join(format!("issue-{issue}")))
+}
+
+fn reopen(h: &Path, slug: &str, issue: u64) -> String {
+    let out = run(
+        h,
+        &[
+            "open",
+            "--headless",
+            "--json",
+            &format!("{slug}/{slug}#{issue}"),
+        ],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    stdout(&out)
+}
+
+#[test]
+fn test_open_reports_live_worktree_as_healthy() {
+    let h = temp_home("repair_live");
+    open_issue(&h, "__rep1__", 1);
+    let json = reopen(&h, "__rep1__", 1);
+    assert!(json.contains("\"integrity\":null"), "{json}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_relinks_a_corrupt_git_file() {
+    let h = temp_home("repair_relink");
+    let (_, wt) = open_issue(&h, "__rep2__", 2);
+    std::fs::write(wt.join(".git"), "gitdir: /nowhere\n").unwrap();
+    let json = reopen(&h, "__rep2__", 2);
+    assert!(json.contains("\"integrity\":\"relinked\""), "{json}");
+    let branch = git_out(&wt, &["rev-parse", "--abbrev-ref", "HEAD"]);
+    assert_eq!(branch.trim(), "issue-2");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_reports_the_branch_of_a_worktree_on_another_branch() {
+    let h = temp_home("repair_other_branch");
+    let (_, wt) = open_issue(&h, "__rep5__", 5);
+    git_in(&wt, &["checkout", "-q", "-b", "other"]);
+    let json = reopen(&h, "__rep5__", 5);
+    assert!(
+        json.contains("\"integrity\":\"other-branch\",\"branch\":\"other\""),
+        "{json}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_recreates_a_plain_directory_keeping_files() {
+    let h = temp_home("repair_recreate");
+    let (bare, wt) = open_issue(&h, "__rep3__", 3);
+    std::fs::remove_dir_all(&wt).unwrap();
+    std::fs::remove_dir_all(bare.join("worktrees").join("issue-3")).unwrap();
+    std::fs::create_dir(&wt).unwrap();
+    std::fs::write(wt.join("notes.txt"), "keep me").unwrap();
+    let json = reopen(&h, "__rep3__", 3);
+    assert!(json.contains("\"integrity\":\"recreated\""), "{json}");
+    assert_eq!(
+        std::fs::read_to_string(wt.join("notes.txt")).unwrap(),
+        "keep me"
+    );
+    assert!(wt.join("f").exists());
+    assert!(!bare.join(".issue-3.broken-1").exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_recreates_an_emptied_directory_still_registered() {
+    let h = temp_home("repair_emptied");
+    let (_, wt) = open_issue(&h, "__rep4__", 4);
+    std::fs::remove_dir_all(&wt).unwrap();
+    std::fs::create_dir(&wt).unwrap();
+    let json = reopen(&h, "__rep4__", 4);
+    assert!(json.contains("\"integrity\":\"recreated\""), "{json}");
+    assert!(wt.join("f").exists());
+    let status = git_out(&wt, &["status", "--porcelain"]);
+    assert!(!status.lines().any(|l| l.contains('D')), "{status}");
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,80 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    add_worktree, git_out, read_registry, run, setup_bare_clone, stderr, stdout, temp_home,
+    write_config, write_registry,
+};
+
+#[test]
+fn test_pin_locks_worktree_and_survives_prune() {
+    let h = temp_home("pin_prune");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__pn__", "__pn__");
+    let wt = add_worktree(&bare, "issue-1");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    let out = run(&h, &["pin", wt.to_str().unwrap()]);
+    assert!(out.status.success(), "pin failed: {}", stderr(&out));
+    assert!(read_registry(&h).contains("pinned = true"));
+    let listed = git_out(&bare, &["worktree", "list", "--porcelain"]);
+    assert!(listed.contains("locked pinned by worktree"), "{listed}");
+    let out = run(&h, &["prune", "--force"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(wt.exists(), "pinned workspace was pruned");
+    let out = run(&h, &["list", "--json"]);
+    assert!(stdout(&out).contains("\"pinned\":true"), "{}", stdout(&out));
+    let out = run(&h, &["list"]);
+    assert!(stderr(&out).contains("PINNED"), "{}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_unpin_unlocks_and_allows_prune() {
+    let h = temp_home("pin_unpin");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__up__", "__up__");
+    let wt = add_worktree(&bare, "issue-2");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    assert!(run(&h, &["pin", wt.to_str().unwrap()]).status.success());
+    let out = run(&h, &["unpin", wt.to_str().unwrap()]);
+    assert!(out.status.success(), "unpin failed: {}", stderr(&out));
+    assert!(!read_registry(&h).contains("pinned"));
+    let listed = git_out(&bare, &["worktree", "list", "--porcelain"]);
+    assert!(!listed.contains("locked"), "{listed}");
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!wt.exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_refuses_pinned_without_force() {
+    let h = temp_home("pin_remove");
+    let bare = setup_bare_clone(&h, "__pr__", "__pr__");
+    let wt = add_worktree(&bare, "issue-3");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    assert!(run(&h, &["pin", wt.to_str().unwrap()]).status.success());
+    let out = run(&h, &["remove", wt.to_str().unwrap()]);
+    assert!(!out.status.success());
+    assert!(stderr(&out).contains("it is pinned"), "{}", stderr(&out));
+    let out = run(&h, &["remove", "--force", wt.to_str().unwrap()]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert!(!wt.exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_pin_unknown_workspace_errors() {
+    let h = temp_home("pin_unknown");
+    let out = run(&h, &["pin", h.join("nope").to_str().unwrap()]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("No workspace registered"),
+        "{}",
+        stderr(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,65 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{read_registry, run, setup_bare_clone, stderr, stdout, temp_home, write_config};
+
+fn open(h: &std::path::Path, issue: u32) -> String {
+    let out = run(
+        h,
+        &["open", "--headless", &format!("__po1__/__po1__#{issue}")],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let seen = std::fs::read_to_string(h.join(format!("port-{issue}.txt"))).unwrap();
+    seen.trim().to_owned()
+}
+
+#[test]
+fn test_open_assigns_stable_non_overlapping_port_blocks() {
+    let h = temp_home("ports_assign");
+    setup_bare_clone(&h, "__po1__", "__po1__");
+    let out_file = h.join("port-{{issue}}.txt").display().to_string();
+    write_config(
+        &h,
+        &format!(
+            "[hooks]\n\"pre:open\" = \"echo \\\"$WORKTREE_PORT {{{{port}}}}\\\" > '{out_file}'\"\n\
+             [ports]\nbase = 5100\nblock = 5\n"
+        ),
+    );
+    assert_eq!(open(&h, 1), "5100 5100");
+    assert_eq!(open(&h, 2), "5105 5105");
+    assert_eq!(open(&h, 1), "5100 5100");
+    assert!(read_registry(&h).contains("port = 5105"));
+
+    let out = run(&h, &["remove", "__po1__/__po1__#1"]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert_eq!(open(&h, 3), "5100 5100");
+
+    let script = "echo \"$WORKTREE_PORT\"";
+    let out = run(&h, &["run", "__po1__/__po1__#2", "--", "sh", "-c", script]);
+    assert!(out.status.success(), "run failed: {}", stderr(&out));
+    assert_eq!(stdout(&out).trim(), "5105");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_open_through_symlink_keeps_port() {
+    let h = temp_home("ports_symlink");
+    setup_bare_clone(&h, "__po1__", "__po1__");
+    let out_file = h.join("port-{{issue}}.txt").display().to_string();
+    write_config(
+        &h,
+        &format!("[hooks]\n\"pre:open\" = \"echo $WORKTREE_PORT > '{out_file}'\"\n"),
+    );
+    assert_eq!(open(&h, 1), "4000");
+    let link = h.with_extension("link");
+    let _ = std::fs::remove_file(&link);
+    std::os::unix::fs::symlink(&h, &link).unwrap();
+    assert_eq!(open(&link, 1), "4000");
+    assert_eq!(read_registry(&h).matches("port = ").count(), 1);
+    std::fs::remove_file(&link).ok();
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,150 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    add_worktree, commit_file, git_out, read_registry, run, setup_bare_clone, stderr, stdout,
+    temp_home, write_config, write_registry,
+};
+
+const OLD: &str = "2000-01-01T00:00:00Z";
+
+#[test]
+fn test_prune_removes_clean_worktree_through_git() {
+    let h = temp_home("prune_git_clean");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__pg__", "__pg__");
+    let wt = add_worktree(&bare, "issue-1");
+    write_registry(&h, &[(&wt, OLD)]);
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!wt.exists());
+    assert!(stderr(&out).contains("Pruned 1 expired workspace(s)"));
+    let listed = git_out(&bare, &["worktree", "list", "--porcelain"]);
+    assert!(
+        !listed.contains("issue-1"),
+        "bare clone still lists it: {listed}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_keeps_dirty_and_unpushed_worktrees() {
+    let h = temp_home("prune_git_unsaved");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__pu__", "__pu__");
+    let dirty = add_worktree(&bare, "issue-1");
+    std::fs::write(dirty.join("scratch.txt"), "wip").unwrap();
+    let ahead = add_worktree(&bare, "issue-2");
+    commit_file(&ahead, "local.txt");
+    write_registry(&h, &[(&dirty, OLD), (&ahead, OLD)]);
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    let err = stderr(&out);
+    assert!(dirty.join("scratch.txt").exists());
+    assert!(ahead.exists());
+    assert!(err.contains("1 uncommitted change(s)"), "stderr: {err}");
+    assert!(err.contains("1 unpushed commit(s)"), "stderr: {err}");
+    assert!(err.contains("Kept 2 expired workspace(s)"), "stderr: {err}");
+    let reg = read_registry(&h);
+    assert!(reg.contains("issue-1") && reg.contains("issue-2"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_force_discards_unsaved_work() {
+    let h = temp_home("prune_git_force");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__pf__", "__pf__");
+    let wt = add_worktree(&bare, "issue-1");
+    std::fs::write(wt.join("scratch.txt"), "wip").unwrap();
+    write_registry(&h, &[(&wt, OLD)]);
+    let out = run(&h, &["prune", "--force"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!wt.exists());
+    assert!(read_registry(&h).contains("trashed_at"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_json_reports_skipped() {
+    let h = temp_home("prune_git_json");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    let bare = setup_bare_clone(&h, "__pj__", "__pj__");
+    let wt = add_worktree(&bare, "issue-1");
+    std::fs::write(wt.join("scratch.txt"), "wip").unwrap();
+    write_registry(&h, &[(&wt, OLD)]);
+    let out = run(&h, &["prune", "--json"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"pruned\":[]"), "stdout: {json}");
+    assert!(
+        json.contains("\"uncommitted\":1,\"unpushed\":0"),
+        "stdout: {json}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_auto_prune_keeps_unsaved_work() {
+    let h = temp_home("prune_git_auto");
+    write_config(&h, "[workspace]\nttl = \"1s\"\nauto_prune = true\n");
+    let bare = setup_bare_clone(&h, "__pa__", "__pa__");
+    let dirty = add_worktree(&bare, "issue-1");
+    std::fs::write(dirty.join("scratch.txt"), "wip").unwrap();
+    let clean = add_worktree(&bare, "issue-2");
+    write_registry(&h, &[(&dirty, OLD), (&clean, OLD)]);
+    let out = run(&h, &["open", "--headless", "__pa__/__pa__#3"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    assert!(dirty.join("scratch.txt").exists());
+    assert!(!clean.exists());
+    assert!(stderr(&out).contains("it has unsaved work"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_open_auto_prune_spares_the_opened_workspace() {
+    let h = temp_home("prune_git_auto_self");
+    write_config(&h, "[workspace]\nttl = \"1s\"\nauto_prune = true\n");
+    let bare = setup_bare_clone(&h, "__pc__", "__pc__");
+    let wt = add_worktree(&bare, "issue-1");
+    write_registry(&h, &[(&wt, OLD)]);
+    let out = run(&h, &["open", "--headless", "__pc__/__pc__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    assert!(wt.join("f").exists());
+    assert!(!read_registry(&h).contains("trashed_at"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_failure_keeps_registry_entry() {
+    let h = temp_home("prune_git_failed");
+    write_config(&h, "[workspace]\nttl = \"1s\"\n");
+    // A worktree whose repository was moved away cannot be inspected.
+    let wt = h.join("moved-away/issue-1");
+    std::fs::create_dir_all(&wt).unwrap();
+    std::fs::write(wt.join(".git"), "gitdir: /nonexistent/admin\n").unwrap();
+    std::fs::write(wt.join("f"), "work").unwrap();
+    write_registry(&h, &[(&wt, OLD)]);
+
+    let out = run(&h, &["prune", "--json"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"pruned\":[]"), "{json}");
+    assert!(json.contains("\"failed\":[{\"path\":"), "{json}");
+    assert!(json.contains("\"total_freed_bytes\":0"), "{json}");
+    assert!(wt.join("f").exists());
+    assert!(read_registry(&h).contains("moved-away/issue-1"));
+
+    let out = run(&h, &["prune"]);
+    let err = stderr(&out);
+    assert!(err.contains("Pruned 0 expired workspace(s)"), "{err}");
+    assert!(
+        err.contains("Failed to prune 1 expired workspace(s)"),
+        "{err}"
+    );
+    assert!(read_registry(&h).contains("moved-away/issue-1"));
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,101 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use common::{read_registry, registry_path, run, stderr, stdout, temp_home, write_config};
+
+/// Create one 4 KB workspace per name, opened in the given order (oldest
+/// first), and register them; `pinned` names are pinned.
+fn write_workspaces(home: &Path, names: &[&str], pinned: &[&str]) -> Vec<PathBuf> {
+    let mut reg = String::from("version = 2\n");
+    let dirs = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let ws = home.join(name);
+            std::fs::create_dir_all(&ws).unwrap();
+            std::fs::write(ws.join("blob"), vec![0u8; 4096]).unwrap();
+            write!(
+                reg,
+                "\n[[workspace]]\npath = \"{}\"\ncreated_at = \"2000-01-01T00:00:00Z\"\n\
+                 last_opened_at = \"2001-01-0{}T00:00:00Z\"\npinned = {}\n",
+                ws.display(),
+                i + 1,
+                pinned.contains(name)
+            )
+            .unwrap();
+            ws
+        })
+        .collect();
+    std::fs::create_dir_all(registry_path(home).parent().unwrap()).unwrap();
+    std::fs::write(registry_path(home), reg).unwrap();
+    dirs
+}
+
+#[test]
+fn test_prune_evicts_least_recently_opened_until_within_budget() {
+    let h = temp_home("budget_evict");
+    write_config(
+        &h,
+        "[workspace]\nprune_policy = \"merged\"\nmax_total_size = \"10KB\"\n",
+    );
+    let ws = write_workspaces(&h, &["a", "b", "c"], &["a"]);
+    let out = run(&h, &["prune"]);
+    let err = stderr(&out);
+    assert!(out.status.success(), "prune failed: {err}");
+    assert!(ws[0].exists(), "pinned workspace must stay: {err}");
+    assert!(!ws[1].exists(), "least recently opened must go: {err}");
+    assert!(ws[2].exists(), "{err}");
+    assert!(err.contains("over the size budget"), "{err}");
+    assert!(
+        err.contains("Pruned 1 merged or over-budget workspace(s)"),
+        "{err}"
+    );
+    assert!(!read_registry(&h).contains("/b\""));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_dry_run_reports_without_removing() {
+    let h = temp_home("budget_dry_run");
+    write_config(&h, "[workspace]\nmax_total_size = \"6KB\"\n");
+    let ws = write_workspaces(&h, &["a", "b"], &[]);
+    let out = run(&h, &["prune", "--dry-run"]);
+    let err = stderr(&out);
+    assert!(out.status.success(), "prune failed: {err}");
+    assert!(err.contains("Would remove"), "{err}");
+    assert!(err.contains("Nothing was removed"), "{err}");
+    assert!(ws.iter().all(|w| w.exists()));
+    let out = run(&h, &["prune", "--dry-run", "--json"]);
+    let json = stdout(&out);
+    assert!(json.contains("\"dry_run\":true"), "{json}");
+    assert!(json.contains("\"reason\":\"over-budget\""), "{json}");
+    assert_eq!(json.matches("\"path\"").count(), 1, "{json}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_reuses_cached_sizes() {
+    let h = temp_home("budget_cache");
+    write_config(&h, "[workspace]\nmax_total_size = \"1MB\"\n");
+    let ws = write_workspaces(&h, &["a", "b"], &[]);
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(reg.contains("bytes = 4096"), "registry: {reg}");
+    // A fresh cached size is trusted over the disk, so an inflated one evicts.
+    std::fs::write(
+        registry_path(&h),
+        reg.replace("bytes = 4096", "bytes = 4194304"),
+    )
+    .unwrap();
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!ws[0].exists() && !ws[1].exists(), "{}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,104 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use common::{read_registry, registry_path, run, stderr, stdout, temp_home, BIN};
+
+/// Register one old GitHub workspace per `(owner, repo)` pair.
+fn write_workspaces(home: &Path, repos: &[(&str, &str)]) -> Vec<PathBuf> {
+    let mut reg = String::from("version = 2\n");
+    let dirs = repos
+        .iter()
+        .map(|(owner, repo)| {
+            let ws = home.join(format!("{owner}-{repo}"));
+            std::fs::create_dir_all(&ws).unwrap();
+            write!(
+                reg,
+                "\n[[workspace]]\npath = \"{}\"\ncreated_at = \"2000-01-01T00:00:00Z\"\n\
+                 provider = \"github\"\n\n[workspace.issue]\ntype = \"github\"\n\
+                 owner = \"{owner}\"\nrepo = \"{repo}\"\nnumber = 1\n",
+                ws.display()
+            )
+            .unwrap();
+            ws
+        })
+        .collect();
+    std::fs::create_dir_all(registry_path(home).parent().unwrap()).unwrap();
+    std::fs::write(registry_path(home), reg).unwrap();
+    dirs
+}
+
+#[test]
+fn test_prune_filters_target_matching_workspaces_without_a_ttl() {
+    let h = temp_home("prune_filters");
+    let ws = write_workspaces(&h, &[("acme", "api"), ("acme", "web"), ("other", "api")]);
+    let out = run(
+        &h,
+        &[
+            "prune",
+            "--owner",
+            "acme",
+            "--repo",
+            "api",
+            "--older-than",
+            "1d",
+        ],
+    );
+    let err = stderr(&out);
+    assert!(out.status.success(), "prune failed: {err}");
+    assert!(!ws[0].exists(), "{err}");
+    assert!(ws[1].exists() && ws[2].exists(), "{err}");
+    assert!(err.contains("TTL: 1day"), "{err}");
+    let out = run(&h, &["prune", "--provider", "gitlab", "--older-than", "1d"]);
+    assert!(
+        stderr(&out).contains("Checked 0 workspace(s)"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(ws[1].exists() && ws[2].exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_dry_run_json_lists_sizes_and_reasons() {
+    let h = temp_home("prune_filters_dry");
+    let ws = write_workspaces(&h, &[("acme", "api")]);
+    std::fs::write(ws[0].join("blob"), vec![0u8; 2048]).unwrap();
+    let out = run(&h, &["prune", "--older-than", "1d", "--dry-run", "--json"]);
+    let json = stdout(&out);
+    assert!(json.contains("\"reason\":\"expired\""), "{json}");
+    assert!(json.contains("\"freed_bytes\":2048"), "{json}");
+    assert!(ws[0].exists());
+    assert!(read_registry(&h).contains("acme-api"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_interactive_asks_per_workspace() {
+    let h = temp_home("prune_filters_interactive");
+    let ws = write_workspaces(&h, &[("acme", "api"), ("acme", "web")]);
+    let mut child = Command::new(BIN)
+        .env("HOME", &h)
+        .args(["prune", "--interactive", "--older-than", "1d"])
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"n\ny\n").unwrap();
+    let out = child.wait_with_output().unwrap();
+    let err = stderr(&out);
+    assert!(out.status.success(), "prune failed: {err}");
+    assert!(err.contains("[y/N]"), "{err}");
+    assert!(ws[0].exists(), "declined workspace must stay: {err}");
+    assert!(!ws[1].exists(), "{err}");
+    let reg = read_registry(&h);
+    assert_eq!(reg.matches("trashed_at").count(), 1, "{reg}");
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,112 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    add_worktree, commit_file, git_in, read_registry, run, setup_bare_clone, stderr, stdout,
+    temp_home, write_config, write_registry,
+};
+
+const CREATED: &str = "2000-01-01T00:00:00Z";
+
+#[test]
+fn test_prune_merged_removes_branch_merged_into_default() {
+    let h = temp_home("prune_merged_ff");
+    let bare = setup_bare_clone(&h, "__pm__", "__pm__");
+    let merged = add_worktree(&bare, "issue-1");
+    commit_file(&merged, "done.txt");
+    let fresh = add_worktree(&bare, "issue-2");
+    // Merge issue-1 into main upstream.
+    git_in(
+        &h.join("_src_"),
+        &[
+            "pull",
+            "-q",
+            "--ff-only",
+            merged.to_str().unwrap(),
+            "issue-1",
+        ],
+    );
+    write_registry(&h, &[(&merged, CREATED), (&fresh, "2100-01-01T00:00:00Z")]);
+    let out = run(&h, &["prune", "--merged"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!merged.exists());
+    assert!(fresh.exists(), "an untouched branch is not merged");
+    let err = stderr(&out);
+    assert!(err.contains("branch merged"), "stderr: {err}");
+    assert!(
+        err.contains("Pruned 1 merged workspace(s)"),
+        "stderr: {err}"
+    );
+    assert!(read_registry(&h).contains("issue-2"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_merged_removes_branch_deleted_upstream() {
+    let h = temp_home("prune_merged_gone");
+    let bare = setup_bare_clone(&h, "__pg2__", "__pg2__");
+    let wt = add_worktree(&bare, "issue-1");
+    commit_file(&wt, "squashed.txt");
+    git_in(&wt, &["push", "-q", "origin", "issue-1"]);
+    git_in(&h.join("_src_"), &["branch", "-q", "-D", "issue-1"]);
+    write_registry(&h, &[(&wt, CREATED)]);
+    let out = run(&h, &["prune", "--merged"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!wt.exists(), "stderr: {}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_policy_config_combines_ttl_and_merged_json() {
+    let h = temp_home("prune_merged_json");
+    let config = "[workspace]\nttl = \"30days\"\nprune_policy = \"ttl,merged\"\n";
+    write_config(&h, config);
+    let bare = setup_bare_clone(&h, "__pmj__", "__pmj__");
+    let expired = add_worktree(&bare, "issue-1");
+    let merged = add_worktree(&bare, "issue-2");
+    commit_file(&merged, "done.txt");
+    git_in(
+        &h.join("_src_"),
+        &[
+            "pull",
+            "-q",
+            "--ff-only",
+            merged.to_str().unwrap(),
+            "issue-2",
+        ],
+    );
+    let hour_ago = std::time::SystemTime::now() - std::time::Duration::from_hours(1);
+    let before = humantime::format_rfc3339(hour_ago).to_string();
+    write_registry(&h, &[(&expired, CREATED), (&merged, &before)]);
+    let out = run(&h, &["prune", "--json"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"reason\":\"expired\""), "stdout: {json}");
+    assert!(json.contains("\"reason\":\"merged\""), "stdout: {json}");
+    assert!(json.contains("\"ttl\":\"30days\""), "stdout: {json}");
+    assert!(!expired.exists() && !merged.exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_merged_without_ttl_reports_none() {
+    let h = temp_home("prune_merged_none");
+    let out = run(&h, &["prune", "--merged"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("none merged"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_merged_json_without_ttl_omits_ttl() {
+    let h = temp_home("prune_merged_json_none");
+    let out = run(&h, &["prune", "--merged", "--json"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"failed\":[]"), "stdout: {json}");
+    assert!(!json.contains("\"ttl\""), "stdout: {json}");
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,122 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::{commit_file, git_out, read_registry, run, setup_bare_clone, stderr, temp_home};
+
+/// Open `owner/repo#issue` headlessly and return `(bare, worktree)` paths.
+fn open_issue(h: &Path, slug: &str, issue: u64) -> (PathBuf, PathBuf) {
+    let bare = setup_bare_clone(h, slug, slug);
+    let out = run(
+        h,
+        &["open", "--headless", &format!("{slug}/{slug}#{issue}")],
+    );
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let wt = bare.join(format!("issue-{issue}"));
+    assert!(wt.exists());
+    (bare, wt)
+}
+
+#[test]
+fn test_remove_clean_workspace_by_ref() {
+    let h = temp_home("rm_clean");
+    let (bare, wt) = open_issue(&h, "__rm1__", 1);
+    let out = run(&h, &["remove", "__rm1__/__rm1__#1"]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert!(!wt.exists());
+    assert!(read_registry(&h).contains("trashed_at"));
+    assert!(git_out(&bare, &["worktree", "list"]).lines().count() == 1);
+    assert_eq!(git_out(&bare, &["branch", "--list", "issue-1"]), "issue-1");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_refuses_dirty_without_force() {
+    let h = temp_home("rm_dirty");
+    let (_, wt) = open_issue(&h, "__rm2__", 2);
+    std::fs::write(wt.join("scratch.txt"), "wip").unwrap();
+    let out = run(&h, &["remove", wt.to_str().unwrap()]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("1 uncommitted change(s)"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(wt.exists());
+    let out = run(&h, &["remove", "--force", wt.to_str().unwrap()]);
+    assert!(
+        out.status.success(),
+        "forced remove failed: {}",
+        stderr(&out)
+    );
+    assert!(!wt.exists());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_refuses_unpushed_commits() {
+    let h = temp_home("rm_unpushed");
+    let (_, wt) = open_issue(&h, "__rm3__", 3);
+    commit_file(&wt, "local.txt");
+    let out = run(&h, &["remove", "__rm3__/__rm3__#3"]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("1 unpushed commit(s)"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(stderr(&out).contains("--force"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_delete_branch() {
+    let h = temp_home("rm_branch");
+    let (bare, _) = open_issue(&h, "__rm4__", 4);
+    let out = run(&h, &["remove", "--delete-branch", "__rm4__/__rm4__#4"]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert_eq!(git_out(&bare, &["branch", "--list", "issue-4"]), "");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_missing_dir_drops_registry_entry() {
+    let h = temp_home("rm_missing");
+    let ws = h.join("gone");
+    common::write_registry(&h, &[(&ws, "2025-01-01T00:00:00Z")]);
+    let out = run(&h, &["remove", ws.to_str().unwrap()]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("dropped its registry entry"));
+    assert!(!read_registry(&h).contains("gone"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_unknown_target_fails() {
+    let h = temp_home("rm_unknown");
+    let out = run(&h, &["remove", "__nope__/__nope__#9"]);
+    assert!(!out.status.success());
+    assert!(stderr(&out).contains("No workspace found"));
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_remove_refuses_unmanaged_directory() {
+    let h = temp_home("rm_unmanaged");
+    let docs = h.join("docs");
+    std::fs::create_dir_all(&docs).unwrap();
+    std::fs::write(docs.join("notes.txt"), "keep").unwrap();
+    let out = run(&h, &["remove", "--force", docs.to_str().unwrap()]);
+    assert!(!out.status.success());
+    assert!(
+        stderr(&out).contains("is not a workspace managed by worktree"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(docs.join("notes.txt").exists());
+    std::fs::remove_dir_all(&h).ok();
+}
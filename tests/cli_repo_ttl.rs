@@ -0,0 +1,79 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    git_in, read_registry, registry_path, run, setup_bare_clone, stderr, stdout, temp_home,
+    write_config,
+};
+
+#[test]
+fn test_open_ttl_flag_is_stored_and_listed() {
+    let h = temp_home("repo_ttl_flag");
+    setup_bare_clone(&h, "__rt__", "__rt__");
+    let out = run(&h, &["open", "__rt__/__rt__#1", "--ttl", "2d"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(reg.contains("ttl = \"2days\""), "registry: {reg}");
+    let out = run(&h, &["list", "--json"]);
+    assert!(
+        stdout(&out).contains("\"ttl\":\"2days\""),
+        "{}",
+        stdout(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_config_repo_rule_applies_on_create() {
+    let h = temp_home("repo_ttl_rule");
+    write_config(&h, "[workspace.repo_ttl]\n__rt__ = \"5d\"\n");
+    setup_bare_clone(&h, "__rt__", "__rt__");
+    let out = run(&h, &["open", "__rt__/__rt__#2"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(reg.contains("ttl = \"5days\""), "registry: {reg}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_repo_file_ttl_beats_config_rule() {
+    let h = temp_home("repo_ttl_file");
+    let src = h.join("_src_");
+    std::fs::create_dir_all(&src).unwrap();
+    git_in(&src, &["init", "-b", "main"]);
+    std::fs::write(src.join(".worktree.toml"), "ttl = \"3d\"\n").unwrap();
+    git_in(&src, &["add", "."]);
+    git_in(&src, &["commit", "-m", "init"]);
+    write_config(&h, "[workspace.repo_ttl]\n\"__rt__/__rt__\" = \"5d\"\n");
+    setup_bare_clone(&h, "__rt__", "__rt__");
+    let out = run(&h, &["open", "__rt__/__rt__#3"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(reg.contains("ttl = \"3days\""), "registry: {reg}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_prune_honors_record_ttl_without_global_ttl() {
+    let h = temp_home("repo_ttl_prune");
+    let (old, kept) = (h.join("old"), h.join("kept"));
+    std::fs::create_dir_all(&old).unwrap();
+    std::fs::create_dir_all(&kept).unwrap();
+    let reg = format!(
+        "version = 2\n\n[[workspace]]\npath = \"{}\"\ncreated_at = \"2000-01-01T00:00:00Z\"\n\
+         ttl = \"1h\"\n\n[[workspace]]\npath = \"{}\"\ncreated_at = \"2000-01-01T00:00:00Z\"\n",
+        old.display(),
+        kept.display()
+    );
+    std::fs::create_dir_all(registry_path(&h).parent().unwrap()).unwrap();
+    std::fs::write(registry_path(&h), reg).unwrap();
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!old.exists(), "{}", stderr(&out));
+    assert!(kept.exists());
+    assert!(stderr(&out).contains("TTL: 1h"), "{}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
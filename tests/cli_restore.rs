@@ -0,0 +1,97 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{git_in, git_out, registry_path, run, setup_bare_clone, stderr, stdout, temp_home};
+
+#[test]
+fn test_restore_rebuilds_multi_workspace_members() {
+    let h = temp_home("restore_multi");
+    setup_bare_clone(&h, "__rm__", "__rm__");
+    let out = run(&h, &["open-multi", "__rm__/__rm__#1", "__rm__/__rm__"]);
+    assert!(out.status.success(), "open-multi failed: {}", stderr(&out));
+    let ws_root = std::fs::read_dir(h.join("workspaces"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    std::fs::remove_dir_all(&ws_root).unwrap();
+    let out = run(&h, &["restore"]);
+    assert!(out.status.success(), "restore failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("Restored 2 of 2"), "{}", stderr(&out));
+    let member = ws_root.join("__rm__-1");
+    assert_eq!(git_out(&member, &["branch", "--show-current"]), "issue-1");
+    let plain = ws_root.join("__rm__");
+    assert_eq!(git_out(&plain, &["branch", "--show-current"]), "main");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_restore_local_workspace_recreates_branch_from_base_ref() {
+    let h = temp_home("restore_local_v2");
+    let project = h.join("proj");
+    std::fs::create_dir_all(&project).unwrap();
+    git_in(&project, &["init", "-q", "-b", "main"]);
+    git_in(&project, &["commit", "-q", "--allow-empty", "-m", "init"]);
+    let wt = h
+        .join("worktrees")
+        .join("local")
+        .join("proj")
+        .join("issue-5");
+    git_in(
+        &project,
+        &["worktree", "add", wt.to_str().unwrap(), "-b", "issue-5"],
+    );
+    std::fs::remove_dir_all(&wt).unwrap();
+    git_in(&project, &["worktree", "prune"]);
+    git_in(&project, &["branch", "-D", "issue-5"]);
+    let reg = format!(
+        "version = 2\n\n[[workspace]]\npath = \"{}\"\ncreated_at = \"2025-01-01T00:00:00Z\"\n\
+         kind = \"local\"\nbranch = \"issue-5\"\nbase_ref = \"main\"\nbare_path = \"{}\"\n",
+        wt.display(),
+        project.display()
+    );
+    std::fs::create_dir_all(registry_path(&h).parent().unwrap()).unwrap();
+    std::fs::write(registry_path(&h), reg).unwrap();
+    let out = run(&h, &["restore"]);
+    assert!(out.status.success(), "restore failed: {}", stderr(&out));
+    assert_eq!(git_out(&wt, &["branch", "--show-current"]), "issue-5");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_restore_json_reports_restored_and_skipped() {
+    let h = temp_home("restore_json");
+    let bare = setup_bare_clone(&h, "__rj__", "__rj__");
+    let out = run(&h, &["open", "__rj__/__rj__#3"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let wt = bare.join("issue-3");
+    std::fs::remove_dir_all(&wt).unwrap();
+    let gone = h.join("worktrees/github/__gone__/__gone__");
+    let reg = std::fs::read_to_string(registry_path(&h)).unwrap();
+    let reg = format!(
+        "{reg}\n[[workspace]]\npath = \"{}\"\ncreated_at = \"2025-01-01T00:00:00Z\"\n\
+         branch = \"issue-1\"\nbare_path = \"{}\"\n",
+        gone.join("issue-1").display(),
+        gone.display()
+    );
+    std::fs::write(registry_path(&h), reg).unwrap();
+    let out = run(&h, &["restore", "--json"]);
+    assert!(out.status.success(), "restore failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.starts_with("{\"orphaned\":2,"), "json: {json}");
+    let restored = format!(
+        "\"path\":\"{}\",\"kind\":\"single\",\"branch\":\"issue-3\"",
+        wt.display()
+    );
+    assert!(json.contains(&restored), "json: {json}");
+    assert!(
+        json.contains("\"reason\":\"bare clone no longer exists"),
+        "json: {json}"
+    );
+    assert!(wt.exists());
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,64 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{commit_file, run, setup_bare_clone, stderr, stdout, temp_home};
+
+#[test]
+fn test_status_reports_branch_changes_and_base() {
+    let h = temp_home("status_json");
+    let bare = setup_bare_clone(&h, "__st1__", "__st1__");
+    let out = run(&h, &["open", "--headless", "__st1__/__st1__#1"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let wt = bare.join("issue-1");
+    commit_file(&wt, "feature.txt");
+    std::fs::write(wt.join("scratch.txt"), "wip").unwrap();
+
+    let out = run(&h, &["status", "--json"]);
+    assert!(out.status.success(), "status failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"state\":\"ok\""), "{json}");
+    assert!(json.contains("\"branch\":\"issue-1\""), "{json}");
+    // scratch.txt and the `.worktree.toml` scaffolded by `open`
+    assert!(json.contains("\"dirty\":2"), "{json}");
+    assert!(
+        json.contains("\"base\":{\"name\":\"origin/main\",\"ahead\":1,\"behind\":0}"),
+        "{json}"
+    );
+    assert!(json.contains("\"subject\":\"feature.txt\""), "{json}");
+    assert!(!json.contains("\"size_bytes\":null"), "{json}");
+
+    let out = run(&h, &["status"]);
+    assert!(out.status.success(), "status failed: {}", stderr(&out));
+    let table = stderr(&out);
+    assert!(table.starts_with("WORKSPACE"), "{table}");
+    assert!(
+        table.contains("issue-1") && table.contains("feature.txt"),
+        "{table}"
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_status_marks_missing_and_hibernated_workspaces() {
+    let h = temp_home("status_gone");
+    let bare = setup_bare_clone(&h, "__st2__", "__st2__");
+    for issue in ["__st2__/__st2__#1", "__st2__/__st2__#2"] {
+        let out = run(&h, &["open", "--headless", issue]);
+        assert!(out.status.success(), "open failed: {}", stderr(&out));
+    }
+    let out = run(&h, &["hibernate", "__st2__/__st2__#1"]);
+    assert!(out.status.success(), "hibernate failed: {}", stderr(&out));
+    std::fs::remove_dir_all(bare.join("issue-2")).unwrap();
+
+    let out = run(&h, &["status", "--json"]);
+    assert!(out.status.success(), "status failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(json.contains("\"state\":\"hibernated\""), "{json}");
+    assert!(json.contains("\"state\":\"missing\""), "{json}");
+    let out = run(&h, &["status"]);
+    assert!(stderr(&out).contains("(hibernated)"), "{}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,106 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::{
+    commit_file, git_out, run, setup_bare_clone, stderr, stdout, temp_home, write_config,
+};
+
+/// A home whose git identity lets `sync` commit, with a bare clone of
+/// `~/_src_` as `slug/slug` and workspaces open for `issues`.
+fn home_with(tag: &str, slug: &str, issues: &[u64]) -> (PathBuf, Vec<PathBuf>) {
+    let h = temp_home(tag);
+    std::fs::write(h.join(".gitconfig"), "[user]\nname = T\nemail = t@t.com\n").unwrap();
+    let bare = setup_bare_clone(&h, slug, slug);
+    let wts = issues
+        .iter()
+        .map(|n| {
+            let out = run(&h, &["open", "--headless", &format!("{slug}/{slug}#{n}")]);
+            assert!(out.status.success(), "open failed: {}", stderr(&out));
+            bare.join(format!("issue-{n}"))
+        })
+        .collect();
+    (h, wts)
+}
+
+fn write_and_commit(dir: &Path, file: &str, body: &str) {
+    std::fs::write(dir.join(file), body).unwrap();
+    common::git_in(dir, &["commit", "-qam", file]);
+}
+
+#[test]
+fn test_sync_rebases_clean_and_skips_dirty() {
+    let (h, wts) = home_with("sync_rebase", "__sy1__", &[1, 2]);
+    commit_file(&wts[0], "feature.txt");
+    std::fs::write(wts[1].join("f"), "edited").unwrap();
+    commit_file(&h.join("_src_"), "upstream.txt");
+
+    let out = run(&h, &["sync", "--json"]);
+    assert!(out.status.success(), "sync failed: {}", stderr(&out));
+    let json = stdout(&out);
+    assert!(
+        json.contains("\"strategy\":\"rebase\",\"fetched\":1"),
+        "{json}"
+    );
+    assert!(
+        json.contains("\"outcome\":\"updated\",\"new_commits\":1"),
+        "{json}"
+    );
+    assert!(
+        json.contains("\"reason\":\"uncommitted changes\""),
+        "{json}"
+    );
+    assert!(wts[0].join("upstream.txt").exists());
+    assert!(!wts[1].join("upstream.txt").exists());
+    let log = git_out(&wts[0], &["log", "--format=%s"]);
+    assert_eq!(
+        log.lines().collect::<Vec<_>>(),
+        ["feature.txt", "upstream.txt", "init"]
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_sync_aborts_conflicts_and_fails() {
+    let (h, wts) = home_with("sync_conflict", "__sy2__", &[1]);
+    write_and_commit(&wts[0], "f", "mine");
+    write_and_commit(&h.join("_src_"), "f", "theirs");
+
+    let out = run(&h, &["sync", "__sy2__/__sy2__#1"]);
+    assert!(!out.status.success());
+    let err = stderr(&out);
+    assert!(err.contains("Conflict trying to rebase"), "{err}");
+    assert!(
+        err.contains("0 updated, 0 up to date, 0 skipped, 1 with conflicts"),
+        "{err}"
+    );
+    assert_eq!(std::fs::read_to_string(wts[0].join("f")).unwrap(), "mine");
+    assert!(git_out(&wts[0], &["status", "--porcelain", "-uno"]).is_empty());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_sync_merges_when_configured() {
+    let (h, wts) = home_with("sync_merge", "__sy3__", &[1]);
+    write_config(&h, "[workspace]\nsync_strategy = \"merge\"\n");
+    commit_file(&h.join("_src_"), "upstream.txt");
+    let out = run(&h, &["sync"]);
+    assert!(out.status.success(), "sync failed: {}", stderr(&out));
+    assert!(
+        stderr(&out).contains("Merged origin/main into"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(wts[0].join("upstream.txt").exists());
+    let out = run(&h, &["sync"]);
+    assert!(
+        stderr(&out).contains("is up to date with origin/main"),
+        "{}",
+        stderr(&out)
+    );
+    std::fs::remove_dir_all(&h).ok();
+}
@@ -0,0 +1,111 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use common::{
+    add_worktree, git_out, read_registry, run, setup_bare_clone, stderr, stdout, temp_home,
+    write_config, write_registry,
+};
+
+/// The id of the only entry printed by `worktree trash list`.
+fn trash_id(h: &std::path::Path) -> String {
+    let out = run(h, &["trash", "list"]);
+    let err = stderr(&out);
+    err.split_whitespace().next().unwrap_or_default().to_owned()
+}
+
+#[test]
+fn test_remove_then_undo_relinks_worktree() {
+    let h = temp_home("trash_undo");
+    let bare = setup_bare_clone(&h, "__tu__", "__tu__");
+    let wt = add_worktree(&bare, "issue-1");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    std::fs::write(wt.join("notes.txt"), "keep me").unwrap();
+    let out = run(&h, &["remove", wt.to_str().unwrap(), "--force"]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert!(stderr(&out).contains("to the trash"), "{}", stderr(&out));
+    assert!(!wt.exists());
+    assert_eq!(git_out(&bare, &["worktree", "list"]).lines().count(), 1);
+    assert!(read_registry(&h).contains("trashed_at"));
+    let list = run(&h, &["list", "--json"]);
+    assert!(
+        stdout(&list).contains("\"trashed\":true"),
+        "{}",
+        stdout(&list)
+    );
+
+    let out = run(&h, &["undo"]);
+    assert!(out.status.success(), "undo failed: {}", stderr(&out));
+    assert_eq!(
+        std::fs::read_to_string(wt.join("notes.txt")).unwrap(),
+        "keep me"
+    );
+    assert_eq!(
+        git_out(&wt, &["rev-parse", "--abbrev-ref", "HEAD"]),
+        "issue-1"
+    );
+    assert!(git_out(&bare, &["worktree", "list"]).contains("issue-1"));
+    let reg = read_registry(&h);
+    assert!(
+        reg.contains("issue-1") && !reg.contains("trashed_at"),
+        "{reg}"
+    );
+    assert!(!stderr(&run(&h, &["undo"])).is_empty());
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_trash_restore_recreates_deleted_branch() {
+    let h = temp_home("trash_branch");
+    let bare = setup_bare_clone(&h, "__tb__", "__tb__");
+    let wt = add_worktree(&bare, "issue-2");
+    write_registry(&h, &[(&wt, "2000-01-01T00:00:00Z")]);
+    let out = run(&h, &["remove", wt.to_str().unwrap(), "--delete-branch"]);
+    assert!(out.status.success(), "remove failed: {}", stderr(&out));
+    assert!(git_out(&bare, &["branch", "--list", "issue-2"]).is_empty());
+    let id = trash_id(&h);
+    assert!(id.starts_with("issue-2-"), "{id}");
+    let out = run(&h, &["trash", "restore", &id]);
+    assert!(out.status.success(), "restore failed: {}", stderr(&out));
+    assert_eq!(
+        git_out(&wt, &["rev-parse", "--abbrev-ref", "HEAD"]),
+        "issue-2"
+    );
+    let branches = git_out(&bare, &["branch", "--format=%(refname:short)"]);
+    assert!(branches.lines().any(|b| b == "issue-2"), "{branches}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_trash_retention_empties_old_entries() {
+    let h = temp_home("trash_retention");
+    write_config(&h, "[workspace]\ntrash_retention = \"1s\"\n");
+    let (old, new) = (h.join("old"), h.join("new"));
+    std::fs::create_dir_all(&old).unwrap();
+    std::fs::create_dir_all(&new).unwrap();
+    write_registry(
+        &h,
+        &[
+            (&old, "2000-01-01T00:00:00Z"),
+            (&new, "2000-01-01T00:00:00Z"),
+        ],
+    );
+    assert!(run(&h, &["remove", old.to_str().unwrap()]).status.success());
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    assert!(run(&h, &["remove", new.to_str().unwrap()]).status.success());
+    let out = run(&h, &["trash", "list"]);
+    let err = stderr(&out);
+    assert!(err.contains("new-") && !err.contains("old-"), "{err}");
+    let reg = read_registry(&h);
+    assert!(reg.contains("/new\"") && !reg.contains("/old\""), "{reg}");
+    let out = run(&h, &["trash", "empty"]);
+    assert!(
+        stderr(&out).contains("Deleted 1 workspace(s)"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(!read_registry(&h).contains("trashed_at"));
+    std::fs::remove_dir_all(&h).ok();
+}
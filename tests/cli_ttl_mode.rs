@@ -0,0 +1,87 @@
+#![allow(
+    missing_docs,
+    reason = "integration test crate — doc comments are not required for test helpers and fixtures"
+)]
+mod common;
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use common::{
+    read_registry, registry_path, run, setup_bare_clone, stderr, temp_home, write_config,
+};
+
+/// Write a v2 registry with one workspace created long ago and last opened now.
+fn write_recently_opened(home: &Path, ws: &Path) {
+    let now = humantime::format_rfc3339(SystemTime::now());
+    let reg = format!(
+        "version = 2\n\n[[workspace]]\npath = \"{}\"\n\
+         created_at = \"2000-01-01T00:00:00Z\"\nlast_opened_at = \"{now}\"\n",
+        ws.display()
+    );
+    std::fs::create_dir_all(registry_path(home).parent().unwrap()).unwrap();
+    std::fs::write(registry_path(home), reg).unwrap();
+}
+
+#[test]
+fn test_reopen_updates_last_opened_at() {
+    let h = temp_home("ttl_mode_reopen");
+    setup_bare_clone(&h, "__tm__", "__tm__");
+    let out = run(&h, &["open", "__tm__/__tm__#4"]);
+    assert!(out.status.success(), "open failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(reg.contains("last_opened_at = "), "registry: {reg}");
+    let stale = reg
+        .lines()
+        .map(|l| {
+            if l.starts_with("last_opened_at") {
+                "last_opened_at = \"2000-01-01T00:00:00Z\""
+            } else {
+                l
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    std::fs::write(registry_path(&h), stale).unwrap();
+    let out = run(&h, &["open", "__tm__/__tm__#4"]);
+    assert!(out.status.success(), "reopen failed: {}", stderr(&out));
+    let reg = read_registry(&h);
+    assert!(!reg.contains("2000-01-01"), "registry: {reg}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_idle_mode_keeps_recently_opened_workspace() {
+    let h = temp_home("ttl_mode_idle");
+    write_config(&h, "[workspace]\nttl = \"1h\"\nttl_mode = \"idle\"\n");
+    let ws = h.join("epic");
+    std::fs::create_dir_all(&ws).unwrap();
+    write_recently_opened(&h, &ws);
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(
+        stderr(&out).contains("none expired (idle TTL: 1h)"),
+        "{}",
+        stderr(&out)
+    );
+    assert!(ws.exists());
+    let out = run(&h, &["list"]);
+    let listed = stderr(&out);
+    assert!(listed.contains("(TTL: 1h since last open)"), "{listed}");
+    assert!(listed.contains("  idle "), "{listed}");
+    assert!(listed.contains("expires in"), "{listed}");
+    std::fs::remove_dir_all(&h).ok();
+}
+
+#[test]
+fn test_created_mode_prunes_recently_opened_workspace() {
+    let h = temp_home("ttl_mode_created");
+    write_config(&h, "[workspace]\nttl = \"1h\"\n");
+    let ws = h.join("epic");
+    std::fs::create_dir_all(&ws).unwrap();
+    write_recently_opened(&h, &ws);
+    let out = run(&h, &["prune"]);
+    assert!(out.status.success(), "prune failed: {}", stderr(&out));
+    assert!(!ws.exists(), "{}", stderr(&out));
+    std::fs::remove_dir_all(&h).ok();
+}
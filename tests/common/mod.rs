@@ -0,0 +1,152 @@
+//! Helpers shared by the per-command CLI integration test crates.
+#![allow(
+    dead_code,
+    reason = "each test crate compiles this module separately and uses only a subset of the helpers"
+)]
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub const BIN: &str = env!("CARGO_BIN_EXE_worktree");
+
+pub fn temp_home(tag: &str) -> PathBuf {
+    let d = std::env::temp_dir().join(format!("wt-cli-{tag}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&d);
+    std::fs::create_dir_all(&d).unwrap();
+    d
+}
+
+pub fn run(home: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(BIN)
+        .env("HOME", home)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+pub fn stderr(out: &std::process::Output) -> String {
+    String::from_utf8_lossy(&out.stderr).into_owned()
+}
+
+pub fn stdout(out: &std::process::Output) -> String {
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+pub fn write_config(home: &Path, toml: &str) {
+    let cfg = home.join(".config").join("worktree").join("config.toml");
+    std::fs::create_dir_all(cfg.parent().unwrap()).unwrap();
+    std::fs::write(cfg, toml).unwrap();
+}
+
+pub fn registry_path(home: &Path) -> PathBuf {
+    home.join(".config")
+        .join("worktree")
+        .join("workspaces.toml")
+}
+
+pub fn read_registry(home: &Path) -> String {
+    std::fs::read_to_string(registry_path(home)).unwrap_or_default()
+}
+
+/// Write a registry with one `[[workspace]]` per `(path, created_at)` pair.
+pub fn write_registry(home: &Path, entries: &[(&Path, &str)]) {
+    let reg = registry_path(home);
+    std::fs::create_dir_all(reg.parent().unwrap()).unwrap();
+    let content = entries
+        .iter()
+        .map(|(path, ts)| {
+            let escaped = path
+                .to_str()
+                .unwrap()
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+            format!("[[workspace]]\npath = \"{escaped}\"\ncreated_at = \"{ts}\"\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    std::fs::write(reg, content).unwrap();
+}
+
+pub fn git_in(dir: &Path, args: &[&str]) {
+    let ok = git_cmd(dir, args).status().unwrap().success();
+    assert!(ok, "git {args:?} failed");
+}
+
+pub fn git_out(dir: &Path, args: &[&str]) -> String {
+    let out = git_cmd(dir, args).output().unwrap();
+    String::from_utf8_lossy(&out.stdout).trim().to_owned()
+}
+
+fn git_cmd(dir: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C")
+        .arg(dir)
+        .args(args)
+        // Unset inherited git env vars so `-C dir` is honoured even inside
+        // a git worktree hook, where GIT_DIR would otherwise override it.
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .env_remove("GIT_INDEX_FILE")
+        .env("GIT_AUTHOR_NAME", "T")
+        .env("GIT_AUTHOR_EMAIL", "t@t.com")
+        .env("GIT_COMMITTER_NAME", "T")
+        .env("GIT_COMMITTER_EMAIL", "t@t.com");
+    cmd
+}
+
+/// Create `~/_src_` with one commit on `main` and bare-clone it to
+/// `~/worktrees/github/<owner>/<repo>`. Returns the bare clone path.
+pub fn setup_bare_clone(home: &Path, owner: &str, repo: &str) -> PathBuf {
+    let src = home.join("_src_");
+    if !src.exists() {
+        std::fs::create_dir_all(&src).unwrap();
+        git_in(&src, &["init", "-b", "main"]);
+        std::fs::write(src.join("f"), "x").unwrap();
+        git_in(&src, &["add", "."]);
+        git_in(&src, &["commit", "-m", "init"]);
+    }
+    let bare = home.join("worktrees").join("github").join(owner).join(repo);
+    std::fs::create_dir_all(bare.parent().unwrap()).unwrap();
+    git_in(
+        home,
+        &[
+            "clone",
+            "--bare",
+            src.to_str().unwrap(),
+            bare.to_str().unwrap(),
+        ],
+    );
+    git_in(
+        &bare,
+        &[
+            "config",
+            "remote.origin.fetch",
+            "+refs/heads/*:refs/remotes/origin/*",
+        ],
+    );
+    git_in(&bare, &["fetch", "origin"]);
+    bare
+}
+
+/// Add a worktree on a new `branch` from `origin/main` inside `bare`.
+pub fn add_worktree(bare: &Path, branch: &str) -> PathBuf {
+    let wt = bare.join(branch);
+    git_in(
+        bare,
+        &[
+            "worktree",
+            "add",
+            wt.to_str().unwrap(),
+            "-b",
+            branch,
+            "origin/main",
+        ],
+    );
+    wt
+}
+
+/// Commit a new file inside worktree `wt`.
+pub fn commit_file(wt: &Path, name: &str) {
+    std::fs::write(wt.join(name), name).unwrap();
+    git_in(wt, &["add", name]);
+    git_in(wt, &["commit", "-m", name]);
+}